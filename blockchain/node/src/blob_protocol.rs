@@ -0,0 +1,217 @@
+//! A request-response protocol through which nodes fetch blobs of the [`BlobStore`] by hash from
+//! their peers, so that a reader node can retrieve a document from any node hosting it.
+//!
+//! A request is the SCALE-encoded hash of a blob, and the response the SCALE-encoded blob, or
+//! `None` if the peer does not host it. Blobs are checked against their hash before they are
+//! used, and peers sending malformed messages or blobs that do not match their hash lose
+//! reputation. A few peers are asked at a time, within an overall deadline, and blobs no peer sent
+//! are not asked for again for a while.
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+use codec::{Decode, Encode};
+use futures::{future, pin_mut, stream, StreamExt};
+use sc_network::{
+	request_responses::{IfDisconnected, IncomingRequest, OutgoingResponse, ProtocolConfig},
+	NetworkPeers, NetworkRequest, NetworkService, PeerId, ProtocolName, ReputationChange,
+};
+use sc_network_sync::SyncingService;
+use speem_runtime::{opaque::Block, Hash};
+
+use crate::blob_store::{self, BlobStore};
+
+/// The largest request, which is a hash.
+const MAX_REQUEST_SIZE: u64 = 1024;
+
+/// How long to wait for a peer to send a blob.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The number of requests waiting to be answered beyond which new ones are dropped.
+const INBOUND_QUEUE_SIZE: usize = 32;
+
+/// The number of peers asked for a blob at the same time.
+const MAX_PARALLEL_REQUESTS: usize = 4;
+
+/// How long to keep asking peers for a blob before giving up.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long a blob that no peer sent is not asked for again.
+const MISS_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// The number of blobs that no peer sent remembered at most.
+const MAX_MISSES: usize = 1024;
+
+/// The reputation lost by a peer sending a message that does not decode.
+const MALFORMED_MESSAGE: ReputationChange =
+	ReputationChange::new(-(1 << 10), "Malformed blob protocol message");
+
+/// The reputation lost by a peer sending a blob that does not match its hash.
+const BAD_BLOB: ReputationChange =
+	ReputationChange::new(-(1 << 12), "Sent a blob not matching its hash");
+
+/// The name of the protocol on the chain with genesis hash `genesis_hash`.
+pub fn protocol_name(genesis_hash: &Hash, fork_id: Option<&str>) -> ProtocolName {
+	match fork_id {
+		Some(fork_id) => format!("/{genesis_hash:x}/{fork_id}/speem/blobs/1"),
+		None => format!("/{genesis_hash:x}/speem/blobs/1"),
+	}
+	.into()
+}
+
+/// Answers the blob requests of peers from the local [`BlobStore`].
+pub struct BlobRequestHandler {
+	store: Arc<BlobStore>,
+	requests: async_channel::Receiver<IncomingRequest>,
+}
+
+impl BlobRequestHandler {
+	/// Creates the handler, and the configuration of the protocol to register with the network.
+	pub fn new(
+		protocol_name: ProtocolName,
+		store: Arc<BlobStore>,
+		max_blob_size: u64,
+	) -> (Self, ProtocolConfig) {
+		let (sender, requests) = async_channel::bounded(INBOUND_QUEUE_SIZE);
+		let config = ProtocolConfig {
+			name: protocol_name,
+			fallback_names: Vec::new(),
+			max_request_size: MAX_REQUEST_SIZE,
+			// The blob, and the overhead of encoding it as an option.
+			max_response_size: max_blob_size.saturating_add(16),
+			request_timeout: REQUEST_TIMEOUT,
+			inbound_queue: Some(sender),
+		};
+		(Self { store, requests }, config)
+	}
+
+	/// Answers requests until the network shuts down.
+	pub async fn run(self) {
+		while let Ok(IncomingRequest { payload, pending_response, .. }) = self.requests.recv().await
+		{
+			let response = match Hash::decode(&mut &payload[..]) {
+				Ok(hash) => {
+					// Reading the disk blocks, so it is kept off the threads running the network.
+					let store = self.store.clone();
+					let blob = tokio::task::spawn_blocking(move || store.get(&hash)).await;
+					OutgoingResponse {
+						// Blobs that cannot be read are not hosted, as far as peers are concerned.
+						result: Ok(blob.ok().and_then(Result::ok).flatten().encode()),
+						reputation_changes: Vec::new(),
+						sent_feedback: None,
+					}
+				},
+				Err(_) => OutgoingResponse {
+					result: Err(()),
+					reputation_changes: vec![MALFORMED_MESSAGE],
+					sent_feedback: None,
+				},
+			};
+			// The peer may have given up waiting.
+			let _ = pending_response.send(response);
+		}
+	}
+}
+
+/// Fetches blobs from the peers of the node, and keeps them in the local [`BlobStore`].
+pub struct BlobFetcher {
+	network: Arc<NetworkService<Block, Hash>>,
+	sync: Arc<SyncingService<Block>>,
+	protocol_name: ProtocolName,
+	store: Arc<BlobStore>,
+	/// The blobs no peer sent, with when they were last asked for.
+	misses: Mutex<HashMap<Hash, Instant>>,
+}
+
+impl BlobFetcher {
+	/// Creates a fetcher requesting blobs over `protocol_name`.
+	pub fn new(
+		network: Arc<NetworkService<Block, Hash>>,
+		sync: Arc<SyncingService<Block>>,
+		protocol_name: ProtocolName,
+		store: Arc<BlobStore>,
+	) -> Self {
+		Self { network, sync, protocol_name, store, misses: Default::default() }
+	}
+
+	/// Asks the connected peers for the blob stored under `hash`, a few at a time, until one sends
+	/// it, or returns `None` if none does in time or none did recently.
+	pub async fn fetch(&self, hash: Hash) -> Option<Vec<u8>> {
+		if self.missed_recently(&hash) {
+			return None
+		}
+
+		let peers = self.sync.peers_info().await.unwrap_or_default();
+		let blobs = stream::iter(peers)
+			.map(|(peer, _)| self.request(peer, hash))
+			.buffer_unordered(MAX_PARALLEL_REQUESTS)
+			.filter_map(future::ready);
+		pin_mut!(blobs);
+		let Ok(Some(blob)) = tokio::time::timeout(FETCH_TIMEOUT, blobs.next()).await else {
+			self.record_miss(hash);
+			return None
+		};
+
+		// Keeping a copy is best effort: a full store still serves the blob it fetched. Writing
+		// the disk blocks, so it is kept off the threads of the caller.
+		let store = self.store.clone();
+		tokio::task::spawn_blocking(move || {
+			let _ = store.put(hash, &blob);
+			blob
+		})
+		.await
+		.ok()
+	}
+
+	/// Asks `peer` for the blob stored under `hash`.
+	async fn request(&self, peer: PeerId, hash: Hash) -> Option<Vec<u8>> {
+		let response = self
+			.network
+			.request(
+				peer,
+				self.protocol_name.clone(),
+				hash.encode(),
+				None,
+				IfDisconnected::ImmediateError,
+			)
+			.await;
+		// Peers that are unreachable or do not speak the protocol are skipped.
+		let (response, _) = response.ok()?;
+
+		let blob = match Option::<Vec<u8>>::decode(&mut &response[..]) {
+			Ok(blob) => blob?,
+			Err(_) => {
+				self.network.report_peer(peer, MALFORMED_MESSAGE);
+				return None
+			},
+		};
+		if blob_store::hash_of(&blob) != hash {
+			self.network.report_peer(peer, BAD_BLOB);
+			return None
+		}
+		Some(blob)
+	}
+
+	fn missed_recently(&self, hash: &Hash) -> bool {
+		let misses = self.misses.lock().expect("no panics while the lock is held; qed");
+		misses.get(hash).is_some_and(|missed| missed.elapsed() < MISS_TTL)
+	}
+
+	fn record_miss(&self, hash: Hash) {
+		let mut misses = self.misses.lock().expect("no panics while the lock is held; qed");
+		if misses.len() >= MAX_MISSES {
+			misses.retain(|_, missed| missed.elapsed() < MISS_TTL);
+		}
+		// When too many blobs were missed recently, the oldest miss is forgotten.
+		if misses.len() >= MAX_MISSES {
+			let oldest = misses.iter().min_by_key(|(_, missed)| **missed).map(|(hash, _)| *hash);
+			if let Some(oldest) = oldest {
+				misses.remove(&oldest);
+			}
+		}
+		misses.insert(hash, Instant::now());
+	}
+}
@@ -0,0 +1,362 @@
+//! A content-addressed store of encrypted manuscript blobs, kept on disk next to the databases of
+//! the node.
+//!
+//! Only content hashes fit on chain, so the node hosts the documents themselves, encrypted by their
+//! authors. A blob is stored under the hash of its bytes, computed with the runtime's hashing
+//! algorithm, which is checked on every write. Blobs are written to a temporary file first and then
+//! renamed, so that a crash never leaves a truncated blob under a valid name. The space a blob
+//! takes is reserved before it is written, so that blobs are written concurrently without
+//! exceeding the capacity of the store.
+
+use std::{
+	collections::HashSet,
+	fmt, fs, io,
+	path::{Path, PathBuf},
+	sync::{Mutex, MutexGuard},
+};
+
+use sp_runtime::traits::Hash as _;
+use speem_runtime::{Hash, Runtime};
+
+/// The suffix of blobs being written.
+const PARTIAL_SUFFIX: &str = "partial";
+
+/// The bytes a JSON-RPC call or response takes besides the blob it carries.
+const RPC_ENVELOPE_SIZE: u64 = 1024;
+
+/// The command line parameters of the blob store.
+#[derive(Debug, Clone, clap::Args)]
+pub struct BlobStoreParams {
+	/// The largest blob the node accepts, in MiB.
+	///
+	/// Blobs go through the RPC server hex-encoded, i.e. twice their size, so the default fits the
+	/// default `--rpc-max-request-size` and `--rpc-max-response-size` of 15 MiB.
+	#[arg(long, value_name = "MIB", default_value_t = 7)]
+	pub blob_max_size: u64,
+
+	/// The disk space the blob store may use, in MiB.
+	#[arg(long, value_name = "MIB", default_value_t = 10 * 1024)]
+	pub blob_store_capacity: u64,
+}
+
+/// Why a blob could not be stored or read.
+#[derive(Debug)]
+pub enum Error {
+	/// The blob is larger than the store accepts.
+	TooLarge {
+		/// The size of the blob, in bytes.
+		size: u64,
+		/// The largest blob accepted, in bytes.
+		max: u64,
+	},
+	/// Storing the blob would exceed the capacity of the store.
+	QuotaExceeded {
+		/// The capacity left, in bytes.
+		available: u64,
+	},
+	/// The blob does not hash to the hash it was submitted under.
+	HashMismatch {
+		/// The hash of the blob.
+		actual: Hash,
+	},
+	/// Reading or writing the disk failed.
+	Io(io::Error),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Error::TooLarge { size, max } =>
+				write!(f, "The blob is {size} bytes long, more than the {max} bytes accepted."),
+			Error::QuotaExceeded { available } =>
+				write!(f, "The blob store has only {available} bytes left."),
+			Error::HashMismatch { actual } => write!(f, "The blob hashes to {actual:?}."),
+			Error::Io(e) => write!(f, "I/O error: {e}"),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+	fn from(e: io::Error) -> Self {
+		Error::Io(e)
+	}
+}
+
+/// The hash of `blob`, as the runtime computes it.
+pub fn hash_of(blob: &[u8]) -> Hash {
+	<Runtime as frame_system::Config>::Hashing::hash(blob)
+}
+
+/// The space taken in a [`BlobStore`].
+#[derive(Default)]
+struct Usage {
+	/// The number of bytes stored or reserved for the blobs being written.
+	used: u64,
+	/// The hashes of the blobs being written.
+	writing: HashSet<Hash>,
+}
+
+/// The size of the JSON-RPC call or response carrying a blob of `size` bytes, hex-encoded.
+pub fn rpc_payload_size(size: u64) -> u64 {
+	size.saturating_mul(2).saturating_add(RPC_ENVELOPE_SIZE)
+}
+
+/// A content-addressed store of blobs on disk.
+pub struct BlobStore {
+	root: PathBuf,
+	max_blob_size: u64,
+	capacity: u64,
+	usage: Mutex<Usage>,
+}
+
+impl BlobStore {
+	/// Opens the store in `root`, creating it if needed and removing blobs left partially written.
+	pub fn open(root: PathBuf, params: &BlobStoreParams) -> io::Result<Self> {
+		fs::create_dir_all(&root)?;
+		let mut used = 0;
+		for shard in fs::read_dir(&root)? {
+			let shard = shard?;
+			if !shard.file_type()?.is_dir() {
+				continue
+			}
+			for blob in fs::read_dir(shard.path())? {
+				let blob = blob?;
+				if blob.path().extension().is_some_and(|ext| ext == PARTIAL_SUFFIX) {
+					fs::remove_file(blob.path())?;
+				} else {
+					used += blob.metadata()?.len();
+				}
+			}
+		}
+
+		Ok(Self {
+			root,
+			max_blob_size: params.blob_max_size.saturating_mul(1024 * 1024),
+			capacity: params.blob_store_capacity.saturating_mul(1024 * 1024),
+			usage: Mutex::new(Usage { used, writing: HashSet::new() }),
+		})
+	}
+
+	/// Stores `blob` under `hash`, which must be its hash. Returns whether it was not stored or
+	/// being stored yet.
+	///
+	/// The disk is written without holding the lock of the store, which is only taken to reserve
+	/// the space of the blob and to release it if the write fails.
+	pub fn put(&self, hash: Hash, blob: &[u8]) -> Result<bool, Error> {
+		let size = blob.len() as u64;
+		if size > self.max_blob_size {
+			return Err(Error::TooLarge { size, max: self.max_blob_size })
+		}
+		let actual = hash_of(blob);
+		if actual != hash {
+			return Err(Error::HashMismatch { actual })
+		}
+
+		let path = self.path(&hash);
+		{
+			let mut usage = self.usage();
+			if usage.writing.contains(&hash) || path.exists() {
+				return Ok(false)
+			}
+			let available = self.capacity.saturating_sub(usage.used);
+			if size > available {
+				return Err(Error::QuotaExceeded { available })
+			}
+			usage.used += size;
+			usage.writing.insert(hash);
+		}
+
+		let written = Self::write(&path, blob);
+		let mut usage = self.usage();
+		usage.writing.remove(&hash);
+		if written.is_err() {
+			usage.used -= size;
+		}
+		written.map(|()| true).map_err(Into::into)
+	}
+
+	/// Writes `blob` to `path` through a partial file, removed if the write fails.
+	fn write(path: &Path, blob: &[u8]) -> io::Result<()> {
+		fs::create_dir_all(path.parent().expect("blobs are stored in a shard; qed"))?;
+		let partial = path.with_extension(PARTIAL_SUFFIX);
+		let written = fs::write(&partial, blob).and_then(|()| fs::rename(&partial, path));
+		if written.is_err() {
+			let _ = fs::remove_file(&partial);
+		}
+		written
+	}
+
+	/// The space taken in the store, locked.
+	fn usage(&self) -> MutexGuard<Usage> {
+		self.usage.lock().expect("no panics while the lock is held; qed")
+	}
+
+	/// The largest blob the store accepts, in bytes.
+	pub fn max_blob_size(&self) -> u64 {
+		self.max_blob_size
+	}
+
+	/// The blob stored under `hash`, if any.
+	pub fn get(&self, hash: &Hash) -> Result<Option<Vec<u8>>, Error> {
+		match fs::read(self.path(hash)) {
+			Ok(blob) => Ok(Some(blob)),
+			Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+			Err(e) => Err(e.into()),
+		}
+	}
+
+	/// Blobs are sharded by the first byte of their hash, to keep directories small.
+	fn path(&self, hash: &Hash) -> PathBuf {
+		self.root.join(format!("{:02x}", hash[0])).join(format!("{hash:x}"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const MIB: usize = 1024 * 1024;
+
+	fn store(dir: &tempfile::TempDir, max_size: u64, capacity: u64) -> BlobStore {
+		let params = BlobStoreParams { blob_max_size: max_size, blob_store_capacity: capacity };
+		BlobStore::open(dir.path().to_path_buf(), &params).unwrap()
+	}
+
+	#[test]
+	fn stores_blobs_once_under_their_hash() {
+		let dir = tempfile::tempdir().unwrap();
+		let blobs = store(&dir, 1, 1);
+		let blob = b"encrypted manuscript".to_vec();
+
+		assert!(blobs.put(hash_of(&blob), &blob).unwrap());
+		assert!(!blobs.put(hash_of(&blob), &blob).unwrap());
+		assert_eq!(blobs.get(&hash_of(&blob)).unwrap(), Some(blob));
+		assert_eq!(blobs.get(&Hash::repeat_byte(1)).unwrap(), None);
+	}
+
+	#[test]
+	fn rejects_blobs_under_another_hash() {
+		let dir = tempfile::tempdir().unwrap();
+		let blobs = store(&dir, 1, 1);
+		let blob = b"encrypted manuscript";
+
+		assert!(matches!(
+			blobs.put(Hash::repeat_byte(1), blob),
+			Err(Error::HashMismatch { actual }) if actual == hash_of(blob)
+		));
+		assert_eq!(blobs.get(&Hash::repeat_byte(1)).unwrap(), None);
+	}
+
+	#[test]
+	fn rejects_blobs_larger_than_the_maximum() {
+		let dir = tempfile::tempdir().unwrap();
+		let blobs = store(&dir, 1, 4);
+		let blob = vec![0; MIB + 1];
+
+		assert!(matches!(
+			blobs.put(hash_of(&blob), &blob),
+			Err(Error::TooLarge { size, max }) if size == MIB as u64 + 1 && max == MIB as u64
+		));
+		assert!(blobs.put(hash_of(&blob[..MIB]), &blob[..MIB]).unwrap());
+	}
+
+	#[test]
+	fn rejects_blobs_beyond_the_capacity() {
+		let dir = tempfile::tempdir().unwrap();
+		let blobs = store(&dir, 1, 1);
+		let (first, second) = (vec![1; MIB / 2 + 1], vec![2; MIB / 2]);
+
+		assert!(blobs.put(hash_of(&first), &first).unwrap());
+		assert!(matches!(
+			blobs.put(hash_of(&second), &second),
+			Err(Error::QuotaExceeded { available }) if available == MIB as u64 / 2 - 1
+		));
+		// A blob already stored takes no more space.
+		assert!(!blobs.put(hash_of(&first), &first).unwrap());
+	}
+
+	#[test]
+	fn counts_stored_blobs_against_the_capacity_on_open() {
+		let dir = tempfile::tempdir().unwrap();
+		let blob = vec![1; MIB / 2 + 1];
+		assert!(store(&dir, 1, 1).put(hash_of(&blob), &blob).unwrap());
+
+		let blobs = store(&dir, 1, 1);
+		let other = vec![2; MIB / 2];
+		assert!(matches!(blobs.put(hash_of(&other), &other), Err(Error::QuotaExceeded { .. })));
+		assert_eq!(blobs.get(&hash_of(&blob)).unwrap(), Some(blob));
+	}
+
+	#[test]
+	fn open_removes_partially_written_blobs() {
+		let dir = tempfile::tempdir().unwrap();
+		let blob = vec![1; MIB / 2 + 1];
+		let hash = hash_of(&blob);
+		let partial = store(&dir, 1, 1).path(&hash).with_extension(PARTIAL_SUFFIX);
+		fs::create_dir_all(partial.parent().unwrap()).unwrap();
+		fs::write(&partial, &blob).unwrap();
+
+		let blobs = store(&dir, 1, 1);
+		assert!(!partial.exists());
+		assert_eq!(blobs.get(&hash).unwrap(), None);
+		// The partial blob takes no space, so the whole of it can be stored again.
+		assert!(blobs.put(hash, &blob).unwrap());
+		assert_eq!(blobs.get(&hash).unwrap(), Some(blob));
+	}
+
+	#[test]
+	fn default_blob_max_size_fits_the_default_rpc_limits() {
+		use clap::Parser;
+
+		#[derive(Parser)]
+		struct Cli {
+			#[command(flatten)]
+			blobs: BlobStoreParams,
+		}
+
+		let params = Cli::parse_from(["speem"]).blobs;
+		assert!(rpc_payload_size(params.blob_max_size * MIB as u64) <= 15 * MIB as u64);
+	}
+
+	#[test]
+	fn releases_the_space_of_failed_writes() {
+		let dir = tempfile::tempdir().unwrap();
+		let blobs = store(&dir, 1, 1);
+		let blob = vec![1; MIB / 2 + 1];
+		let hash = hash_of(&blob);
+		// A file in place of its shard keeps the blob from being written.
+		let shard = blobs.path(&hash).parent().unwrap().to_path_buf();
+		fs::write(&shard, b"").unwrap();
+
+		assert!(matches!(blobs.put(hash, &blob), Err(Error::Io(_))));
+		fs::remove_file(&shard).unwrap();
+		assert!(blobs.put(hash, &blob).unwrap());
+		assert_eq!(blobs.get(&hash).unwrap(), Some(blob));
+	}
+
+	#[test]
+	fn writes_blobs_concurrently_within_the_capacity() {
+		let dir = tempfile::tempdir().unwrap();
+		let blobs = &store(&dir, 1, 1);
+		let candidates: Vec<_> = (0..4u8).map(|i| vec![i; MIB / 3]).collect();
+
+		let stored = std::thread::scope(|scope| {
+			let writers: Vec<_> = candidates
+				.iter()
+				.map(|blob| scope.spawn(move || blobs.put(hash_of(blob), blob)))
+				.collect();
+			writers.into_iter().map(|writer| writer.join().unwrap()).collect::<Vec<_>>()
+		});
+		// Three of the blobs fit, whichever are written first.
+		assert_eq!(stored.iter().filter(|put| matches!(put, Ok(true))).count(), 3);
+		assert_eq!(
+			stored
+				.iter()
+				.filter(|put| matches!(put, Err(Error::QuotaExceeded { .. })))
+				.count(),
+			1
+		);
+	}
+}
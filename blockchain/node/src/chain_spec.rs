@@ -1,4 +1,4 @@
-use speem_runtime::{AccountId, RuntimeGenesisConfig, Signature, WASM_BINARY};
+use speem_runtime::{opaque::SessionKeys, AccountId, RuntimeGenesisConfig, Signature, WASM_BINARY};
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -28,9 +28,17 @@ where
 	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
 }
 
-/// Generate an Aura authority key.
-pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
-	(get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
+/// Generate a validator account and its Aura and GRANDPA session keys.
+pub fn authority_keys_from_seed(s: &str) -> (AccountId, AuraId, GrandpaId) {
+	(
+		get_account_id_from_seed::<sr25519::Public>(s),
+		get_from_seed::<AuraId>(s),
+		get_from_seed::<GrandpaId>(s),
+	)
+}
+
+fn session_keys(aura: AuraId, grandpa: GrandpaId) -> SessionKeys {
+	SessionKeys { aura, grandpa }
 }
 
 pub fn development_config() -> Result<ChainSpec, String> {
@@ -42,10 +50,17 @@ pub fn development_config() -> Result<ChainSpec, String> {
 	.with_id("dev")
 	.with_chain_type(ChainType::Development)
 	.with_genesis_config_patch(testnet_genesis(
-		// Initial PoA authorities
+		// Initial validators, run by the founding institutions in order
 		vec![authority_keys_from_seed("Alice")],
-		// Sudo account
-		get_account_id_from_seed::<sr25519::Public>("Alice"),
+		// Founding institutions, whose first admins form the council
+		vec![(
+			"Development University",
+			*b"CH",
+			"dev.speem.app",
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+		)],
+		// Technical committee
+		vec![get_account_id_from_seed::<sr25519::Public>("Alice")],
 		// Pre-funded accounts
 		vec![
 			get_account_id_from_seed::<sr25519::Public>("Alice"),
@@ -67,10 +82,28 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	.with_id("local_testnet")
 	.with_chain_type(ChainType::Local)
 	.with_genesis_config_patch(testnet_genesis(
-		// Initial PoA authorities
+		// Initial validators, run by the founding institutions in order
 		vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
-		// Sudo account
-		get_account_id_from_seed::<sr25519::Public>("Alice"),
+		// Founding institutions, whose first admins form the council
+		vec![
+			(
+				"Alice University",
+				*b"CH",
+				"alice.speem.app",
+				get_account_id_from_seed::<sr25519::Public>("Alice"),
+			),
+			(
+				"Bob Institute of Technology",
+				*b"FR",
+				"bob.speem.app",
+				get_account_id_from_seed::<sr25519::Public>("Bob"),
+			),
+		],
+		// Technical committee
+		vec![
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+			get_account_id_from_seed::<sr25519::Public>("Bob"),
+		],
 		// Pre-funded accounts
 		vec![
 			get_account_id_from_seed::<sr25519::Public>("Alice"),
@@ -93,8 +126,9 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
-	initial_authorities: Vec<(AuraId, GrandpaId)>,
-	root_key: AccountId,
+	initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
+	institutions: Vec<(&str, [u8; 2], &str, AccountId)>,
+	technical_committee: Vec<AccountId>,
 	endowed_accounts: Vec<AccountId>,
 	_enable_println: bool,
 ) -> serde_json::Value {
@@ -103,15 +137,32 @@ fn testnet_genesis(
 			// Configure endowed accounts with initial balance of 1 << 60.
 			"balances": endowed_accounts.iter().cloned().map(|k| (k, 1u64 << 60)).collect::<Vec<_>>(),
 		},
-		"aura": {
-			"authorities": initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>(),
+		"institutions": {
+			"institutions": institutions
+				.into_iter()
+				.map(|(name, country, domain, admin)| {
+					(name.as_bytes().to_vec(), country, domain.as_bytes().to_vec(), vec![admin])
+				})
+				.collect::<Vec<_>>(),
+		},
+		"validatorSet": {
+			"validators": initial_authorities
+				.iter()
+				.enumerate()
+				.map(|(institution, x)| (institution as u32, x.0.clone()))
+				.collect::<Vec<_>>(),
 		},
-		"grandpa": {
-			"authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
+		// The Aura and GRANDPA authorities are set from the first session's validators.
+		"session": {
+			"keys": initial_authorities
+				.iter()
+				.map(|x| (x.0.clone(), x.0.clone(), session_keys(x.1.clone(), x.2.clone())))
+				.collect::<Vec<_>>(),
 		},
-		"sudo": {
-			// Assign network admin rights.
-			"key": Some(root_key),
+		// The council is made up of the institutions' representatives; the technical committee is
+		// appointed by it from then on.
+		"technicalMembership": {
+			"members": technical_committee,
 		},
 	})
 }
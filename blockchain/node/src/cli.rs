@@ -1,3 +1,4 @@
+use crate::blob_store::BlobStoreParams;
 use sc_cli::RunCmd;
 
 #[derive(Debug, clap::Parser)]
@@ -7,6 +8,9 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	#[clap(flatten)]
+	pub blob_store: BlobStoreParams,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -48,4 +52,15 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Check whether a local file is registered on chain.
+	Verify(crate::verify::VerifyCmd),
+
+	/// Export and verify receipts proving the registration of a file offline.
+	#[command(subcommand)]
+	Receipt(crate::receipt::ReceiptCmd),
+
+	/// Commit to the chunks or sections of a file and prove their inclusion.
+	#[command(subcommand)]
+	Commitment(crate::commitment::CommitmentCmd),
 }
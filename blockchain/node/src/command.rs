@@ -2,6 +2,8 @@ use crate::{
 	benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder},
 	chain_spec,
 	cli::{Cli, Subcommand},
+	commitment::CommitmentCmd,
+	receipt::ReceiptCmd,
 	service,
 };
 use frame_benchmarking_cli::{BenchmarkCmd, ExtrinsicFactory, SUBSTRATE_REFERENCE_HARDWARE};
@@ -180,10 +182,38 @@ pub fn run() -> sc_cli::Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run::<Block>(&config))
 		},
+		Some(Subcommand::Verify(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			match &cmd.rpc_url {
+				Some(url) => runner.async_run(|config| {
+					let task_manager = sc_service::TaskManager::new(config.tokio_handle, None)
+						.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+					Ok((cmd.run_remote(url), task_manager))
+				}),
+				None => runner.async_run(|config| {
+					let PartialComponents { client, task_manager, .. } =
+						service::new_partial(&config)?;
+					Ok((cmd.run(client), task_manager))
+				}),
+			}
+		},
+		Some(Subcommand::Receipt(ReceiptCmd::Export(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, backend, task_manager, other, .. } =
+					service::new_partial(&config)?;
+				let authority_set = other.1.shared_authority_set().clone();
+				Ok((cmd.run(client, backend, authority_set), task_manager))
+			})
+		},
+		Some(Subcommand::Receipt(ReceiptCmd::Verify(cmd))) => cmd.run(),
+		Some(Subcommand::Commitment(CommitmentCmd::Compute(cmd))) => cmd.run(),
+		Some(Subcommand::Commitment(CommitmentCmd::Prove(cmd))) => cmd.run(),
 		None => {
 			let runner = cli.create_runner(&cli.run)?;
+			let blob_store = cli.blob_store.clone();
 			runner.run_node_until_exit(|config| async move {
-				service::new_full(config).map_err(sc_cli::Error::Service)
+				service::new_full(config, blob_store).map_err(sc_cli::Error::Service)
 			})
 		},
 	}
@@ -0,0 +1,321 @@
+//! The `commitment` subcommand, which computes the Merkle commitment a file is registered with and
+//! proves that its parts are included in it.
+//!
+//! A file is committed to either by its chunks of [`merkle::CHUNK_SIZE`] bytes or, given the
+//! offsets at which its sections start, by its sections. `commitment compute` prints the commitment
+//! to submit with the registration of the file, and `commitment prove` writes the proof that one of
+//! its parts is included, which `speem_verifyInclusion` checks against the registry without the
+//! rest of the file. The content hash the commitment is printed with is computed with the
+//! algorithm the file is registered with, while the tree is always built with the runtime's.
+
+use std::path::PathBuf;
+
+use codec::Encode;
+use serde::Serialize;
+use sp_core::{hexdisplay::HexDisplay, Bytes};
+use sp_runtime::traits::BlakeTwo256;
+use speem_primitives::{merkle, multihash::HashAlgorithm};
+use speem_runtime::{
+	pallet_manuscripts::{Layout, MerkleCommitment},
+	Hash,
+};
+
+use crate::{content_id, verify::parse_algorithm};
+
+/// The `commitment` command used to commit to the parts of a file and prove their inclusion.
+#[derive(Debug, clap::Subcommand)]
+pub enum CommitmentCmd {
+	/// Print the Merkle commitment to register a file with.
+	Compute(ComputeCommitmentCmd),
+
+	/// Write the proof that a chunk or section of a file is included in its commitment.
+	Prove(ProveInclusionCmd),
+}
+
+/// The file to commit to and how it is cut into parts.
+#[derive(Debug, Clone, clap::Args)]
+pub struct PartsParams {
+	/// The file.
+	#[arg(value_name = "FILE")]
+	pub file: PathBuf,
+
+	/// Commit to the sections of the file starting at these byte offsets, in increasing order,
+	/// instead of to its chunks. The first section starts at offset 0 and need not be listed.
+	#[arg(long, value_name = "OFFSETS", value_delimiter = ',')]
+	pub sections: Option<Vec<usize>>,
+
+	/// The algorithm the file is hashed with when it is registered: `sha2-256`, `sha3-256` or
+	/// `blake2b-256`.
+	#[arg(
+		long,
+		value_name = "ALGORITHM",
+		default_value = "blake2b-256",
+		value_parser = parse_algorithm
+	)]
+	pub hash_algorithm: HashAlgorithm,
+}
+
+/// A file cut into the parts it is committed to.
+struct Parts {
+	content_hash: Hash,
+	hash_algorithm: HashAlgorithm,
+	layout: Layout,
+	parts: Vec<Vec<u8>>,
+}
+
+impl PartsParams {
+	fn read(&self) -> sc_cli::Result<Parts> {
+		let data = std::fs::read(&self.file)?;
+		let content_hash = content_id::digest(self.hash_algorithm, &data);
+		let Some(offsets) = &self.sections else {
+			let parts = (0..merkle::chunk_count(data.len()))
+				.filter_map(|index| merkle::chunk(&data, index).map(<[u8]>::to_vec))
+				.collect();
+			return Ok(Parts {
+				content_hash,
+				hash_algorithm: self.hash_algorithm,
+				layout: Layout::Chunks,
+				parts,
+			})
+		};
+
+		let mut starts = vec![0];
+		for &offset in offsets.iter().filter(|&&offset| offset != 0) {
+			if offset <= *starts.last().expect("starts at 0; qed") || offset >= data.len() {
+				return Err(input(format!(
+					"Section offsets must increase and be below the size of the file, {} bytes.",
+					data.len()
+				)))
+			}
+			starts.push(offset);
+		}
+		let parts = starts
+			.iter()
+			.zip(starts.iter().skip(1).chain([&data.len()]))
+			.map(|(&start, &end)| data[start..end].to_vec())
+			.collect();
+		Ok(Parts {
+			content_hash,
+			hash_algorithm: self.hash_algorithm,
+			layout: Layout::Sections,
+			parts,
+		})
+	}
+}
+
+impl Parts {
+	fn leaves(&self) -> Vec<Hash> {
+		self.parts.iter().map(|part| merkle::leaf_hash::<BlakeTwo256>(part)).collect()
+	}
+
+	fn commitment(&self) -> MerkleCommitment<Hash> {
+		MerkleCommitment {
+			layout: self.layout,
+			root: merkle::root_of_leaves::<BlakeTwo256>(self.leaves())
+				.expect("a file has at least one part; qed"),
+			leaves: self.parts.len() as u32,
+		}
+	}
+
+	fn prove(&self, index: u32) -> Option<InclusionProof> {
+		let part = self.parts.get(index as usize)?;
+		Some(InclusionProof {
+			content_hash: self.content_hash,
+			layout: layout_name(self.layout),
+			index,
+			part: Bytes(part.clone()),
+			proof: merkle::prove_leaf::<BlakeTwo256>(self.leaves(), index)?,
+		})
+	}
+}
+
+/// The `commitment compute` command used to print the commitment to register a file with.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ComputeCommitmentCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub parts: PartsParams,
+}
+
+impl ComputeCommitmentCmd {
+	/// Print the content hash of the file and its commitment.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let parts = self.parts.read()?;
+		let commitment = parts.commitment();
+
+		println!("Content hash: {:?} ({})", parts.content_hash, parts.hash_algorithm.name());
+		println!("Layout:       {}", layout_name(commitment.layout));
+		println!("Root:         {:?}", commitment.root);
+		println!("Leaves:       {}", commitment.leaves);
+		println!("Encoded:      0x{}", HexDisplay::from(&commitment.encode()));
+		Ok(())
+	}
+}
+
+/// The `commitment prove` command used to write the inclusion proof of a part of a file.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ProveInclusionCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub parts: PartsParams,
+
+	/// The position of the chunk or section to prove, from 0.
+	#[arg(long)]
+	pub index: u32,
+
+	/// Where to write the proof, rather than to the standard output.
+	#[arg(long, short, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+}
+
+/// The proof that a part of a file is included in its commitment, in the shape of the parameters
+/// of `speem_verifyInclusion`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionProof {
+	/// The content hash the file is registered under.
+	pub content_hash: Hash,
+	/// How the file is cut into parts, `chunks` or `sections`.
+	pub layout: &'static str,
+	/// The position of the part.
+	pub index: u32,
+	/// The part.
+	pub part: Bytes,
+	/// The siblings of the part on its path to the root, from the bottom up.
+	pub proof: Vec<Hash>,
+}
+
+impl ProveInclusionCmd {
+	/// Write the proof as JSON.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let parts = self.parts.read()?;
+		let Some(proof) = parts.prove(self.index) else {
+			return Err(input(format!("The file has only {} parts.", parts.parts.len())))
+		};
+
+		let json = serde_json::to_string_pretty(&proof).map_err(|e| input(e.to_string()))?;
+		match &self.output {
+			Some(path) => std::fs::write(path, json)?,
+			None => println!("{json}"),
+		}
+		Ok(())
+	}
+}
+
+fn layout_name(layout: Layout) -> &'static str {
+	match layout {
+		Layout::Chunks => "chunks",
+		Layout::Sections => "sections",
+	}
+}
+
+fn input(message: String) -> sc_cli::Error {
+	sc_cli::Error::Input(message)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde::Deserialize;
+
+	/// The parameters of `speem_verifyInclusion`, as they are read from a proof.
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct VerifyInclusionParams {
+		content_hash: Hash,
+		index: u32,
+		part: Bytes,
+		proof: Vec<Hash>,
+	}
+
+	fn parts(data: &[u8], sections: Option<Vec<usize>>) -> sc_cli::Result<Parts> {
+		let dir = tempfile::tempdir().unwrap();
+		let file = dir.path().join("manuscript");
+		std::fs::write(&file, data).unwrap();
+		PartsParams { file, sections, hash_algorithm: HashAlgorithm::Blake2b256 }.read()
+	}
+
+	/// The parameters `speem_verifyInclusion` reads from the JSON proof of the `index`th part.
+	fn round_trip(parts: &Parts, index: u32) -> VerifyInclusionParams {
+		let json = serde_json::to_string(&parts.prove(index).unwrap()).unwrap();
+		serde_json::from_str(&json).unwrap()
+	}
+
+	#[test]
+	fn sections_start_at_the_given_offsets() {
+		let parts = parts(b"abstract body references", Some(vec![0, 9, 14])).unwrap();
+
+		assert_eq!(parts.layout, Layout::Sections);
+		assert_eq!(
+			parts.parts,
+			vec![b"abstract ".to_vec(), b"body ".to_vec(), b"references".to_vec()]
+		);
+		assert_eq!(parts.commitment().leaves, 3);
+	}
+
+	#[test]
+	fn section_offsets_must_increase_within_the_file() {
+		let data = b"abstract body references";
+
+		assert!(parts(data, Some(vec![14, 9])).is_err());
+		assert!(parts(data, Some(vec![9, 9])).is_err());
+		assert!(parts(data, Some(vec![9, data.len()])).is_err());
+		assert!(parts(data, Some(vec![9, data.len() + 1])).is_err());
+	}
+
+	/// A file of three chunks, none of which repeats another.
+	fn file() -> Vec<u8> {
+		(0..merkle::CHUNK_SIZE * 2 + 1).map(|i| (i * 31 % 251) as u8).collect()
+	}
+
+	#[test]
+	fn chunks_are_committed_to_without_offsets() {
+		let data = file();
+		let parts = parts(&data, None).unwrap();
+		let commitment = parts.commitment();
+
+		assert_eq!(commitment.layout, Layout::Chunks);
+		assert_eq!(commitment.leaves, 3);
+		assert_eq!(commitment.root, merkle::root::<BlakeTwo256>(&data));
+		assert_eq!(parts.content_hash, content_id::digest(HashAlgorithm::Blake2b256, &data));
+	}
+
+	#[test]
+	fn content_hashes_use_the_registered_algorithm() {
+		let dir = tempfile::tempdir().unwrap();
+		let file = dir.path().join("manuscript");
+		std::fs::write(&file, b"hello world").unwrap();
+		let params = PartsParams { file, sections: None, hash_algorithm: HashAlgorithm::Sha2_256 };
+		let parts = params.read().unwrap();
+
+		assert_eq!(parts.content_hash, sp_core::hashing::sha2_256(b"hello world").into());
+		// The tree is still built with the runtime's hashing algorithm.
+		assert_eq!(parts.commitment().root, merkle::root::<BlakeTwo256>(b"hello world"));
+	}
+
+	#[test]
+	fn proofs_verify_against_the_commitment() {
+		let data = file();
+		for sections in [None, Some(vec![100, 1500, 2000])] {
+			let parts = parts(&data, sections).unwrap();
+			let commitment = parts.commitment();
+
+			for index in 0..commitment.leaves {
+				let params = round_trip(&parts, index);
+				assert_eq!(params.content_hash, parts.content_hash);
+				assert_eq!(params.index, index);
+				assert!(commitment.includes::<BlakeTwo256>(
+					params.index,
+					&params.part,
+					&params.proof
+				));
+				assert!(!commitment.includes::<BlakeTwo256>(
+					(params.index + 1) % commitment.leaves,
+					&params.part,
+					&params.proof
+				));
+			}
+			assert!(parts.prove(commitment.leaves).is_none());
+		}
+	}
+}
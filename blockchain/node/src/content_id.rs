@@ -0,0 +1,198 @@
+//! Content identifiers: hashing files with any algorithm the registry accepts, and converting the
+//! resulting multihashes to and from IPFS CIDs.
+//!
+//! A CID v1 is the concatenation of the version of the CID, the multicodec of the content and the
+//! multihash of the content, the first two as unsigned varints, written in a multibase. Manuscripts
+//! are stored as they are, so their CIDs use the `raw` codec, and they are written in base32, the
+//! default of IPFS, whose multibase prefix is `b`. A manuscript registered under a content hash is
+//! then found in content-addressed storage under the CID of the same multihash, and conversely.
+
+use std::fmt;
+
+use sha3::{Digest as _, Sha3_256};
+use sp_core::hashing::{blake2_256, sha2_256};
+use speem_primitives::multihash::{decode_varint, encode_varint, HashAlgorithm, Multihash};
+use speem_runtime::Hash;
+
+/// The version of the CIDs written by this node.
+pub const CID_VERSION: u64 = 1;
+
+/// The multicodec of content stored as it is.
+pub const RAW_CODEC: u64 = 0x55;
+
+/// The multibase prefix of lowercase base32 without padding.
+const BASE32_PREFIX: char = 'b';
+
+/// The digits of lowercase base32, as defined by RFC 4648.
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Why a string is not the CID of a manuscript.
+#[derive(Debug)]
+pub enum Error {
+	/// The CID is not written in base32.
+	Multibase,
+	/// The CID is not of version 1.
+	Version(u64),
+	/// The CID is not of raw content.
+	Codec(u64),
+	/// The multihash is malformed, or made with an algorithm the registry does not accept.
+	Multihash,
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Error::Multibase => write!(f, "The CID is not written in base32."),
+			Error::Version(version) => write!(f, "The CID is of version {version}, not 1."),
+			Error::Codec(codec) => write!(f, "The CID is of codec {codec:#x}, not raw."),
+			Error::Multihash =>
+				write!(f, "The CID does not hold a multihash of an accepted algorithm."),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+/// The `algorithm` digest of `data`.
+pub fn digest(algorithm: HashAlgorithm, data: &[u8]) -> Hash {
+	match algorithm {
+		HashAlgorithm::Sha2_256 => sha2_256(data).into(),
+		HashAlgorithm::Sha3_256 => <[u8; 32]>::from(Sha3_256::digest(data)).into(),
+		HashAlgorithm::Blake2b256 => blake2_256(data).into(),
+	}
+}
+
+/// The CID v1 of the raw content identified by `multihash`.
+pub fn to_cid(multihash: &Multihash<Hash>) -> String {
+	let mut bytes = Vec::new();
+	encode_varint(CID_VERSION, &mut bytes);
+	encode_varint(RAW_CODEC, &mut bytes);
+	bytes.extend(multihash.to_bytes());
+
+	let mut cid = String::from(BASE32_PREFIX);
+	cid.push_str(&base32_encode(&bytes));
+	cid
+}
+
+/// The multihash of the raw content identified by the CID v1 `cid`.
+pub fn from_cid(cid: &str) -> Result<Multihash<Hash>, Error> {
+	let bytes = cid
+		.strip_prefix(BASE32_PREFIX)
+		.and_then(base32_decode)
+		.ok_or(Error::Multibase)?;
+	let mut input = &bytes[..];
+	match decode_varint(&mut input).ok_or(Error::Multihash)? {
+		CID_VERSION => (),
+		version => return Err(Error::Version(version)),
+	}
+	match decode_varint(&mut input).ok_or(Error::Multihash)? {
+		RAW_CODEC => (),
+		codec => return Err(Error::Codec(codec)),
+	}
+	Multihash::from_bytes(input).ok_or(Error::Multihash)
+}
+
+fn base32_encode(bytes: &[u8]) -> String {
+	let mut text = String::with_capacity((bytes.len() * 8).div_ceil(5));
+	let (mut buffer, mut bits) = (0u32, 0);
+	for &byte in bytes {
+		buffer = (buffer << 8) | u32::from(byte);
+		bits += 8;
+		while bits >= 5 {
+			bits -= 5;
+			text.push(BASE32_ALPHABET[(buffer >> bits) as usize & 0x1f] as char);
+		}
+		buffer &= (1 << bits) - 1;
+	}
+	if bits > 0 {
+		// The last digit is padded with zero bits.
+		text.push(BASE32_ALPHABET[(buffer << (5 - bits)) as usize & 0x1f] as char);
+	}
+	text
+}
+
+fn base32_decode(text: &str) -> Option<Vec<u8>> {
+	let mut bytes = Vec::with_capacity(text.len() * 5 / 8);
+	let (mut buffer, mut bits) = (0u32, 0);
+	for digit in text.bytes() {
+		let value = BASE32_ALPHABET.iter().position(|&d| d == digit.to_ascii_lowercase())?;
+		buffer = (buffer << 5) | value as u32;
+		bits += 5;
+		if bits >= 8 {
+			bits -= 8;
+			bytes.push((buffer >> bits) as u8);
+			buffer &= (1 << bits) - 1;
+		}
+	}
+	Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const HELLO: &[u8] = b"hello world";
+
+	fn cid_of(algorithm: HashAlgorithm, data: &[u8]) -> String {
+		to_cid(&Multihash::new(algorithm, digest(algorithm, data)))
+	}
+
+	#[test]
+	fn cids_match_those_of_ipfs() {
+		assert_eq!(
+			cid_of(HashAlgorithm::Sha2_256, HELLO),
+			"bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e"
+		);
+		assert_eq!(
+			cid_of(HashAlgorithm::Sha3_256, HELLO),
+			"bafkrmidejpgh4vsdomcatgnkzcphmixtzjy7xiozol6zjiy4hp57etrzha"
+		);
+		assert_eq!(
+			cid_of(HashAlgorithm::Blake2b256, HELLO),
+			"bafk2bzaceaswza5ss4iu2ia3galz6pyo6dfm5f4dmiw2lf2de22dmf4k533ba"
+		);
+	}
+
+	#[test]
+	fn cids_decode_to_the_multihash_they_encode() {
+		for algorithm in HashAlgorithm::ALL {
+			let id = Multihash::new(algorithm, digest(algorithm, HELLO));
+			assert_eq!(from_cid(&to_cid(&id)).unwrap(), id);
+		}
+		let id = from_cid("bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e").unwrap();
+		assert_eq!(id, Multihash::new(HashAlgorithm::Sha2_256, sha2_256(HELLO).into()));
+	}
+
+	#[test]
+	fn cids_of_other_content_are_rejected() {
+		// A CID v0, written in base58.
+		assert!(matches!(
+			from_cid("QmWATWQ7fVPP2EFGu71UkfnqhYXDYH566qy47CnJDgvs8u"),
+			Err(Error::Multibase)
+		));
+		// The CID v1 of a directory, whose codec is dag-pb.
+		assert!(matches!(
+			from_cid("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+			Err(Error::Codec(0x70))
+		));
+		// A truncated multihash.
+		assert!(matches!(from_cid("bafkreifzjut3te2nhyekklss27nh3k72"), Err(Error::Multihash)));
+		assert!(matches!(from_cid("bafkreif!"), Err(Error::Multibase)));
+	}
+
+	#[test]
+	fn base32_matches_rfc_4648() {
+		for (bytes, text) in [
+			(&b""[..], ""),
+			(b"f", "my"),
+			(b"fo", "mzxq"),
+			(b"foo", "mzxw6"),
+			(b"foob", "mzxw6yq"),
+			(b"fooba", "mzxw6ytb"),
+			(b"foobar", "mzxw6ytboi"),
+		] {
+			assert_eq!(base32_encode(bytes), text);
+			assert_eq!(base32_decode(text).unwrap(), bytes);
+		}
+	}
+}
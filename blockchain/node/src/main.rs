@@ -2,11 +2,17 @@
 #![warn(missing_docs)]
 
 mod benchmarking;
+mod blob_protocol;
+mod blob_store;
 mod chain_spec;
 mod cli;
 mod command;
+mod commitment;
+mod content_id;
+mod receipt;
 mod rpc;
 mod service;
+mod verify;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
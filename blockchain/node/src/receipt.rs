@@ -0,0 +1,432 @@
+//! Provenance receipts, which prove that a file was registered on chain to anyone holding the
+//! genesis hash of the chain and a trusted GRANDPA authority set, without trusting any node.
+//!
+//! A [`Receipt`] bundles the header of a finalized block, a GRANDPA finality proof for it, the
+//! authority set that signed that proof, and a storage proof of the registry entry of the file
+//! against the state root of the header. `receipt export` builds one from the local database, and
+//! `receipt verify` checks one fully offline, for the file or content hash it is meant to prove.
+
+use std::{path::PathBuf, sync::Arc};
+
+use codec::{Decode, Encode};
+use sc_cli::{CliConfiguration, DatabaseParams, SharedParams};
+use sc_client_api::{Backend, ProofProvider, StorageProvider};
+use sc_consensus_grandpa::{
+	FinalityProof, FinalityProofProvider, GrandpaJustification, SharedAuthoritySet,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_grandpa::{AuthorityList, GrandpaApi, SetId};
+use sp_core::hashing::blake2_256;
+use sp_runtime::traits::{BlakeTwo256, Header as _};
+use sp_state_machine::{read_proof_check, StorageProof};
+use speem_runtime::{
+	opaque::{Block, Header},
+	BlockNumber, Hash,
+};
+
+use crate::verify::{print_entry, RegistryParams};
+
+/// The version of the receipt format written by this node.
+pub const RECEIPT_VERSION: u8 = 1;
+
+/// A self-contained proof that a storage entry was part of the finalized state of the chain.
+#[derive(Encode, Decode)]
+pub struct Receipt {
+	/// The version of the format, [`RECEIPT_VERSION`].
+	pub version: u8,
+	/// The hash of the genesis block of the chain.
+	pub genesis_hash: Hash,
+	/// The id of the GRANDPA authority set that signed the justification of `finality`.
+	pub set_id: SetId,
+	/// The GRANDPA authority set that signed the justification of `finality`.
+	pub authorities: AuthorityList,
+	/// The header of the block the entry is proven at.
+	pub header: Header,
+	/// The justification of `header`, or of a descendant together with the headers linking it to
+	/// `header`.
+	pub finality: FinalityProof<Header>,
+	/// The storage key of the entry.
+	pub key: Vec<u8>,
+	/// The proof of the entry against the state root of `header`.
+	pub proof: StorageProof,
+}
+
+impl Receipt {
+	/// The hash committing to the chain and authority set a receipt must be checked against.
+	pub fn authority_set_hash(&self) -> Hash {
+		authority_set_hash(&self.genesis_hash, self.set_id, &self.authorities)
+	}
+
+	/// Check that the receipt proves the entry under `key` in the finalized state of the chain
+	/// with `genesis_hash`, as signed by the authority set committed to by `authority_set_hash`,
+	/// and return the value of the entry, or why the receipt is invalid.
+	pub fn verify(
+		&self,
+		genesis_hash: &Hash,
+		authority_set_hash: &Hash,
+		key: &[u8],
+	) -> Result<Vec<u8>, String> {
+		if self.version != RECEIPT_VERSION {
+			return Err(format!("unsupported version {}", self.version))
+		}
+		if self.key != key {
+			return Err("it proves another entry than the registration of the file".into())
+		}
+		if self.genesis_hash != *genesis_hash {
+			return Err("it comes from another chain".into())
+		}
+		if self.authority_set_hash() != *authority_set_hash {
+			return Err("it is signed by an untrusted authority set".into())
+		}
+
+		// The justified block must be signed by two thirds of the authorities ...
+		let justification =
+			GrandpaJustification::<Block>::decode(&mut &self.finality.justification[..])
+				.map_err(|e| format!("bad justification: {e}"))?;
+		justification
+			.verify(self.set_id, &self.authorities)
+			.map_err(|e| format!("bad justification: {e}"))?;
+		let (_, justified) = justification.target();
+		if justified != self.finality.block {
+			return Err("the justification is for another block".into())
+		}
+
+		// ... and descend from the header the entry is proven against.
+		let mut hash = self.header.hash();
+		for header in &self.finality.unknown_headers {
+			if header.parent_hash != hash {
+				return Err("the headers do not form a chain".into())
+			}
+			hash = header.hash();
+		}
+		if hash != justified {
+			return Err("the headers do not lead to the justified block".into())
+		}
+
+		read_proof_check::<BlakeTwo256, _>(self.header.state_root, self.proof.clone(), [key])
+			.map_err(|e| format!("bad storage proof: {e:?}"))?
+			.remove(key)
+			.flatten()
+			.ok_or_else(|| "the entry is not in the proven state".into())
+	}
+}
+
+fn authority_set_hash(genesis_hash: &Hash, set_id: SetId, authorities: &AuthorityList) -> Hash {
+	blake2_256(&(genesis_hash, set_id, authorities).encode()).into()
+}
+
+/// The `receipt` command used to export and verify provenance receipts.
+#[derive(Debug, clap::Subcommand)]
+pub enum ReceiptCmd {
+	/// Write the receipt of a registered file, proven at a finalized block.
+	Export(ExportReceiptCmd),
+
+	/// Check a receipt offline, against a trusted genesis hash and authority set.
+	Verify(VerifyReceiptCmd),
+}
+
+/// The `receipt export` command used to write the receipt of a registered file.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportReceiptCmd {
+	/// The registered file.
+	#[arg(value_name = "FILE")]
+	pub file: PathBuf,
+
+	/// Where to write the receipt.
+	#[arg(long, short, value_name = "RECEIPT")]
+	pub output: PathBuf,
+
+	/// The finalized block to prove the registration at. Defaults to the latest finalized block.
+	#[arg(long, value_name = "NUMBER")]
+	pub block: Option<BlockNumber>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub registry: RegistryParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ExportReceiptCmd {
+	/// Build the receipt from the local database.
+	pub async fn run<C, B>(
+		&self,
+		client: Arc<C>,
+		backend: Arc<B>,
+		authority_set: SharedAuthoritySet<Hash, BlockNumber>,
+	) -> sc_cli::Result<()>
+	where
+		C: HeaderBackend<Block> + ProofProvider<Block> + StorageProvider<Block, B>,
+		C: ProvideRuntimeApi<Block>,
+		C::Api: GrandpaApi<Block>,
+		B: Backend<Block> + Send + Sync + 'static,
+	{
+		let content_hash = self.registry.content_hash(&self.file)?;
+		let key = self.registry.storage_key(&content_hash);
+
+		let info = client.info();
+		let number = self.block.unwrap_or(info.finalized_number);
+		if number > info.finalized_number {
+			return Err(input(format!("Block #{number} is not finalized yet.")))
+		}
+		let hash = client.hash(number)?.ok_or_else(|| input(format!("No block #{number}.")))?;
+		let header = client.expect_header(hash)?;
+		if client.storage(hash, &key)?.is_none() {
+			return Err(input(format!("{content_hash:?} is not registered at block #{number}.")))
+		}
+		let proof = client.read_proof(hash, &mut std::iter::once(&key.0[..]))?;
+
+		let finality =
+			FinalityProofProvider::<B, Block>::new_for_service(backend, Some(authority_set))
+				.prove_finality(number)
+				.map_err(|e| sc_cli::Error::Application(Box::new(e)))?
+				.ok_or_else(|| input(format!("No justification covers block #{number} yet.")))?;
+		let finality = FinalityProof::<Header>::decode(&mut &finality[..])
+			.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+
+		// The authorities in the state of the parent of the justified block signed it.
+		let justified = client.expect_header(finality.block)?;
+		let api = client.runtime_api();
+		let set_id = api.current_set_id(justified.parent_hash).map_err(api_error)?;
+		let authorities = api.grandpa_authorities(justified.parent_hash).map_err(api_error)?;
+
+		let receipt = Receipt {
+			version: RECEIPT_VERSION,
+			genesis_hash: info.genesis_hash,
+			set_id,
+			authorities,
+			header,
+			finality,
+			key: key.0,
+			proof,
+		};
+		std::fs::write(&self.output, receipt.encode())?;
+
+		println!(
+			"Wrote the receipt of {content_hash:?} at block #{number} to {}.",
+			self.output.display()
+		);
+		println!("Genesis hash:       {:?}", receipt.genesis_hash);
+		println!("Authority set hash: {:?} (set #{set_id})", receipt.authority_set_hash());
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ExportReceiptCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// The `receipt verify` command used to check a receipt offline.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct VerifyReceiptCmd {
+	/// The receipt to check.
+	#[arg(value_name = "RECEIPT")]
+	pub receipt: PathBuf,
+
+	/// The file the receipt must prove the registration of.
+	#[arg(
+		value_name = "FILE",
+		required_unless_present = "content_hash",
+		conflicts_with = "content_hash"
+	)]
+	pub file: Option<PathBuf>,
+
+	/// The content hash the receipt must prove the registration of, instead of a file.
+	#[arg(long, value_name = "HASH")]
+	pub content_hash: Option<Hash>,
+
+	/// The hash of the genesis block of the chain the receipt must come from.
+	#[arg(long, value_name = "HASH")]
+	pub genesis_hash: Hash,
+
+	/// The hash of the trusted GRANDPA authority set, as printed by `receipt export` or published
+	/// by the operators of the chain.
+	#[arg(long, value_name = "HASH")]
+	pub authority_set_hash: Hash,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub registry: RegistryParams,
+}
+
+impl VerifyReceiptCmd {
+	/// Check that the receipt proves the registration of the file, and print the entry.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let content_hash = match (&self.file, self.content_hash) {
+			(Some(file), _) => self.registry.content_hash(file)?,
+			(None, Some(content_hash)) => content_hash,
+			(None, None) => return Err(input("Pass a FILE or a --content-hash.".into())),
+		};
+		let key = self.registry.storage_key(&content_hash);
+
+		let receipt = Receipt::decode(&mut &std::fs::read(&self.receipt)?[..]).map_err(invalid)?;
+		let value = receipt
+			.verify(&self.genesis_hash, &self.authority_set_hash, &key.0)
+			.map_err(invalid)?;
+
+		println!("Receipt:      valid");
+		println!("Content hash: {content_hash:?}");
+		println!("Proven at:    finalized block #{}", receipt.header.number);
+		print_entry(&value);
+		Ok(())
+	}
+}
+
+fn input(message: String) -> sc_cli::Error {
+	sc_cli::Error::Input(message)
+}
+
+fn invalid(reason: impl std::fmt::Display) -> sc_cli::Error {
+	sc_cli::Error::Input(format!("Invalid receipt: {reason}."))
+}
+
+fn api_error(e: sp_api::ApiError) -> sc_cli::Error {
+	sc_cli::Error::Application(Box::new(e))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_consensus_grandpa::{AuthorityId, AuthoritySignature};
+	use sp_core::storage::StateVersion;
+	use sp_keyring::Ed25519Keyring;
+	use sp_runtime::Digest;
+	use sp_state_machine::{prove_read, InMemoryBackend};
+
+	const KEY: &[u8] = b"registry entry";
+	const VALUE: &[u8] = b"manuscript record";
+	const SET_ID: SetId = 3;
+	const ROUND: u64 = 7;
+
+	fn genesis_hash() -> Hash {
+		Hash::repeat_byte(1)
+	}
+
+	/// The state root of a state holding `value` under `KEY`, and the proof of the entry.
+	fn state(value: &[u8]) -> (Hash, StorageProof) {
+		let backend = InMemoryBackend::<BlakeTwo256>::from((
+			vec![(None, vec![(KEY.to_vec(), Some(value.to_vec()))])],
+			StateVersion::V1,
+		));
+		let root = *backend.root();
+		(root, prove_read(backend, [KEY]).unwrap())
+	}
+
+	fn header(number: BlockNumber, parent_hash: Hash, state_root: Hash) -> Header {
+		Header::new(number, Hash::zero(), state_root, parent_hash, Digest::default())
+	}
+
+	/// A justification of `target` signed by `signer` in `SET_ID`.
+	fn justification(target: &Header, signer: Ed25519Keyring) -> Vec<u8> {
+		let precommit = finality_grandpa::Precommit {
+			target_hash: target.hash(),
+			target_number: target.number,
+		};
+		let message = finality_grandpa::Message::Precommit(precommit.clone());
+		let payload = sp_consensus_grandpa::localized_payload(ROUND, SET_ID, &message);
+		let signed = finality_grandpa::SignedPrecommit {
+			precommit,
+			signature: AuthoritySignature::from(signer.sign(&payload)),
+			id: AuthorityId::from(signer.public()),
+		};
+		sp_consensus_grandpa::GrandpaJustification::<Header> {
+			round: ROUND,
+			commit: finality_grandpa::Commit {
+				target_hash: target.hash(),
+				target_number: target.number,
+				precommits: vec![signed],
+			},
+			votes_ancestries: Vec::new(),
+		}
+		.encode()
+	}
+
+	/// A receipt of `KEY` at block #1, justified through its child, signed by Alice alone.
+	fn receipt() -> Receipt {
+		let (state_root, proof) = state(VALUE);
+		let header = header(1, genesis_hash(), state_root);
+		let child = self::header(2, header.hash(), Hash::zero());
+		Receipt {
+			version: RECEIPT_VERSION,
+			genesis_hash: genesis_hash(),
+			set_id: SET_ID,
+			authorities: vec![(Ed25519Keyring::Alice.public().into(), 1)],
+			finality: FinalityProof {
+				block: child.hash(),
+				justification: justification(&child, Ed25519Keyring::Alice),
+				unknown_headers: vec![child],
+			},
+			header,
+			key: KEY.to_vec(),
+			proof,
+		}
+	}
+
+	fn verify(receipt: &Receipt) -> Result<Vec<u8>, String> {
+		let trusted = authority_set_hash(&genesis_hash(), SET_ID, &receipt.authorities);
+		receipt.verify(&genesis_hash(), &trusted, KEY)
+	}
+
+	#[test]
+	fn valid_receipts_prove_the_entry() {
+		assert_eq!(verify(&receipt()), Ok(VALUE.to_vec()));
+	}
+
+	#[test]
+	fn tampered_proofs_are_rejected() {
+		let mut receipt = receipt();
+		receipt.proof = state(b"another record").1;
+		assert!(verify(&receipt).unwrap_err().starts_with("bad storage proof"));
+	}
+
+	#[test]
+	fn receipts_of_other_entries_are_rejected() {
+		let receipt = receipt();
+		let trusted = receipt.authority_set_hash();
+		assert_eq!(
+			receipt.verify(&genesis_hash(), &trusted, b"another entry"),
+			Err("it proves another entry than the registration of the file".into())
+		);
+	}
+
+	#[test]
+	fn unlinked_header_chains_are_rejected() {
+		let mut receipt = receipt();
+		let stray = header(1, Hash::repeat_byte(9), receipt.header.state_root);
+		receipt.header = stray;
+		assert_eq!(verify(&receipt), Err("the headers do not form a chain".into()));
+	}
+
+	#[test]
+	fn untrusted_authority_sets_are_rejected() {
+		let receipt = receipt();
+		let untrusted = authority_set_hash(
+			&genesis_hash(),
+			SET_ID,
+			&vec![(Ed25519Keyring::Bob.public().into(), 1)],
+		);
+		assert_eq!(
+			receipt.verify(&genesis_hash(), &untrusted, KEY),
+			Err("it is signed by an untrusted authority set".into())
+		);
+
+		// Nor does a receipt listing the trusted set pass with another set's signatures.
+		let mut forged = receipt;
+		let child = forged.finality.unknown_headers[0].clone();
+		forged.finality.justification = justification(&child, Ed25519Keyring::Bob);
+		assert!(verify(&forged).unwrap_err().starts_with("bad justification"));
+	}
+}
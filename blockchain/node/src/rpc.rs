@@ -8,14 +8,22 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
-use speem_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use sc_client_api::{BlockchainEvents, StorageProvider};
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use speem_runtime::{
+	opaque::Block, pallet_manuscripts::ManuscriptRecord, pallet_reviews::Submission, AccountId,
+	Balance, BlockNumber, Hash, Nonce, Runtime,
+};
+
+use crate::{blob_protocol::BlobFetcher, blob_store::BlobStore};
 
 pub use sc_rpc_api::DenyUnsafe;
 
+pub mod speem;
+
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
 	/// The client instance to use.
@@ -24,34 +32,46 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// The store of encrypted manuscripts hosted by the node.
+	pub blob_store: Arc<BlobStore>,
+	/// Fetches the blobs the node does not host from its peers.
+	pub blob_fetcher: Arc<BlobFetcher>,
 }
 
 /// Instantiate all full RPC extensions.
-pub fn create_full<C, P>(
+pub fn create_full<C, P, B>(
 	deps: FullDeps<C, P>,
 ) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 where
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+	C: BlockchainEvents<Block> + StorageProvider<Block, B>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_manuscripts_runtime_api::ManuscriptsApi<
+		Block,
+		AccountId,
+		Hash,
+		BlockNumber,
+		ManuscriptRecord<Runtime>,
+	>,
+	C::Api: pallet_reviews_runtime_api::ReviewsApi<Block, Hash, Submission<Runtime>>,
+	C::Api: pallet_integrity_runtime_api::IntegrityApi<Block, Hash>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
+	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use speem::{Speem, SpeemApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, pool, deny_unsafe, blob_store, blob_fetcher } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
-
-	// Extend this RPC with a custom API by using the following syntax.
-	// `YourRpcStruct` should have a reference to a client, which is needed
-	// to call into the runtime.
-	// `module.merge(YourRpcTrait::into_rpc(YourRpcStruct::new(ReferenceToClient, ...)))?;`
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Speem::new(client, blob_store, blob_fetcher, deny_unsafe).into_rpc())?;
 
 	// You probably want to enable the `rpc v2 chainSpec` API as well
 	//
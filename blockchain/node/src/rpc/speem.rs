@@ -0,0 +1,763 @@
+//! The `speem` RPC namespace, through which clients verify documents against the registry without
+//! decoding its storage.
+//!
+//! - `speem_verifyHash` returns the registration of a content hash, including the algorithm of the
+//!   hash and the IPFS CID of the manuscript, or `null` if nothing is registered under it.
+//! - `speem_verifyCid` returns the registration of the manuscript with an IPFS CID, such as one
+//!   found in content-addressed storage, or `null` if none has it.
+//! - `speem_getProvenance` returns the place of a registered manuscript in its document's history.
+//! - `speem_listByAuthor` returns a page of the content hashes of the manuscripts of an author.
+//! - `speem_getReviewStatus` returns where the peer review of a registered manuscript stands.
+//! - `speem_verifyInclusion` checks that a chunk or section was part of a registered manuscript
+//!   against the Merkle commitment it was registered with.
+//!
+//! Every method takes an optional block hash, and answers at the best block if it is omitted.
+//!
+//! Dashboards follow the registry through subscriptions instead, which push the matching events of
+//! each new best block, or only of finalized blocks if their `finalized` parameter is `true`. A
+//! subscription is rejected if the registry cannot be queried when it starts, and ends with an
+//! error if the events of a block cannot be read:
+//!
+//! - `speem_subscribeManuscript` pushes the events of any SPEEM pallet concerning a version of a
+//!   document.
+//! - `speem_subscribeReviewerAssignments` pushes the assignments of a reviewer, or of a pseudonym,
+//!   to submissions.
+//!
+//! The node also hosts encrypted manuscripts in its [`BlobStore`]:
+//!
+//! - `speem_putBlob` stores a blob under its hash. It is unsafe, as it writes to the disk of the
+//!   node.
+//! - `speem_getBlob` returns a blob, asking the peers of the node for it if the node does not host
+//!   it, or `null` if no peer does either. Asking the peers is unsafe, as it costs the node network
+//!   requests, so the blobs the node does not host are only fetched for trusted callers.
+//! - `speem_getChunkProof` returns a chunk of a blob the node hosts with its Merkle proof, with
+//!   which the offchain worker of the node answers proof-of-storage challenges. It is unsafe, as it
+//!   reads and hashes a whole blob; the offchain worker calls it on the local interface.
+//!
+//! The blob store is read and written on threads that may block, off those serving RPC.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use futures::{
+	future::{self, Either},
+	stream,
+	stream::BoxStream,
+	FutureExt, StreamExt,
+};
+use jsonrpsee::{
+	core::{async_trait, RpcResult, SubscriptionResult},
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+	PendingSubscriptionSink, SubscriptionMessage,
+};
+use pallet_integrity_runtime_api::{IntegrityApi, IntegrityStatus};
+use pallet_manuscripts_runtime_api::{
+	DocumentId, HashAlgorithm, ManuscriptStatus, ManuscriptsApi, Multihash,
+};
+use pallet_reviews_runtime_api::{Blinding, Recommendation, ReviewStatus, ReviewsApi};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sc_rpc_api::DenyUnsafe;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::BlakeTwo256;
+use speem_primitives::merkle;
+use speem_runtime::{
+	opaque::Block, pallet_manuscripts::ManuscriptRecord, pallet_reviews::Submission, AccountId,
+	BlockNumber, Hash, Runtime, RuntimeEvent,
+};
+
+use crate::{
+	blob_protocol::BlobFetcher,
+	blob_store::{self, BlobStore},
+	content_id,
+};
+
+mod events;
+
+pub use events::{ManuscriptEvent, Notification, ReviewerAssignment};
+
+/// The registration of a manuscript.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Registration {
+	/// The content hash the manuscript is registered under.
+	pub content_hash: Hash,
+	/// The algorithm of the content hash, named as in the multicodec table, e.g. `sha2-256`.
+	pub hash_algorithm: &'static str,
+	/// The IPFS CID v1 of the manuscript, under which content-addressed storage serves it.
+	pub cid: String,
+	/// The document the manuscript is a version of.
+	pub document: DocumentId,
+	/// The position of the manuscript in the document's history.
+	pub version: u32,
+	/// The title of the manuscript.
+	pub title: String,
+	/// The hash of the manuscript's abstract.
+	pub abstract_hash: Hash,
+	/// The authors of the manuscript, in byline order.
+	pub authors: Vec<AccountId>,
+	/// The license the manuscript is published under.
+	pub license: String,
+	/// The account that registered the manuscript, or that it was later transferred to.
+	pub registrant: AccountId,
+	/// The block in which the manuscript was registered.
+	pub registered_at: BlockNumber,
+	/// The timestamp of that block, in milliseconds since the Unix epoch.
+	pub timestamp: u64,
+	/// Whether all the authors have approved the registration.
+	pub status: Approval,
+	/// Whether the manuscript has been retracted, corrected or flagged, once it is final.
+	pub integrity: Option<Integrity>,
+}
+
+/// Whether all the authors of a manuscript have approved its registration.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "status")]
+pub enum Approval {
+	/// Some authors have yet to approve the registration.
+	Pending {
+		/// The last block in which approvals are accepted.
+		deadline: BlockNumber,
+	},
+	/// Every listed author has approved the registration.
+	Final,
+}
+
+impl From<ManuscriptStatus<BlockNumber>> for Approval {
+	fn from(status: ManuscriptStatus<BlockNumber>) -> Self {
+		match status {
+			ManuscriptStatus::Pending { deadline } => Approval::Pending { deadline },
+			ManuscriptStatus::Final => Approval::Final,
+		}
+	}
+}
+
+/// The standing of a final manuscript.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "status")]
+pub enum Integrity {
+	/// No notice has been issued against the manuscript.
+	Clean,
+	/// An expression of concern has not been resolved yet.
+	UnderConcern,
+	/// The manuscript has been corrected, and no concern about it is outstanding.
+	#[serde(rename_all = "camelCase")]
+	Corrected {
+		/// The content hash of the latest corrected manuscript.
+		replacement: Hash,
+	},
+	/// The manuscript has been retracted.
+	Retracted,
+}
+
+impl From<IntegrityStatus<Hash>> for Integrity {
+	fn from(status: IntegrityStatus<Hash>) -> Self {
+		match status {
+			IntegrityStatus::Clean => Integrity::Clean,
+			IntegrityStatus::UnderConcern => Integrity::UnderConcern,
+			IntegrityStatus::Corrected { replacement } => Integrity::Corrected { replacement },
+			IntegrityStatus::Retracted => Integrity::Retracted,
+		}
+	}
+}
+
+/// The place of a manuscript in its document's history.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Provenance {
+	/// The document the manuscript is a version of.
+	pub document: DocumentId,
+	/// The position of the manuscript in the document's history.
+	pub version: u32,
+	/// The content hash of the previous version, or `null` for the original registration.
+	pub parent: Option<Hash>,
+	/// The content hashes of every version of the document, oldest first.
+	pub history: Vec<Hash>,
+	/// Whether all the authors of the latest version have approved it.
+	pub latest_status: Option<Approval>,
+}
+
+/// Where the peer review of a manuscript stands.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Review {
+	/// The editor handling the submission.
+	pub editor: AccountId,
+	/// Whether reviewers act under pseudonyms, and if so whether they are ever disclosed.
+	pub blinding: &'static str,
+	/// The last block in which reviews are accepted.
+	pub deadline: BlockNumber,
+	/// The number of reviewers assigned to the submission.
+	pub reviewers: u32,
+	/// The number of sealed reviews, in blind reviews.
+	pub sealed: u32,
+	/// The number of reviews making each recommendation.
+	pub tally: Tally,
+	/// Where the review stands: `awaitingReviews`, `reviewed` or `decided`.
+	pub status: &'static str,
+	/// The decision of the editor, once taken.
+	pub decision: Option<&'static str>,
+}
+
+/// The number of reviews making each recommendation.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tally {
+	/// Reviews recommending acceptance.
+	pub accept: u32,
+	/// Reviews recommending minor revisions.
+	pub minor_revision: u32,
+	/// Reviews recommending major revisions.
+	pub major_revision: u32,
+	/// Reviews recommending rejection.
+	pub reject: u32,
+}
+
+impl From<pallet_reviews_runtime_api::Tally> for Tally {
+	fn from(tally: pallet_reviews_runtime_api::Tally) -> Self {
+		Tally {
+			accept: tally.accept,
+			minor_revision: tally.minor_revision,
+			major_revision: tally.major_revision,
+			reject: tally.reject,
+		}
+	}
+}
+
+impl From<Submission<Runtime>> for Review {
+	fn from(submission: Submission<Runtime>) -> Self {
+		let (status, decision) = match submission.status {
+			ReviewStatus::AwaitingReviews => ("awaitingReviews", None),
+			ReviewStatus::Reviewed => ("reviewed", None),
+			ReviewStatus::Decided(recommendation) =>
+				("decided", Some(recommendation_name(recommendation))),
+		};
+		Review {
+			editor: submission.editor,
+			blinding: match submission.blinding {
+				Blinding::Identified => "identified",
+				Blinding::DiscloseAfterDecision => "discloseAfterDecision",
+				Blinding::NeverDisclose => "neverDisclose",
+			},
+			deadline: submission.deadline,
+			reviewers: submission.reviewers.len() as u32,
+			sealed: submission.sealed,
+			tally: submission.tally.into(),
+			status,
+			decision,
+		}
+	}
+}
+
+/// A chunk of a blob with the proof that it belongs to the Merkle tree over the blob's chunks.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkProof {
+	/// The number of chunks of the blob.
+	pub chunk_count: u32,
+	/// The root of the Merkle tree over the chunks of the blob.
+	pub chunk_root: Hash,
+	/// The chunk.
+	pub chunk: Bytes,
+	/// The siblings of the nodes on the path from the chunk's leaf to the root, bottom up.
+	pub proof: Vec<Hash>,
+}
+
+fn recommendation_name(recommendation: Recommendation) -> &'static str {
+	match recommendation {
+		Recommendation::Accept => "accept",
+		Recommendation::MinorRevision => "minorRevision",
+		Recommendation::MajorRevision => "majorRevision",
+		Recommendation::Reject => "reject",
+	}
+}
+
+/// Document verification RPC methods.
+#[rpc(server)]
+pub trait SpeemApi<BlockHash> {
+	/// The registration of the manuscript registered under `content_hash`, pending or final, or
+	/// `null` if nothing is registered under it.
+	#[method(name = "speem_verifyHash")]
+	fn verify_hash(
+		&self,
+		content_hash: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Registration>>;
+
+	/// The registration of the manuscript whose IPFS CID is `cid`, a CID v1 of raw content written
+	/// in base32, or `null` if no manuscript has it.
+	#[method(name = "speem_verifyCid")]
+	fn verify_cid(&self, cid: String, at: Option<BlockHash>) -> RpcResult<Option<Registration>>;
+
+	/// The place of the manuscript registered under `content_hash` in its document's history.
+	#[method(name = "speem_getProvenance")]
+	fn get_provenance(&self, content_hash: Hash, at: Option<BlockHash>) -> RpcResult<Provenance>;
+
+	/// The `page`th page, the first by default, of the content hashes of the manuscripts listing
+	/// `author`, ordered by content hash.
+	#[method(name = "speem_listByAuthor")]
+	fn list_by_author(
+		&self,
+		author: AccountId,
+		page: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Hash>>;
+
+	/// Where the peer review of the manuscript registered under `content_hash` stands.
+	#[method(name = "speem_getReviewStatus")]
+	fn get_review_status(&self, content_hash: Hash, at: Option<BlockHash>) -> RpcResult<Review>;
+
+	/// Whether `proof` shows that `part` is the `index`th chunk or section of the manuscript
+	/// registered under `content_hash`, according to the Merkle commitment it was registered
+	/// with. It is not if the manuscript was registered without one, and the call fails if nothing
+	/// is registered under `content_hash`.
+	#[method(name = "speem_verifyInclusion")]
+	fn verify_inclusion(
+		&self,
+		content_hash: Hash,
+		index: u32,
+		part: Bytes,
+		proof: Vec<Hash>,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Pushes the events concerning any version of `document`, including those registered after
+	/// the subscription.
+	#[subscription(
+		name = "speem_subscribeManuscript" => "speem_manuscript",
+		unsubscribe = "speem_unsubscribeManuscript",
+		item = Notification<ManuscriptEvent>
+	)]
+	async fn subscribe_manuscript(
+		&self,
+		document: DocumentId,
+		finalized: Option<bool>,
+	) -> SubscriptionResult;
+
+	/// Pushes the assignments of `reviewer` to submissions. Blind reviewers subscribe with their
+	/// pseudonym.
+	#[subscription(
+		name = "speem_subscribeReviewerAssignments" => "speem_reviewerAssignment",
+		unsubscribe = "speem_unsubscribeReviewerAssignments",
+		item = Notification<ReviewerAssignment>
+	)]
+	async fn subscribe_reviewer_assignments(
+		&self,
+		reviewer: AccountId,
+		finalized: Option<bool>,
+	) -> SubscriptionResult;
+
+	/// Stores `blob`, an encrypted manuscript, under `blob_hash`, which must be its hash. Returns
+	/// whether it was not stored yet.
+	#[method(name = "speem_putBlob")]
+	async fn put_blob(&self, blob_hash: Hash, blob: Bytes) -> RpcResult<bool>;
+
+	/// The blob stored under `blob_hash`, fetched from the peers of this node if it does not host
+	/// it, or `null` if none of them does either. Fetching a blob from the peers is unsafe.
+	#[method(name = "speem_getBlob")]
+	async fn get_blob(&self, blob_hash: Hash) -> RpcResult<Option<Bytes>>;
+
+	/// The `index`th chunk of the blob stored under `blob_hash` with its Merkle proof, or `null`
+	/// if this node does not host the blob or it has fewer chunks. This method is unsafe.
+	#[method(name = "speem_getChunkProof")]
+	async fn get_chunk_proof(&self, blob_hash: Hash, index: u32) -> RpcResult<Option<ChunkProof>>;
+}
+
+/// Provides RPC methods to verify documents against the registry.
+pub struct Speem<C, B> {
+	client: Arc<C>,
+	blobs: Arc<BlobStore>,
+	fetcher: Arc<BlobFetcher>,
+	deny_unsafe: DenyUnsafe,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Speem<C, B> {
+	/// Creates a new instance of the Speem RPC helper.
+	pub fn new(
+		client: Arc<C>,
+		blobs: Arc<BlobStore>,
+		fetcher: Arc<BlobFetcher>,
+		deny_unsafe: DenyUnsafe,
+	) -> Self {
+		Self { client, blobs, fetcher, deny_unsafe, _marker: PhantomData }
+	}
+}
+
+impl<C, B> Speem<C, B>
+where
+	C: BlockchainEvents<Block> + HeaderBackend<Block> + StorageProvider<Block, B>,
+	C: Send + Sync + 'static,
+	B: Backend<Block> + Send + Sync + 'static,
+{
+	/// The events of each new best block, or of each newly finalized one if `finalized`, that
+	/// `filter` keeps, or why the events of a block cannot be read.
+	fn notifications<E, F>(
+		&self,
+		finalized: bool,
+		mut filter: F,
+	) -> BoxStream<'static, Result<Notification<E>, String>>
+	where
+		E: Send + 'static,
+		F: FnMut(RuntimeEvent) -> Option<E> + Send + 'static,
+	{
+		let blocks = if finalized {
+			// Blocks finalized along with the notified one come first, oldest first.
+			self.client
+				.finality_notification_stream()
+				.flat_map(|notification| {
+					let mut route = notification.tree_route.to_vec();
+					route.push(notification.hash);
+					stream::iter(route)
+				})
+				.boxed()
+		} else {
+			self.client
+				.import_notification_stream()
+				.filter_map(|notification| {
+					future::ready(notification.is_new_best.then_some(notification.hash))
+				})
+				.boxed()
+		};
+
+		let client = self.client.clone();
+		blocks
+			.flat_map(move |block_hash| {
+				let block_number = client.number(block_hash).ok().flatten().unwrap_or_default();
+				let notifications: Vec<_> = match events::read_events(&*client, block_hash) {
+					Ok(events) => events
+						.into_iter()
+						.filter_map(&mut filter)
+						.map(|event| {
+							Ok(Notification { block_hash, block_number, finalized, event })
+						})
+						.collect(),
+					Err(e) => vec![Err(e)],
+				};
+				stream::iter(notifications)
+			})
+			.boxed()
+	}
+}
+
+/// Pushes `notifications` to the subscriber until it unsubscribes, and ends the subscription with
+/// an error as soon as the events of a block cannot be read, rather than skipping the block.
+async fn pipe_notifications<E: Serialize>(
+	pending: PendingSubscriptionSink,
+	mut notifications: BoxStream<'static, Result<Notification<E>, String>>,
+) -> SubscriptionResult {
+	let sink = pending.accept().await?;
+	loop {
+		let notification = match future::select(sink.closed().boxed(), notifications.next()).await {
+			Either::Left(_) => return Ok(()),
+			Either::Right((notification, _)) => notification,
+		};
+		match notification {
+			Some(Ok(notification)) => {
+				sink.send(SubscriptionMessage::from_json(&notification)?).await?;
+			},
+			Some(Err(e)) => {
+				log::warn!(
+					target: "rpc",
+					"Ending subscription {:?} to {}: {e}",
+					sink.subscription_id(),
+					sink.method_name(),
+				);
+				return Err(e.into())
+			},
+			None => return Ok(()),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+	/// Nothing is registered under the content hash.
+	NotRegistered,
+	/// No review has been opened for the manuscript.
+	NotUnderReview,
+	/// The blob is larger than the node accepts.
+	BlobTooLarge,
+	/// The blob store of the node is full.
+	BlobStoreFull,
+	/// The blob does not hash to the hash it was submitted under.
+	BlobHashMismatch,
+	/// Reading or writing the blob store failed.
+	BlobStoreError,
+	/// The CID is not that of a manuscript.
+	InvalidCid,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::NotRegistered => 2,
+			Error::NotUnderReview => 3,
+			Error::BlobTooLarge => 4,
+			Error::BlobStoreFull => 5,
+			Error::BlobHashMismatch => 6,
+			Error::BlobStoreError => 7,
+			Error::InvalidCid => 8,
+		}
+	}
+}
+
+fn runtime_error(e: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the registry.",
+		Some(e.to_string()),
+	)
+}
+
+fn not_registered(content_hash: Hash) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::NotRegistered.into(),
+		"Nothing is registered under this content hash.",
+		Some(format!("{content_hash:?}")),
+	)
+}
+
+fn blob_error(e: blob_store::Error) -> ErrorObjectOwned {
+	let code = match e {
+		blob_store::Error::TooLarge { .. } => Error::BlobTooLarge,
+		blob_store::Error::QuotaExceeded { .. } => Error::BlobStoreFull,
+		blob_store::Error::HashMismatch { .. } => Error::BlobHashMismatch,
+		blob_store::Error::Io(_) => Error::BlobStoreError,
+	};
+	ErrorObject::owned(code.into(), e.to_string(), None::<()>)
+}
+
+/// Runs `task`, which reads or writes the blob store, on a thread that may block.
+async fn blocking<R: Send + 'static>(
+	task: impl FnOnce() -> RpcResult<R> + Send + 'static,
+) -> RpcResult<R> {
+	tokio::task::spawn_blocking(task).await.map_err(|e| {
+		ErrorObject::owned(
+			Error::BlobStoreError.into(),
+			"The blob store task failed.",
+			Some(e.to_string()),
+		)
+	})?
+}
+
+#[async_trait]
+impl<C, B> SpeemApiServer<<Block as sp_runtime::traits::Block>::Hash> for Speem<C, B>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C: BlockchainEvents<Block> + StorageProvider<Block, B>,
+	B: Backend<Block> + Send + Sync + 'static,
+	C::Api: ManuscriptsApi<Block, AccountId, Hash, BlockNumber, ManuscriptRecord<Runtime>>,
+	C::Api: ReviewsApi<Block, Hash, Submission<Runtime>>,
+	C::Api: IntegrityApi<Block, Hash>,
+{
+	fn verify_hash(
+		&self,
+		content_hash: Hash,
+		at: Option<<Block as sp_runtime::traits::Block>::Hash>,
+	) -> RpcResult<Option<Registration>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let Some(record) = api.verify_hash(at_hash, content_hash).map_err(runtime_error)? else {
+			return Ok(None)
+		};
+		let integrity = api.integrity_status(at_hash, content_hash).map_err(runtime_error)?;
+		let id = api
+			.content_id(at_hash, content_hash)
+			.map_err(runtime_error)?
+			.unwrap_or_else(|| Multihash::new(HashAlgorithm::default(), content_hash));
+
+		let metadata = record.metadata;
+		Ok(Some(Registration {
+			content_hash,
+			hash_algorithm: id.algorithm.name(),
+			cid: content_id::to_cid(&id),
+			document: record.document,
+			version: record.version,
+			title: String::from_utf8_lossy(&metadata.title).into_owned(),
+			abstract_hash: metadata.abstract_hash,
+			authors: metadata.authors.into_inner(),
+			license: String::from_utf8_lossy(&metadata.license).into_owned(),
+			registrant: record.registrant,
+			registered_at: record.registered_at,
+			timestamp: record.timestamp,
+			status: record.status.into(),
+			integrity: integrity.map(Into::into),
+		}))
+	}
+
+	fn verify_cid(
+		&self,
+		cid: String,
+		at: Option<<Block as sp_runtime::traits::Block>::Hash>,
+	) -> RpcResult<Option<Registration>> {
+		let id = content_id::from_cid(&cid).map_err(|e| {
+			ErrorObject::owned(Error::InvalidCid.into(), e.to_string(), Some(cid.clone()))
+		})?;
+
+		// A digest of another algorithm registered under the same hash is another manuscript.
+		Ok(self
+			.verify_hash(id.digest, at)?
+			.filter(|registration| registration.hash_algorithm == id.algorithm.name()))
+	}
+
+	fn get_provenance(
+		&self,
+		content_hash: Hash,
+		at: Option<<Block as sp_runtime::traits::Block>::Hash>,
+	) -> RpcResult<Provenance> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let record = api
+			.verify_hash(at_hash, content_hash)
+			.map_err(runtime_error)?
+			.ok_or_else(|| not_registered(content_hash))?;
+		let history = api.history(at_hash, record.document).map_err(runtime_error)?;
+		let latest_status = api.status(at_hash, record.document).map_err(runtime_error)?;
+
+		Ok(Provenance {
+			document: record.document,
+			version: record.version,
+			parent: record.parent,
+			history,
+			latest_status: latest_status.map(Into::into),
+		})
+	}
+
+	fn list_by_author(
+		&self,
+		author: AccountId,
+		page: Option<u32>,
+		at: Option<<Block as sp_runtime::traits::Block>::Hash>,
+	) -> RpcResult<Vec<Hash>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.by_author(at_hash, author, page.unwrap_or_default()).map_err(runtime_error)
+	}
+
+	fn get_review_status(
+		&self,
+		content_hash: Hash,
+		at: Option<<Block as sp_runtime::traits::Block>::Hash>,
+	) -> RpcResult<Review> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.review(at_hash, content_hash).map_err(runtime_error)? {
+			Some(submission) => Ok(submission.into()),
+			None => {
+				api.verify_hash(at_hash, content_hash)
+					.map_err(runtime_error)?
+					.ok_or_else(|| not_registered(content_hash))?;
+				Err(ErrorObject::owned(
+					Error::NotUnderReview.into(),
+					"No review has been opened for this manuscript.",
+					Some(format!("{content_hash:?}")),
+				))
+			},
+		}
+	}
+
+	async fn subscribe_manuscript(
+		&self,
+		pending: PendingSubscriptionSink,
+		document: DocumentId,
+		finalized: Option<bool>,
+	) -> SubscriptionResult {
+		// Versions registered after the subscription are picked up from their events.
+		let history =
+			match self.client.runtime_api().history(self.client.info().best_hash, document) {
+				Ok(history) => history,
+				// Without the known versions, the events of the document could not be told apart.
+				Err(e) => {
+					pending.reject(runtime_error(e)).await;
+					return Ok(())
+				},
+			};
+		let mut versions = events::DocumentEvents::new(document, history);
+		let stream =
+			self.notifications(finalized.unwrap_or_default(), move |event| versions.filter(event));
+
+		pipe_notifications(pending, stream).await
+	}
+
+	async fn subscribe_reviewer_assignments(
+		&self,
+		pending: PendingSubscriptionSink,
+		reviewer: AccountId,
+		finalized: Option<bool>,
+	) -> SubscriptionResult {
+		let stream = self.notifications(finalized.unwrap_or_default(), move |event| {
+			events::reviewer_assignment(&reviewer, event)
+		});
+
+		pipe_notifications(pending, stream).await
+	}
+
+	fn verify_inclusion(
+		&self,
+		content_hash: Hash,
+		index: u32,
+		part: Bytes,
+		proof: Vec<Hash>,
+		at: Option<<Block as sp_runtime::traits::Block>::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		if api.verify_hash(at_hash, content_hash).map_err(runtime_error)?.is_none() {
+			return Err(not_registered(content_hash))
+		}
+		let commitment = api.commitment(at_hash, content_hash).map_err(runtime_error)?;
+		Ok(commitment
+			.map_or(false, |commitment| commitment.includes::<BlakeTwo256>(index, &part, &proof)))
+	}
+
+	async fn put_blob(&self, blob_hash: Hash, blob: Bytes) -> RpcResult<bool> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let blobs = self.blobs.clone();
+		blocking(move || blobs.put(blob_hash, &blob).map_err(blob_error)).await
+	}
+
+	async fn get_blob(&self, blob_hash: Hash) -> RpcResult<Option<Bytes>> {
+		let blobs = self.blobs.clone();
+		if let Some(blob) = blocking(move || blobs.get(&blob_hash).map_err(blob_error)).await? {
+			return Ok(Some(Bytes(blob)))
+		}
+		self.deny_unsafe.check_if_safe()?;
+
+		Ok(self.fetcher.fetch(blob_hash).await.map(Bytes))
+	}
+
+	async fn get_chunk_proof(&self, blob_hash: Hash, index: u32) -> RpcResult<Option<ChunkProof>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let blobs = self.blobs.clone();
+		blocking(move || {
+			let Some(blob) = blobs.get(&blob_hash).map_err(blob_error)? else { return Ok(None) };
+			let Some(chunk) = merkle::chunk(&blob, index) else { return Ok(None) };
+			// The proof and the root are built from the same leaves, hashed once.
+			let leaves = merkle::leaves::<BlakeTwo256>(&blob);
+			let proof = merkle::prove_leaf::<BlakeTwo256>(leaves.clone(), index)
+				.expect("the blob has the chunk; qed");
+			let chunk_root = merkle::root_of_leaves::<BlakeTwo256>(leaves)
+				.expect("a blob has at least one chunk; qed");
+			Ok(Some(ChunkProof {
+				chunk_count: merkle::chunk_count(blob.len()),
+				chunk_root,
+				chunk: Bytes(chunk.to_vec()),
+				proof,
+			}))
+		})
+		.await
+	}
+}
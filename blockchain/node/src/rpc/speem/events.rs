@@ -0,0 +1,416 @@
+//! The runtime events pushed to the subscribers of the `speem` namespace.
+
+use std::collections::HashSet;
+
+use codec::Decode;
+use frame_system::EventRecord;
+use sc_client_api::{Backend, StorageProvider};
+use serde::Serialize;
+use sp_core::storage::StorageKey;
+use speem_runtime::{
+	opaque::Block,
+	pallet_citations, pallet_integrity,
+	pallet_integrity::{NoticeKind, ReasonCode},
+	pallet_manuscripts::{self, DocumentId},
+	pallet_priority_claims, pallet_reviews, pallet_venues,
+	pallet_venues::VenueId,
+	AccountId, BlockNumber, Hash, Runtime, RuntimeEvent,
+};
+
+use super::{recommendation_name, Tally};
+
+/// An event pushed to a subscriber, with the block it was emitted in.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification<E> {
+	/// The hash of the block.
+	pub block_hash: Hash,
+	/// The number of the block.
+	pub block_number: BlockNumber,
+	/// Whether the block is finalized. Events of blocks that are not may be reverted.
+	pub finalized: bool,
+	/// The event.
+	#[serde(flatten)]
+	pub event: E,
+}
+
+/// Something that happened to a version of a document, in any of the SPEEM pallets.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "event")]
+pub enum ManuscriptEvent {
+	/// The document has been registered.
+	#[serde(rename_all = "camelCase")]
+	Registered {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The account that registered it.
+		registrant: AccountId,
+	},
+	/// A new version of the document has been submitted.
+	#[serde(rename_all = "camelCase")]
+	RevisionSubmitted {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The version number of the revision.
+		version: u32,
+		/// The content hash of the version it revises.
+		parent: Hash,
+		/// The author.
+		author: AccountId,
+	},
+	/// An author has approved a pending version.
+	#[serde(rename_all = "camelCase")]
+	AuthorshipApproved {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The author.
+		author: AccountId,
+	},
+	/// Every author has approved a version, which is now final.
+	#[serde(rename_all = "camelCase")]
+	Finalized {
+		/// The content hash of the version.
+		content_hash: Hash,
+	},
+	/// A pending version was removed after its approval period elapsed.
+	#[serde(rename_all = "camelCase")]
+	Expired {
+		/// The content hash of the version.
+		content_hash: Hash,
+	},
+	/// A pending version was withdrawn by its registrant.
+	#[serde(rename_all = "camelCase")]
+	Withdrawn {
+		/// The content hash of the version.
+		content_hash: Hash,
+	},
+	/// The registrant of a version has offered it to another account.
+	#[serde(rename_all = "camelCase")]
+	TransferProposed {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The current registrant.
+		from: AccountId,
+		/// The account the registration is offered to.
+		to: AccountId,
+	},
+	/// A version has changed hands.
+	#[serde(rename_all = "camelCase")]
+	Transferred {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The previous registrant.
+		from: AccountId,
+		/// The new registrant.
+		to: AccountId,
+	},
+	/// A commitment made before registration has been linked to a version.
+	#[serde(rename_all = "camelCase")]
+	PriorityClaimRevealed {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The commitment.
+		commitment: Hash,
+	},
+	/// A version has been submitted to a venue.
+	#[serde(rename_all = "camelCase")]
+	SubmittedToVenue {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The venue.
+		venue: VenueId,
+	},
+	/// An editor has opened the review of a version.
+	#[serde(rename_all = "camelCase")]
+	ReviewOpened {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The editor handling the review.
+		editor: AccountId,
+		/// The last block in which reviews are accepted.
+		deadline: BlockNumber,
+	},
+	/// A reviewer, or their pseudonym in blind reviews, has been assigned to a version.
+	#[serde(rename_all = "camelCase")]
+	ReviewerAssigned {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The reviewer, or their pseudonym in blind reviews.
+		reviewer: AccountId,
+		/// The last block in which the review is accepted.
+		deadline: BlockNumber,
+	},
+	/// A blind reviewer has sealed their review.
+	#[serde(rename_all = "camelCase")]
+	ReviewSealed {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The reviewer's pseudonym.
+		pseudonym: AccountId,
+	},
+	/// A reviewer has submitted, or unsealed, their review.
+	#[serde(rename_all = "camelCase")]
+	ReviewSubmitted {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The reviewer, or their pseudonym in blind reviews.
+		reviewer: AccountId,
+		/// The hash of the review report.
+		review_hash: Hash,
+		/// The reviewer's recommendation.
+		recommendation: &'static str,
+	},
+	/// Every assigned reviewer has submitted a review.
+	#[serde(rename_all = "camelCase")]
+	ReviewsCompleted {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The number of reviews making each recommendation.
+		tally: Tally,
+	},
+	/// The editor has decided on a version.
+	#[serde(rename_all = "camelCase")]
+	Decided {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The decision.
+		decision: &'static str,
+	},
+	/// The identity of a blind reviewer has been disclosed.
+	#[serde(rename_all = "camelCase")]
+	IdentityDisclosed {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The reviewer's pseudonym.
+		pseudonym: AccountId,
+		/// The reviewer behind it.
+		reviewer: AccountId,
+	},
+	/// A retraction, correction, expression of concern or its resolution has been issued against a
+	/// version.
+	#[serde(rename_all = "camelCase")]
+	NoticeIssued {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// `retraction`, `correction`, `expressionOfConcern` or `concernResolved`.
+		kind: &'static str,
+		/// The content hash of the corrected version, for corrections.
+		replacement: Option<Hash>,
+		/// Why the notice was issued.
+		reason: &'static str,
+		/// The registrant or editor who issued it.
+		issuer: AccountId,
+	},
+	/// The references of a version have been declared.
+	#[serde(rename_all = "camelCase")]
+	ReferencesDeclared {
+		/// The content hash of the version.
+		content_hash: Hash,
+		/// The number of documents of the registry it cites.
+		documents: u32,
+		/// The number of external works it cites.
+		external: u32,
+	},
+}
+
+/// The assignment of a reviewer to a submission.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewerAssignment {
+	/// The content hash of the submission.
+	pub content_hash: Hash,
+	/// The last block in which the review is accepted.
+	pub deadline: BlockNumber,
+}
+
+/// The events emitted in the block `at`, or why they cannot be read.
+pub(super) fn read_events<C, B>(client: &C, at: Hash) -> Result<Vec<RuntimeEvent>, String>
+where
+	C: StorageProvider<Block, B>,
+	B: Backend<Block>,
+{
+	let key = StorageKey(frame_system::Events::<Runtime>::hashed_key().to_vec());
+	let Some(data) = client
+		.storage(at, &key)
+		.map_err(|e| format!("Unable to read the events of block {at:?}: {e}"))?
+	else {
+		return Ok(Vec::new())
+	};
+	let records = Vec::<EventRecord<RuntimeEvent, Hash>>::decode(&mut &data.0[..])
+		.map_err(|e| format!("Unable to decode the events of block {at:?}: {e}"))?;
+	Ok(records.into_iter().map(|record| record.event).collect())
+}
+
+/// Picks the events concerning the versions of a document out of those of each block.
+pub(super) struct DocumentEvents {
+	document: DocumentId,
+	/// The content hashes of the versions of the document, including the pending ones.
+	versions: HashSet<Hash>,
+}
+
+impl DocumentEvents {
+	/// Follows `document`, whose versions are `history` so far.
+	pub(super) fn new(document: DocumentId, history: Vec<Hash>) -> Self {
+		Self { document, versions: history.into_iter().collect() }
+	}
+
+	/// `event`, if it concerns a version of the document.
+	pub(super) fn filter(&mut self, event: RuntimeEvent) -> Option<ManuscriptEvent> {
+		use ManuscriptEvent::*;
+
+		let event = match event {
+			RuntimeEvent::Manuscripts(event) => match event {
+				pallet_manuscripts::Event::ManuscriptRegistered {
+					document,
+					content_hash,
+					registrant,
+				} if document == self.document => {
+					self.versions.insert(content_hash);
+					return Some(Registered { content_hash, registrant })
+				},
+				pallet_manuscripts::Event::RevisionSubmitted {
+					document,
+					version,
+					content_hash,
+					parent,
+					author,
+				} if document == self.document => {
+					self.versions.insert(content_hash);
+					return Some(RevisionSubmitted { content_hash, version, parent, author })
+				},
+				pallet_manuscripts::Event::AuthorshipApproved { content_hash, author } =>
+					AuthorshipApproved { content_hash, author },
+				pallet_manuscripts::Event::ManuscriptFinalized { content_hash } =>
+					Finalized { content_hash },
+				pallet_manuscripts::Event::SubmissionExpired { content_hash } =>
+					Expired { content_hash },
+				pallet_manuscripts::Event::SubmissionWithdrawn { content_hash } =>
+					Withdrawn { content_hash },
+				pallet_manuscripts::Event::TransferProposed { content_hash, from, to } =>
+					TransferProposed { content_hash, from, to },
+				pallet_manuscripts::Event::RegistrationTransferred { content_hash, from, to } =>
+					Transferred { content_hash, from, to },
+				_ => return None,
+			},
+			RuntimeEvent::PriorityClaims(pallet_priority_claims::Event::Revealed {
+				commitment,
+				document,
+				..
+			}) => PriorityClaimRevealed { content_hash: document, commitment },
+			RuntimeEvent::Venues(pallet_venues::Event::Submitted {
+				content_hash, venue, ..
+			}) => SubmittedToVenue { content_hash, venue },
+			RuntimeEvent::Reviews(event) => match event {
+				pallet_reviews::Event::ReviewOpened { content_hash, editor, deadline, .. } =>
+					ReviewOpened { content_hash, editor, deadline },
+				pallet_reviews::Event::ReviewerAssigned { content_hash, reviewer, deadline } =>
+					ReviewerAssigned { content_hash, reviewer, deadline },
+				pallet_reviews::Event::ReviewSealed { content_hash, pseudonym } =>
+					ReviewSealed { content_hash, pseudonym },
+				pallet_reviews::Event::ReviewSubmitted {
+					content_hash,
+					reviewer,
+					review_hash,
+					recommendation,
+				} => ReviewSubmitted {
+					content_hash,
+					reviewer,
+					review_hash,
+					recommendation: recommendation_name(recommendation),
+				},
+				pallet_reviews::Event::ReviewsCompleted { content_hash, tally } =>
+					ReviewsCompleted { content_hash, tally: tally.into() },
+				pallet_reviews::Event::Decided { content_hash, decision } =>
+					Decided { content_hash, decision: recommendation_name(decision) },
+				pallet_reviews::Event::IdentityDisclosed { content_hash, pseudonym, reviewer } =>
+					IdentityDisclosed { content_hash, pseudonym, reviewer },
+				_ => return None,
+			},
+			RuntimeEvent::Integrity(pallet_integrity::Event::NoticeIssued {
+				content_hash,
+				kind,
+				reason,
+				issuer,
+			}) => {
+				let (kind, replacement) = match kind {
+					NoticeKind::Retraction => ("retraction", None),
+					NoticeKind::Correction { replacement } => ("correction", Some(replacement)),
+					NoticeKind::ExpressionOfConcern => ("expressionOfConcern", None),
+					NoticeKind::ConcernResolved => ("concernResolved", None),
+				};
+				NoticeIssued {
+					content_hash,
+					kind,
+					replacement,
+					reason: reason_name(reason),
+					issuer,
+				}
+			},
+			RuntimeEvent::Citations(pallet_citations::Event::ReferencesDeclared {
+				content_hash,
+				documents,
+				external,
+			}) => ReferencesDeclared { content_hash, documents, external },
+			_ => return None,
+		};
+		self.versions.contains(event.content_hash()).then_some(event)
+	}
+}
+
+impl ManuscriptEvent {
+	/// The content hash of the version the event concerns.
+	fn content_hash(&self) -> &Hash {
+		use ManuscriptEvent::*;
+
+		match self {
+			Registered { content_hash, .. } |
+			RevisionSubmitted { content_hash, .. } |
+			AuthorshipApproved { content_hash, .. } |
+			Finalized { content_hash } |
+			Expired { content_hash } |
+			Withdrawn { content_hash } |
+			TransferProposed { content_hash, .. } |
+			Transferred { content_hash, .. } |
+			PriorityClaimRevealed { content_hash, .. } |
+			SubmittedToVenue { content_hash, .. } |
+			ReviewOpened { content_hash, .. } |
+			ReviewerAssigned { content_hash, .. } |
+			ReviewSealed { content_hash, .. } |
+			ReviewSubmitted { content_hash, .. } |
+			ReviewsCompleted { content_hash, .. } |
+			Decided { content_hash, .. } |
+			IdentityDisclosed { content_hash, .. } |
+			NoticeIssued { content_hash, .. } |
+			ReferencesDeclared { content_hash, .. } => content_hash,
+		}
+	}
+}
+
+/// `event`, if it assigns `reviewer` to a submission.
+pub(super) fn reviewer_assignment(
+	reviewer: &AccountId,
+	event: RuntimeEvent,
+) -> Option<ReviewerAssignment> {
+	match event {
+		RuntimeEvent::Reviews(pallet_reviews::Event::ReviewerAssigned {
+			content_hash,
+			reviewer: assigned,
+			deadline,
+		}) if assigned == *reviewer => Some(ReviewerAssignment { content_hash, deadline }),
+		_ => None,
+	}
+}
+
+fn reason_name(reason: ReasonCode) -> &'static str {
+	match reason {
+		ReasonCode::HonestError => "honestError",
+		ReasonCode::Irreproducible => "irreproducible",
+		ReasonCode::Fabrication => "fabrication",
+		ReasonCode::Plagiarism => "plagiarism",
+		ReasonCode::DuplicatePublication => "duplicatePublication",
+		ReasonCode::AuthorshipDispute => "authorshipDispute",
+		ReasonCode::EthicsViolation => "ethicsViolation",
+		ReasonCode::ReviewManipulation => "reviewManipulation",
+		ReasonCode::Other => "other",
+	}
+}
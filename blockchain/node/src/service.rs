@@ -1,7 +1,10 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
+use crate::{
+	blob_protocol::{self, BlobFetcher, BlobRequestHandler},
+	blob_store::{self, BlobStore, BlobStoreParams},
+};
 use futures::FutureExt;
-use speem_runtime::{self, opaque::Block, RuntimeApi};
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
@@ -9,6 +12,7 @@ use sc_service::{error::Error as ServiceError, Configuration, TaskManager, WarpS
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use speem_runtime::{self, opaque::Block, RuntimeApi};
 use std::{sync::Arc, time::Duration};
 
 pub(crate) type FullClient = sc_service::TFullClient<
@@ -124,7 +128,10 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 }
 
 /// Builds a new service for a full client.
-pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
+pub fn new_full(
+	config: Configuration,
+	blob_store: BlobStoreParams,
+) -> Result<TaskManager, ServiceError> {
 	let sc_service::PartialComponents {
 		client,
 		backend,
@@ -138,14 +145,38 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 
 	let mut net_config = sc_network::config::FullNetworkConfiguration::new(&config.network);
 
-	let grandpa_protocol_name = sc_consensus_grandpa::protocol_standard_name(
-		&client.block_hash(0).ok().flatten().expect("Genesis block exists; qed"),
-		&config.chain_spec,
-	);
+	let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+	let grandpa_protocol_name =
+		sc_consensus_grandpa::protocol_standard_name(&genesis_hash, &config.chain_spec);
 	let (grandpa_protocol_config, grandpa_notification_service) =
 		sc_consensus_grandpa::grandpa_peers_set_config(grandpa_protocol_name.clone());
 	net_config.add_notification_protocol(grandpa_protocol_config);
 
+	let blob_store = Arc::new(
+		BlobStore::open(
+			config.base_path.config_dir(config.chain_spec.id()).join("blobs"),
+			&blob_store,
+		)
+		.map_err(|e| ServiceError::Other(format!("Failed to open the blob store: {e}")))?,
+	);
+	let rpc_max_size = config.rpc_max_request_size.min(config.rpc_max_response_size);
+	if blob_store::rpc_payload_size(blob_store.max_blob_size()) > u64::from(rpc_max_size) << 20 {
+		log::warn!(
+			"The largest blobs accepted do not fit the {rpc_max_size} MiB the RPC server carries \
+			 once hex-encoded; lower --blob-max-size or raise --rpc-max-request-size and \
+			 --rpc-max-response-size."
+		);
+	}
+
+	let blob_protocol_name =
+		blob_protocol::protocol_name(&genesis_hash, config.chain_spec.fork_id());
+	let (blob_request_handler, blob_protocol_config) = BlobRequestHandler::new(
+		blob_protocol_name.clone(),
+		blob_store.clone(),
+		blob_store.max_blob_size(),
+	);
+	net_config.add_request_response_protocol(blob_protocol_config);
+
 	let warp_sync = Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
 		backend.clone(),
 		grandpa_link.shared_authority_set().clone(),
@@ -165,6 +196,18 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 			block_relay: None,
 		})?;
 
+	task_manager.spawn_handle().spawn(
+		"blob-request-handler",
+		Some("networking"),
+		blob_request_handler.run(),
+	);
+	let blob_fetcher = Arc::new(BlobFetcher::new(
+		network.clone(),
+		sync_service.clone(),
+		blob_protocol_name,
+		blob_store.clone(),
+	));
+
 	if config.offchain_worker.enabled {
 		task_manager.spawn_handle().spawn(
 			"offchain-workers-runner",
@@ -198,9 +241,14 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 		let pool = transaction_pool.clone();
 
 		Box::new(move |deny_unsafe, _| {
-			let deps =
-				crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
-			crate::rpc::create_full(deps).map_err(Into::into)
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+				blob_store: blob_store.clone(),
+				blob_fetcher: blob_fetcher.clone(),
+			};
+			crate::rpc::create_full::<_, _, FullBackend>(deps).map_err(Into::into)
 		})
 	};
 
@@ -0,0 +1,232 @@
+//! The `verify` subcommand, which checks whether a local file is registered on chain.
+//!
+//! The file is hashed with the runtime's hashing algorithm, or with any other algorithm the
+//! registry accepts, unless it is named by the IPFS CID it is stored under, which holds its content
+//! hash. The content hash is looked up in a storage map of the registry, either in the local
+//! database or through the RPC endpoint of a running node. The map is the manuscript registry by
+//! default, and any other map keyed by content hash can be named on the command line, so that the
+//! lookup keeps working as the pallets evolve. It is read at the latest finalized block, so that
+//! a registration reported cannot be reverted.
+
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+
+use codec::DecodeAll;
+use jsonrpsee::{core::client::ClientT, rpc_params, ws_client::WsClientBuilder};
+use sc_cli::{CliConfiguration, DatabaseParams, SharedParams};
+use sc_client_api::{Backend, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	hashing::{blake2_128, twox_128, twox_64},
+	hexdisplay::HexDisplay,
+	storage::StorageKey,
+	Bytes,
+};
+use speem_primitives::multihash::{HashAlgorithm, Multihash};
+use speem_runtime::{
+	opaque::{Block, Header},
+	pallet_manuscripts::{ManuscriptRecord, ManuscriptStatus},
+	BlockNumber, Hash, Runtime,
+};
+
+use crate::content_id;
+
+/// The hasher of the key of a storage map.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum KeyHasher {
+	/// The key itself.
+	Identity,
+	/// The 128-bit BLAKE2 hash of the key, followed by the key.
+	#[value(name = "blake2-128-concat")]
+	Blake2_128Concat,
+	/// The 64-bit xxHash of the key, followed by the key.
+	#[value(name = "twox-64-concat")]
+	Twox64Concat,
+}
+
+impl KeyHasher {
+	fn hash(&self, key: &[u8]) -> Vec<u8> {
+		match self {
+			KeyHasher::Identity => key.to_vec(),
+			KeyHasher::Blake2_128Concat => [&blake2_128(key)[..], key].concat(),
+			KeyHasher::Twox64Concat => [&twox_64(key)[..], key].concat(),
+		}
+	}
+}
+
+/// The storage map of the registry in which files are looked up.
+#[derive(Debug, Clone, clap::Args)]
+pub struct RegistryParams {
+	/// The pallet of the storage map the registry is kept in.
+	#[arg(long, default_value = "Manuscripts")]
+	pub pallet: String,
+
+	/// The storage map the registry is kept in, keyed by content hash.
+	#[arg(long, default_value = "Manuscripts")]
+	pub item: String,
+
+	/// The hasher of the key of the storage map.
+	#[arg(long, value_enum, default_value_t = KeyHasher::Identity)]
+	pub hasher: KeyHasher,
+
+	/// The algorithm the file was hashed with when it was registered: `sha2-256`, `sha3-256` or
+	/// `blake2b-256`.
+	#[arg(
+		long,
+		value_name = "ALGORITHM",
+		default_value = "blake2b-256",
+		value_parser = parse_algorithm
+	)]
+	pub hash_algorithm: HashAlgorithm,
+}
+
+pub(crate) fn parse_algorithm(name: &str) -> Result<HashAlgorithm, String> {
+	HashAlgorithm::from_name(name).ok_or_else(|| format!("unsupported hash algorithm `{name}`"))
+}
+
+impl RegistryParams {
+	/// The content hash of `file`, computed with the chosen algorithm.
+	pub fn content_hash(&self, file: &Path) -> sc_cli::Result<Hash> {
+		let content = std::fs::read(file)?;
+		Ok(content_id::digest(self.hash_algorithm, &content))
+	}
+
+	/// The key of the registry map's entry for `content_hash`.
+	pub fn storage_key(&self, content_hash: &Hash) -> StorageKey {
+		let mut key = twox_128(self.pallet.as_bytes()).to_vec();
+		key.extend(twox_128(self.item.as_bytes()));
+		key.extend(self.hasher.hash(content_hash.as_ref()));
+		StorageKey(key)
+	}
+}
+
+/// Prints what the registry `value` says about a file, if it decodes as a manuscript record with
+/// no bytes left over.
+///
+/// Entries of other maps, or laid out by a newer runtime, are shown as they are stored, even when
+/// they begin with bytes that would decode as a record.
+pub fn print_entry(value: &[u8]) {
+	match ManuscriptRecord::<Runtime>::decode_all(&mut &value[..]) {
+		Ok(record) => {
+			println!("Registered:   yes, in block #{}", record.registered_at);
+			println!("Registrant:   {}", record.registrant);
+			match record.status {
+				ManuscriptStatus::Pending { deadline } =>
+					println!("Status:       awaiting author approval until block #{deadline}"),
+				ManuscriptStatus::Final => println!("Status:       final"),
+			}
+		},
+		Err(_) => println!("Registered:   yes, as 0x{}", HexDisplay::from(&value)),
+	}
+}
+
+/// The `verify` command used to check whether a local file is registered on chain.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct VerifyCmd {
+	/// The file to verify.
+	#[arg(value_name = "FILE", required_unless_present = "cid")]
+	pub file: Option<PathBuf>,
+
+	/// Verify the file stored under this IPFS CID, a CID v1 of raw content written in base32,
+	/// instead of a local file. The CID names the hash algorithm.
+	#[arg(long, value_name = "CID", conflicts_with_all = ["file", "hash_algorithm"])]
+	pub cid: Option<String>,
+
+	/// Query the node serving this WebSocket RPC endpoint, e.g. `ws://127.0.0.1:9944`, instead
+	/// of opening the local database.
+	#[arg(long, value_name = "URL")]
+	pub rpc_url: Option<String>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub registry: RegistryParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl VerifyCmd {
+	/// Look the file up in the local database, at the finalized block.
+	pub async fn run<C, B>(&self, client: Arc<C>) -> sc_cli::Result<()>
+	where
+		C: HeaderBackend<Block> + StorageProvider<Block, B>,
+		B: Backend<Block>,
+	{
+		let id = self.content_id()?;
+		let info = client.info();
+		let value = client.storage(info.finalized_hash, &self.registry.storage_key(&id.digest))?;
+
+		self.report(id, info.finalized_number, value.map(|data| data.0));
+		Ok(())
+	}
+
+	/// Look the file up through the RPC endpoint at `url`, at the finalized block of that node.
+	pub async fn run_remote(&self, url: &str) -> sc_cli::Result<()> {
+		let id = self.content_id()?;
+		let client = WsClientBuilder::default().build(url).await.map_err(rpc_error)?;
+		let finalized: Hash = client
+			.request("chain_getFinalizedHead", rpc_params![])
+			.await
+			.map_err(rpc_error)?;
+		let header: Header = client
+			.request("chain_getHeader", rpc_params![finalized])
+			.await
+			.map_err(rpc_error)?;
+		let value: Option<Bytes> = client
+			.request(
+				"state_getStorage",
+				rpc_params![Bytes(self.registry.storage_key(&id.digest).0), finalized],
+			)
+			.await
+			.map_err(rpc_error)?;
+
+		self.report(id, header.number, value.map(|bytes| bytes.0));
+		Ok(())
+	}
+
+	/// The identifier of the file, read from its CID or computed by hashing it.
+	fn content_id(&self) -> sc_cli::Result<Multihash<Hash>> {
+		match (&self.cid, &self.file) {
+			(Some(cid), _) =>
+				content_id::from_cid(cid).map_err(|e| sc_cli::Error::Input(e.to_string())),
+			(None, Some(file)) =>
+				Ok(Multihash::new(self.registry.hash_algorithm, self.registry.content_hash(file)?)),
+			(None, None) => Err(sc_cli::Error::Input("A file or a CID is required.".into())),
+		}
+	}
+
+	fn report(&self, id: Multihash<Hash>, at: BlockNumber, value: Option<Vec<u8>>) {
+		if let Some(file) = &self.file {
+			println!("File:         {}", file.display());
+		}
+		println!("Content hash: {:?} ({})", id.digest, id.algorithm.name());
+		println!("CID:          {}", content_id::to_cid(&id));
+		println!("Checked at:   block #{at}, finalized");
+
+		match value {
+			Some(value) => print_entry(&value),
+			None => println!("Registered:   no"),
+		}
+	}
+}
+
+impl CliConfiguration for VerifyCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+fn rpc_error(e: jsonrpsee::core::ClientError) -> sc_cli::Error {
+	sc_cli::Error::Application(Box::new(e))
+}
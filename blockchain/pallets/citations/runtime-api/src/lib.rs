@@ -0,0 +1,19 @@
+//! Runtime API of the citations pallet, through which clients read the citation graph of the
+//! registry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The documents citing each document of the registry.
+	pub trait CitationsApi<Hash> where Hash: Codec {
+		/// The number of documents citing `content_hash`.
+		fn citation_count(content_hash: Hash) -> u32;
+
+		/// The `page`th page of the documents citing `content_hash`, in the order their references
+		/// were declared, with pages of `page_size` documents, capped by the pallet.
+		fn cited_by(content_hash: Hash, page: u32, page_size: u32) -> Vec<Hash>;
+	}
+}
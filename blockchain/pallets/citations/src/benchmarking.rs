@@ -0,0 +1,40 @@
+//! Benchmarking setup for pallet-citations
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as CitationsPallet;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn declare_references() {
+		// Citing only documents of the registry is the most expensive, and checking for duplicates
+		// is quadratic in the number of references.
+		let references: Vec<Reference<T>> = (1..=T::MaxReferences::get())
+			.map(|seed| Reference::Document(T::BenchmarkHelper::registered_document(seed).0))
+			.collect();
+		// The citing document is registered after the documents it cites.
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + 1u32.into());
+		let (document, registrant) = T::BenchmarkHelper::registered_document(0);
+		let cited = references.clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(registrant), document, references);
+
+		for reference in cited {
+			if let Reference::Document(cited) = reference {
+				assert_eq!(CitationCount::<T>::get(cited), 1);
+			}
+		}
+	}
+
+	impl_benchmark_test_suite!(CitationsPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
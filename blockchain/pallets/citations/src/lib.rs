@@ -0,0 +1,303 @@
+//! # Citations Pallet
+//!
+//! Records the citation graph between the documents of the registry.
+//!
+//! ## Overview
+//!
+//! Once a document is final in the registry (see [`Config::Documents`]), its registrant declares
+//! its references, once, as a bounded list of [`Reference`]s. A reference is either:
+//!
+//! - the content hash of another document, which must already be a final entry of the registry,
+//!   registered in an earlier block than the citing document;
+//! - an external identifier, e.g. the DOI of a work published elsewhere.
+//!
+//! For every cited document, the pallet keeps the number of documents citing it and the list of
+//! those documents, in the order their references were declared. Both are exposed through the
+//! runtime API of the `pallet-citations-runtime-api` crate, the list one page at a time.
+//!
+//! ## Declaring References Separately
+//!
+//! References are declared in their own call rather than as an argument of the manuscript
+//! registry's `register`, for three reasons:
+//!
+//! - A manuscript with several authors is registered pending and only becomes final once every
+//!   author has approved it, and it may be withdrawn before then. Declaring its references at
+//!   registration would put a document into the graph that its authors never agreed to, and would
+//!   need a way to take its citations back out on withdrawal. Declaring them once the document is
+//!   final keeps the graph to documents the registry vouches for.
+//! - The registry would otherwise depend on this pallet, while this pallet reads the registry
+//!   through [`Config::Documents`]; keeping the dependency one way keeps the registry usable on its
+//!   own.
+//! - `register` does not pay for the up to [`Config::MaxReferences`] writes of the "cited by"
+//!   index, which only documents declaring references incur.
+//!
+//! The guarantees are those registration-time references would give: only the registrant
+//! declares the references, once, and they cannot be changed afterwards. A document only cites
+//! documents registered in earlier blocks than itself, as if it had declared its references when
+//! it was registered, so that no document cites a later one and no two documents cite each other.
+//! Documents registered in the same block cannot cite each other, as the registry does not order
+//! them.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`declare_references`](Pallet::declare_references) - Declare the references of a document.
+//!
+//! Run `cargo doc --package pallet-citations --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+pub use speem_primitives::{DocumentRegistrant, DocumentRegistration};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*, traits::Contains, CloneNoBound, EqNoBound, PartialEqNoBound,
+		RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	/// The position of a citing document in the list of those citing a document.
+	pub type CitationIndex = u32;
+
+	/// The kind of identifier naming a work that is not in the registry.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum ExternalScheme {
+		/// A Digital Object Identifier, e.g. `10.1000/182`.
+		Doi,
+		/// An arXiv identifier, e.g. `2101.00001`.
+		ArXiv,
+		/// An International Standard Book Number.
+		Isbn,
+		/// A PubMed identifier.
+		PubMed,
+		/// A URL.
+		Url,
+		/// An identifier the other schemes do not cover.
+		Other,
+	}
+
+	/// A work cited by a document.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub enum Reference<T: Config> {
+		/// A document of the registry, by content hash.
+		Document(T::Hash),
+		/// A work that is not in the registry.
+		External {
+			/// The kind of identifier.
+			scheme: ExternalScheme,
+			/// The identifier, UTF-8 encoded.
+			identifier: BoundedVec<u8, T::MaxIdentifierLength>,
+		},
+	}
+
+	/// Provides benchmarks with state that this pallet does not control.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Hash, AccountId> {
+		/// Make sure a document, distinct for each `seed`, is final in the registry and return its
+		/// content hash and registrant.
+		fn registered_document(seed: u32) -> (Hash, AccountId);
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The registry of documents that cite and are cited, of their registrants and of when
+		/// they were registered.
+		type Documents: Contains<Self::Hash>
+			+ DocumentRegistrant<Self::AccountId, Self::Hash>
+			+ DocumentRegistration<Self::Hash, BlockNumberFor<Self>>;
+		/// The maximum number of references a document declares.
+		#[pallet::constant]
+		type MaxReferences: Get<u32>;
+		/// The maximum length of an external identifier, in bytes.
+		#[pallet::constant]
+		type MaxIdentifierLength: Get<u32>;
+		/// The maximum number of citing documents returned in one page.
+		#[pallet::constant]
+		type MaxPageSize: Get<u32>;
+		/// Helper for benchmarks to obtain documents the registry contains.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::Hash, Self::AccountId>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The references declared by each document, keyed by content hash.
+	#[pallet::storage]
+	pub type References<T: Config> =
+		StorageMap<_, Identity, T::Hash, BoundedVec<Reference<T>, T::MaxReferences>, OptionQuery>;
+
+	/// The number of documents citing each document, keyed by content hash.
+	#[pallet::storage]
+	pub type CitationCount<T: Config> = StorageMap<_, Identity, T::Hash, u32, ValueQuery>;
+
+	/// The documents citing each document, keyed by the content hash of the cited document and
+	/// the position of the citing one, in the order their references were declared.
+	#[pallet::storage]
+	pub type CitedBy<T: Config> =
+		StorageDoubleMap<_, Identity, T::Hash, Twox64Concat, CitationIndex, T::Hash, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The references of a document have been declared.
+		ReferencesDeclared {
+			/// The content hash of the citing document.
+			content_hash: T::Hash,
+			/// The number of documents of the registry it cites.
+			documents: u32,
+			/// The number of external works it cites.
+			external: u32,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The citing document is not a final entry of the registry.
+		UnregisteredDocument,
+		/// The signer is not the registrant of the document.
+		NotRegistrant,
+		/// The references of the document have already been declared.
+		AlreadyDeclared,
+		/// The document declares more references than [`Config::MaxReferences`] allows.
+		TooManyReferences,
+		/// A referenced content hash is not a final entry of the registry.
+		UnknownReference,
+		/// A referenced document was not registered in an earlier block than the citing one.
+		LaterReference,
+		/// The document references itself.
+		SelfCitation,
+		/// The document references the same work more than once.
+		DuplicateReference,
+		/// An external identifier is empty.
+		EmptyIdentifier,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Declare the references of a registered document.
+		///
+		/// The origin must be signed by the registrant of the document, once all of its authors
+		/// have approved it. The references cannot be changed afterwards.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NotRegistrant`] if the signer is not its registrant.
+		/// - [`Error::AlreadyDeclared`] if its references have already been declared.
+		/// - [`Error::TooManyReferences`] if `references` exceeds [`Config::MaxReferences`].
+		/// - [`Error::UnknownReference`] if a referenced document is not a final registry entry.
+		/// - [`Error::LaterReference`] if a referenced document was not registered in an earlier
+		///   block than the document.
+		/// - [`Error::SelfCitation`] if the document references itself.
+		/// - [`Error::DuplicateReference`] if a work is referenced more than once.
+		/// - [`Error::EmptyIdentifier`] if an external identifier is empty.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::declare_references())]
+		pub fn declare_references(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			references: Vec<Reference<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(T::Documents::contains(&content_hash), Error::<T>::UnregisteredDocument);
+			ensure!(
+				T::Documents::registrant_of(&content_hash) == Some(who),
+				Error::<T>::NotRegistrant
+			);
+			ensure!(!References::<T>::contains_key(content_hash), Error::<T>::AlreadyDeclared);
+			let references: BoundedVec<_, T::MaxReferences> =
+				references.try_into().map_err(|_| Error::<T>::TooManyReferences)?;
+			let registered_at = T::Documents::registered_at(&content_hash)
+				.ok_or(Error::<T>::UnregisteredDocument)?;
+
+			for (i, reference) in references.iter().enumerate() {
+				ensure!(!references[..i].contains(reference), Error::<T>::DuplicateReference);
+				match reference {
+					Reference::Document(cited) => {
+						ensure!(*cited != content_hash, Error::<T>::SelfCitation);
+						ensure!(T::Documents::contains(cited), Error::<T>::UnknownReference);
+						ensure!(
+							T::Documents::registered_at(cited)
+								.map_or(false, |cited_at| cited_at < registered_at),
+							Error::<T>::LaterReference
+						);
+					},
+					Reference::External { identifier, .. } => {
+						ensure!(!identifier.is_empty(), Error::<T>::EmptyIdentifier);
+					},
+				}
+			}
+
+			let mut documents = 0u32;
+			for reference in &references {
+				if let Reference::Document(cited) = reference {
+					let index = CitationCount::<T>::mutate(cited, |count| {
+						let index = *count;
+						*count = count.saturating_add(1);
+						index
+					});
+					CitedBy::<T>::insert(cited, index, content_hash);
+					documents += 1;
+				}
+			}
+			let external = references.len() as u32 - documents;
+			References::<T>::insert(content_hash, references);
+
+			Self::deposit_event(Event::ReferencesDeclared { content_hash, documents, external });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The number of documents citing `content_hash`.
+		pub fn citation_count(content_hash: &T::Hash) -> u32 {
+			CitationCount::<T>::get(content_hash)
+		}
+
+		/// The `page`th page of the documents citing `content_hash`, in the order their references
+		/// were declared, with pages of `page_size` documents, at most [`Config::MaxPageSize`].
+		pub fn cited_by(content_hash: &T::Hash, page: u32, page_size: u32) -> Vec<T::Hash> {
+			let page_size = page_size.min(T::MaxPageSize::get());
+			let start = page.saturating_mul(page_size);
+			let end = start.saturating_add(page_size).min(Self::citation_count(content_hash));
+			(start..end)
+				.filter_map(|index| CitedBy::<T>::get(content_hash, index))
+				.collect()
+		}
+	}
+}
@@ -0,0 +1,115 @@
+use crate as pallet_citations;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU16, ConstU32, ConstU64, Contains},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use speem_primitives::{DocumentRegistrant, DocumentRegistration};
+use std::cell::RefCell;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Citations: pallet_citations,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+thread_local! {
+	static REGISTERED: RefCell<Vec<(H256, u64, u64)>> = RefCell::new(Vec::new());
+}
+
+/// A stand-in for the manuscript registry.
+pub struct MockDocuments;
+
+impl MockDocuments {
+	/// Mark `document` as a final registry entry registered by `registrant` in the current block.
+	pub fn register(document: H256, registrant: u64) {
+		let now = System::block_number();
+		REGISTERED.with(|registered| registered.borrow_mut().push((document, registrant, now)));
+	}
+
+	fn record(document: &H256) -> Option<(H256, u64, u64)> {
+		REGISTERED.with(|registered| {
+			registered.borrow().iter().find(|(hash, ..)| hash == document).copied()
+		})
+	}
+}
+
+impl Contains<H256> for MockDocuments {
+	fn contains(document: &H256) -> bool {
+		Self::registrant_of(document).is_some()
+	}
+}
+
+impl DocumentRegistrant<u64, H256> for MockDocuments {
+	fn registrant_of(document: &H256) -> Option<u64> {
+		Self::record(document).map(|(_, who, _)| who)
+	}
+}
+
+impl DocumentRegistration<H256, u64> for MockDocuments {
+	fn registered_at(document: &H256) -> Option<u64> {
+		Self::record(document).map(|(.., at)| at)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<H256, u64> for MockDocuments {
+	fn registered_document(seed: u32) -> (H256, u64) {
+		let document = H256::from_low_u64_be(seed as u64 + 1);
+		Self::register(document, 1_000);
+		(document, 1_000)
+	}
+}
+
+impl pallet_citations::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Documents = MockDocuments;
+	type MaxReferences = ConstU32<3>;
+	type MaxIdentifierLength = ConstU32<16>;
+	type MaxPageSize = ConstU32<2>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockDocuments;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	REGISTERED.with(|registered| registered.borrow_mut().clear());
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+}
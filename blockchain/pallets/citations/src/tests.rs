@@ -0,0 +1,125 @@
+use crate::{mock::*, CitationCount, CitedBy, Error, Event, ExternalScheme, Reference, References};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use sp_core::H256;
+
+const DOCUMENT: H256 = H256::repeat_byte(2);
+const CITED: H256 = H256::repeat_byte(3);
+const OTHER_CITED: H256 = H256::repeat_byte(4);
+const REGISTRANT: u64 = 1;
+
+fn doi(identifier: &[u8]) -> Reference<Test> {
+	Reference::External {
+		scheme: ExternalScheme::Doi,
+		identifier: identifier.to_vec().try_into().unwrap(),
+	}
+}
+
+fn declare(content_hash: H256, references: Vec<Reference<Test>>) -> DispatchResult {
+	Citations::declare_references(RuntimeOrigin::signed(REGISTRANT), content_hash, references)
+}
+
+#[test]
+fn declare_references_builds_the_cited_by_index() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+		MockDocuments::register(CITED, 2);
+		System::set_block_number(2);
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+
+		let references = vec![Reference::Document(CITED), doi(b"10.1000/182")];
+		assert_ok!(declare(DOCUMENT, references.clone()));
+		System::assert_last_event(
+			Event::ReferencesDeclared { content_hash: DOCUMENT, documents: 1, external: 1 }.into(),
+		);
+		assert_eq!(References::<Test>::get(DOCUMENT).unwrap().into_inner(), references);
+		assert_eq!(CitationCount::<Test>::get(CITED), 1);
+		assert_eq!(CitedBy::<Test>::get(CITED, 0), Some(DOCUMENT));
+
+		assert_noop!(declare(DOCUMENT, vec![]), Error::<Test>::AlreadyDeclared);
+	});
+}
+
+#[test]
+fn only_registrants_of_final_documents_declare_references() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(declare(DOCUMENT, vec![]), Error::<Test>::UnregisteredDocument);
+
+		MockDocuments::register(DOCUMENT, 2);
+		assert_noop!(declare(DOCUMENT, vec![]), Error::<Test>::NotRegistrant);
+	});
+}
+
+#[test]
+fn invalid_references_are_rejected() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(CITED, 2);
+		System::set_block_number(1);
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+
+		assert_noop!(
+			declare(DOCUMENT, vec![Reference::Document(OTHER_CITED)]),
+			Error::<Test>::UnknownReference
+		);
+		assert_noop!(
+			declare(DOCUMENT, vec![Reference::Document(DOCUMENT)]),
+			Error::<Test>::SelfCitation
+		);
+		assert_noop!(
+			declare(DOCUMENT, vec![Reference::Document(CITED), Reference::Document(CITED)]),
+			Error::<Test>::DuplicateReference
+		);
+		assert_noop!(declare(DOCUMENT, vec![doi(b"")]), Error::<Test>::EmptyIdentifier);
+		assert_noop!(
+			declare(DOCUMENT, vec![doi(b"1"), doi(b"2"), doi(b"3"), doi(b"4")]),
+			Error::<Test>::TooManyReferences
+		);
+	});
+}
+
+#[test]
+fn cited_by_is_paged_in_declaration_order() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(CITED, 2);
+		System::set_block_number(1);
+		let citing: Vec<H256> = (10..15).map(H256::repeat_byte).collect();
+		for document in &citing {
+			MockDocuments::register(*document, REGISTRANT);
+			assert_ok!(declare(*document, vec![Reference::Document(CITED)]));
+		}
+
+		assert_eq!(Citations::citation_count(&CITED), 5);
+		assert_eq!(Citations::citation_count(&OTHER_CITED), 0);
+		assert_eq!(Citations::cited_by(&CITED, 0, 2), citing[0..2].to_vec());
+		assert_eq!(Citations::cited_by(&CITED, 2, 2), citing[4..].to_vec());
+		assert!(Citations::cited_by(&CITED, 3, 2).is_empty());
+		// Pages are no larger than the configured maximum.
+		assert_eq!(Citations::cited_by(&CITED, 1, 10), citing[2..4].to_vec());
+	});
+}
+
+#[test]
+fn only_earlier_documents_are_cited() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		MockDocuments::register(OTHER_CITED, REGISTRANT);
+		System::set_block_number(2);
+		MockDocuments::register(CITED, REGISTRANT);
+
+		// A document cites neither later documents nor those registered in the same block.
+		assert_noop!(
+			declare(DOCUMENT, vec![Reference::Document(CITED)]),
+			Error::<Test>::LaterReference
+		);
+		assert_noop!(
+			declare(DOCUMENT, vec![Reference::Document(OTHER_CITED)]),
+			Error::<Test>::LaterReference
+		);
+
+		// Once a later document cites an earlier one, the earlier one cannot cite it back.
+		assert_ok!(declare(CITED, vec![Reference::Document(DOCUMENT)]));
+		assert_ok!(declare(DOCUMENT, vec![doi(b"10.1000/182")]));
+		assert_eq!(Citations::citation_count(&CITED), 0);
+	});
+}
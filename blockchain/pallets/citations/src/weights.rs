@@ -0,0 +1,45 @@
+
+//! Weights for pallet_citations
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_citations` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_citations.
+pub trait WeightInfo {
+	fn declare_references() -> Weight;
+}
+
+/// Weights for pallet_citations using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Manuscripts Manuscripts (r:129 w:0)
+	/// Storage: Citations References (r:1 w:1)
+	/// Storage: Citations CitationCount (r:128 w:128)
+	/// Storage: Citations CitedBy (r:0 w:128)
+	fn declare_references() -> Weight {
+		Weight::from_parts(1_185_000_000, 1008991)
+			.saturating_add(T::DbWeight::get().reads(258_u64))
+			.saturating_add(T::DbWeight::get().writes(257_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Manuscripts Manuscripts (r:129 w:0)
+	/// Storage: Citations References (r:1 w:1)
+	/// Storage: Citations CitationCount (r:128 w:128)
+	/// Storage: Citations CitedBy (r:0 w:128)
+	fn declare_references() -> Weight {
+		Weight::from_parts(1_185_000_000, 1008991)
+			.saturating_add(RocksDbWeight::get().reads(258_u64))
+			.saturating_add(RocksDbWeight::get().writes(257_u64))
+	}
+}
@@ -0,0 +1,188 @@
+//! Benchmarking setup for pallet-institutions
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as InstitutionsPallet;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	sp_runtime::Saturating,
+	traits::{
+		fungible::{Inspect, Mutate},
+		Get,
+	},
+};
+use frame_system::RawOrigin;
+use sp_std::{prelude::*, vec};
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	let amount = T::Currency::minimum_balance()
+		.saturating_add(T::ApplicationDeposit::get().saturating_mul(10u32.into()));
+	T::Currency::set_balance(&caller, amount);
+	caller
+}
+
+/// `count` distinct admin accounts, derived from `seed`.
+fn admins<T: Config>(seed: &'static str, count: u32) -> Vec<T::AccountId> {
+	(0..count).map(|i| account(seed, i, 0)).collect()
+}
+
+/// Register an institution with details at the configured maximum size and the given status.
+fn insert<T: Config>(admins: Vec<T::AccountId>, status: InstitutionStatus) -> InstitutionId {
+	let institution = Pallet::<T>::bound_institution(
+		vec![b'n'; T::MaxNameLength::get() as usize],
+		*b"CH",
+		vec![b'd'; T::MaxDomainLength::get() as usize],
+		admins,
+		status,
+	)
+	.expect("details are within bounds");
+	Pallet::<T>::insert_institution(institution).expect("admins are fresh")
+}
+
+/// Register an active institution and count it, along with its representative.
+fn activate<T: Config>(admins: Vec<T::AccountId>) -> InstitutionId {
+	let representative = admins[0].clone();
+	let institution = insert::<T>(admins, InstitutionStatus::Active);
+	Pallet::<T>::increment_active().expect("below the maximum");
+	Pallet::<T>::change_representative(None, Some(&representative));
+	institution
+}
+
+/// Fill the consortium with active institutions until it has room for exactly one more, returning
+/// an admin of every active institution.
+fn fill_consortium<T: Config>() -> Vec<T::AccountId> {
+	let mut index = 0;
+	while ActiveCount::<T>::get() + 1 < T::MaxInstitutions::get() {
+		activate::<T>(vec![account("voter", index, 0)]);
+		index += 1;
+	}
+	Institutions::<T>::iter_values()
+		.filter(|record| record.status == InstitutionStatus::Active)
+		.map(|record| record.admins[0].clone())
+		.collect()
+}
+
+/// Open an application for an institution with the maximum number of admins.
+fn apply_max<T: Config>(applicant: &T::AccountId) -> InstitutionId {
+	Pallet::<T>::apply(
+		RawOrigin::Signed(applicant.clone()).into(),
+		vec![b'n'; T::MaxNameLength::get() as usize],
+		*b"CH",
+		vec![b'd'; T::MaxDomainLength::get() as usize],
+		admins::<T>("applicant", T::MaxAdmins::get()),
+	)
+	.expect("application succeeds");
+	NextInstitutionId::<T>::get() - 1
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn apply() {
+		let caller = funded_caller::<T>();
+
+		#[extrinsic_call]
+		apply(
+			RawOrigin::Signed(caller),
+			vec![b'n'; T::MaxNameLength::get() as usize],
+			*b"CH",
+			vec![b'd'; T::MaxDomainLength::get() as usize],
+			admins::<T>("applicant", T::MaxAdmins::get()),
+		);
+
+		assert!(Applications::<T>::contains_key(NextInstitutionId::<T>::get() - 1));
+	}
+
+	#[benchmark]
+	fn vote() {
+		let caller = funded_caller::<T>();
+		let mut voters = fill_consortium::<T>();
+		let candidate = apply_max::<T>(&caller);
+		// Cast every vote but the one that admits the candidate, which is the measured call.
+		let required = Pallet::<T>::required_ayes(ActiveCount::<T>::get());
+		voters.truncate(required as usize);
+		let last = voters.pop().expect("the consortium is not empty");
+		for voter in voters {
+			Pallet::<T>::vote(RawOrigin::Signed(voter).into(), candidate, true)
+				.expect("active institutions can vote");
+		}
+
+		#[extrinsic_call]
+		vote(RawOrigin::Signed(last), candidate, true);
+
+		assert_eq!(
+			Institutions::<T>::get(candidate).map(|record| record.status),
+			Some(InstitutionStatus::Active)
+		);
+	}
+
+	#[benchmark]
+	fn close() {
+		let caller = funded_caller::<T>();
+		let candidate = apply_max::<T>(&caller);
+		let expired =
+			frame_system::Pallet::<T>::block_number() + T::VotingPeriod::get() + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(expired);
+
+		#[extrinsic_call]
+		close(RawOrigin::Signed(caller), candidate);
+
+		assert!(!Institutions::<T>::contains_key(candidate));
+	}
+
+	#[benchmark]
+	fn set_admins() {
+		fill_consortium::<T>();
+		let current = admins::<T>("current", T::MaxAdmins::get());
+		let institution = activate::<T>(current.clone());
+		let replacements = admins::<T>("replacement", T::MaxAdmins::get());
+
+		#[extrinsic_call]
+		set_admins(RawOrigin::Signed(current[0].clone()), institution, replacements.clone());
+
+		assert_eq!(
+			Institutions::<T>::get(institution).map(|record| record.admins.into_inner()),
+			Some(replacements)
+		);
+	}
+
+	#[benchmark]
+	fn suspend() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		fill_consortium::<T>();
+		let institution = activate::<T>(admins::<T>("admin", 1));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, institution);
+
+		assert_eq!(
+			Institutions::<T>::get(institution).map(|record| record.status),
+			Some(InstitutionStatus::Suspended)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reinstate() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		fill_consortium::<T>();
+		let institution = insert::<T>(admins::<T>("admin", 1), InstitutionStatus::Suspended);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, institution);
+
+		assert_eq!(
+			Institutions::<T>::get(institution).map(|record| record.status),
+			Some(InstitutionStatus::Active)
+		);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(InstitutionsPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
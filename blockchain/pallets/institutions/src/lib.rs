@@ -0,0 +1,801 @@
+//! # Institutions Pallet
+//!
+//! The registry of the universities, research centres and other institutions taking part in
+//! SPEEM.
+//!
+//! ## Overview
+//!
+//! Each institution is recorded with its name, its country, its internet domain and the accounts
+//! administering it on chain (see [`Institution`]). An institution is either
+//! [`InstitutionStatus::Pending`] admission, [`InstitutionStatus::Active`] or
+//! [`InstitutionStatus::Suspended`].
+//!
+//! ### Admission
+//!
+//! New institutions are admitted by the consortium of existing ones rather than by a privileged
+//! key. Anyone can [`apply`](Pallet::apply) on behalf of an institution, placing
+//! [`Config::ApplicationDeposit`] on hold under [`HoldReason::ApplicationDeposit`]. The admins of
+//! every active institution then [`vote`](Pallet::vote) on the application, with one vote per
+//! institution. As soon as the ayes reach [`Config::AdmissionThreshold`] of the active
+//! institutions the applicant is admitted; as soon as they no longer can, or once
+//! [`Config::VotingPeriod`] has elapsed without a decision, the application is rejected and its
+//! record removed. Either way the deposit is released.
+//!
+//! Listing an account as an admin in an application does not bind that account: admins are only
+//! indexed in [`AdminOf`] once the institution is admitted. Until then they remain free to act for,
+//! or be listed by, any other institution, and admission fails with [`Error::AlreadyAdmin`] if one
+//! of them has meanwhile started acting for another.
+//!
+//! The institutions of the first consortium are set up at genesis.
+//!
+//! ### Suspension
+//!
+//! [`Config::ForceOrigin`] can suspend an active institution and later reinstate it. A suspended
+//! institution keeps its record and admins but takes no part in votes, and its admins are no
+//! longer treated as acting for an active institution.
+//!
+//! ### Representatives
+//!
+//! Each active institution is represented by its first admin. The pallet keeps the sorted list of
+//! [`Representatives`] up to date as institutions are admitted, suspended, reinstated or change
+//! their admins, and forwards every change to [`Config::Representatives`], typically the collective
+//! through which the consortium governs the chain.
+//!
+//! ### Querying the registry
+//!
+//! Other pallets ask whether an account acts for an active institution through the
+//! [`InstitutionInspect`] trait, which this pallet implements, or restrict calls to such accounts
+//! with the [`EnsureActiveAdmin`] origin.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`apply`](Pallet::apply) - Apply for the admission of an institution.
+//! - [`vote`](Pallet::vote) - Vote on an application on behalf of an active institution.
+//! - [`close`](Pallet::close) - Reject an application whose voting period has elapsed.
+//! - [`set_admins`](Pallet::set_admins) - Replace the admins of an institution.
+//! - [`suspend`](Pallet::suspend) - Suspend an active institution.
+//! - [`reinstate`](Pallet::reinstate) - Reinstate a suspended institution.
+//!
+//! Run `cargo doc --package pallet-institutions --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+pub use speem_primitives::{InstitutionId, InstitutionInspect, InstitutionSuspend};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::Perbill,
+		traits::{
+			fungible::{Inspect, Mutate, MutateHold},
+			tokens::Precision,
+			BuildGenesisConfig, ChangeMembers,
+		},
+		CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	/// The balance type of the configured currency.
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// An ISO 3166-1 alpha-2 country code, e.g. `*b"CH"`.
+	pub type CountryCode = [u8; 2];
+
+	/// Where an institution stands with the consortium.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum InstitutionStatus {
+		/// The institution has applied and the active institutions are voting on it.
+		Pending,
+		/// The institution has been admitted.
+		Active,
+		/// The institution has been admitted but is currently suspended.
+		Suspended,
+	}
+
+	/// A participating institution.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Institution<T: Config> {
+		/// The name of the institution, UTF-8 encoded.
+		pub name: BoundedVec<u8, T::MaxNameLength>,
+		/// The country the institution is based in.
+		pub country: CountryCode,
+		/// The internet domain of the institution, e.g. `ethz.ch`.
+		pub domain: BoundedVec<u8, T::MaxDomainLength>,
+		/// The accounts acting on chain on behalf of the institution.
+		pub admins: BoundedVec<T::AccountId, T::MaxAdmins>,
+		/// Where the institution stands with the consortium.
+		pub status: InstitutionStatus,
+	}
+
+	/// An application for admission and the votes cast on it so far.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Application<T: Config> {
+		/// The account that applied, from which the deposit is held.
+		pub applicant: T::AccountId,
+		/// The deposit held from the applicant.
+		pub deposit: BalanceOf<T>,
+		/// The last block in which votes are accepted.
+		pub deadline: BlockNumberFor<T>,
+		/// The active institutions that voted for admission.
+		pub ayes: BoundedVec<InstitutionId, T::MaxInstitutions>,
+		/// The active institutions that voted against admission.
+		pub nays: BoundedVec<InstitutionId, T::MaxInstitutions>,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds held while an application for admission is open.
+		#[codec(index = 0)]
+		ApplicationDeposit,
+	}
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency in which application deposits are held.
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The deposit held from an applicant until the application is decided.
+		#[pallet::constant]
+		type ApplicationDeposit: Get<BalanceOf<Self>>;
+		/// The number of blocks active institutions have to vote on an application.
+		#[pallet::constant]
+		type VotingPeriod: Get<BlockNumberFor<Self>>;
+		/// The share of active institutions that must vote for an application to admit it.
+		#[pallet::constant]
+		type AdmissionThreshold: Get<Perbill>;
+		/// The origin that may suspend and reinstate institutions.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Notified whenever the representatives of the active institutions change.
+		type Representatives: ChangeMembers<Self::AccountId>;
+		/// The maximum number of active institutions.
+		#[pallet::constant]
+		type MaxInstitutions: Get<u32>;
+		/// The maximum number of admins an institution can have.
+		#[pallet::constant]
+		type MaxAdmins: Get<u32>;
+		/// The maximum length of an institution's name, in bytes.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+		/// The maximum length of an institution's domain, in bytes.
+		#[pallet::constant]
+		type MaxDomainLength: Get<u32>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Registered institutions, keyed by identifier.
+	#[pallet::storage]
+	pub type Institutions<T: Config> =
+		StorageMap<_, Twox64Concat, InstitutionId, Institution<T>, OptionQuery>;
+
+	/// Open applications, keyed by the identifier of the applying institution.
+	#[pallet::storage]
+	pub type Applications<T: Config> =
+		StorageMap<_, Twox64Concat, InstitutionId, Application<T>, OptionQuery>;
+
+	/// The institution each admin account acts for. Only admins of admitted institutions are
+	/// indexed.
+	#[pallet::storage]
+	pub type AdminOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, InstitutionId, OptionQuery>;
+
+	/// The identifier the next registered institution will receive.
+	#[pallet::storage]
+	pub type NextInstitutionId<T> = StorageValue<_, InstitutionId, ValueQuery>;
+
+	/// The number of active institutions.
+	#[pallet::storage]
+	pub type ActiveCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The representative of every active institution, sorted.
+	#[pallet::storage]
+	pub type Representatives<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxInstitutions>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The institutions active from genesis, as `(name, country, domain, admins)`.
+		pub institutions: Vec<(Vec<u8>, CountryCode, Vec<u8>, Vec<T::AccountId>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (name, country, domain, admins) in &self.institutions {
+				let institution = Pallet::<T>::bound_institution(
+					name.clone(),
+					*country,
+					domain.clone(),
+					admins.clone(),
+					InstitutionStatus::Active,
+				)
+				.expect("genesis institutions must respect the configured bounds");
+				let representative = institution.admins[0].clone();
+				Pallet::<T>::insert_institution(institution)
+					.expect("genesis institutions must not share admins");
+				ActiveCount::<T>::mutate(|count| *count += 1);
+				Pallet::<T>::change_representative(None, Some(&representative));
+			}
+			assert!(
+				ActiveCount::<T>::get() <= T::MaxInstitutions::get(),
+				"too many genesis institutions"
+			);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An institution has applied for admission.
+		Applied {
+			/// The identifier assigned to the institution.
+			institution: InstitutionId,
+			/// The account that applied on its behalf.
+			applicant: T::AccountId,
+		},
+		/// An active institution has voted on an application.
+		Voted {
+			/// The applying institution.
+			institution: InstitutionId,
+			/// The institution that voted.
+			voter: InstitutionId,
+			/// Whether it voted for admission.
+			approve: bool,
+		},
+		/// An institution has been admitted by the consortium.
+		Admitted {
+			/// The admitted institution.
+			institution: InstitutionId,
+		},
+		/// An application has been rejected and the institution's record removed.
+		Rejected {
+			/// The rejected institution.
+			institution: InstitutionId,
+		},
+		/// The admins of an institution have been replaced.
+		AdminsUpdated {
+			/// The institution.
+			institution: InstitutionId,
+		},
+		/// An institution has been suspended.
+		Suspended {
+			/// The suspended institution.
+			institution: InstitutionId,
+		},
+		/// A suspended institution has been reinstated.
+		Reinstated {
+			/// The reinstated institution.
+			institution: InstitutionId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The name is longer than [`Config::MaxNameLength`].
+		NameTooLong,
+		/// The domain is longer than [`Config::MaxDomainLength`].
+		DomainTooLong,
+		/// More admins were listed than [`Config::MaxAdmins`] allows.
+		TooManyAdmins,
+		/// An institution must have at least one admin.
+		NoAdmins,
+		/// The same account is listed as an admin more than once.
+		DuplicateAdmin,
+		/// The country is not a code of two uppercase ASCII letters.
+		InvalidCountryCode,
+		/// A listed account already administers an institution.
+		AlreadyAdmin,
+		/// No institution exists with the given identifier.
+		UnknownInstitution,
+		/// The institution has no open application.
+		NotPending,
+		/// The signer is not an admin of an active institution.
+		NotActiveAdmin,
+		/// The signer is not an admin of the institution.
+		NotAdmin,
+		/// The signer's institution has already voted on the application.
+		AlreadyVoted,
+		/// The voting period of the application has elapsed.
+		VotingPeriodElapsed,
+		/// The voting period of the application has not elapsed yet.
+		VotingPeriodOngoing,
+		/// The consortium already has [`Config::MaxInstitutions`] active institutions.
+		TooManyInstitutions,
+		/// The institution is not active.
+		NotActive,
+		/// The institution is not suspended.
+		NotSuspended,
+		/// The identifier counter has been exhausted.
+		Overflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Apply for the admission of an institution.
+		///
+		/// The origin must be signed; [`Config::ApplicationDeposit`] is held from the signer until
+		/// the application is decided. The signer need not be one of the listed `admins`.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NameTooLong`], [`Error::DomainTooLong`] or [`Error::TooManyAdmins`] if the
+		///   details exceed the configured bounds.
+		/// - [`Error::NoAdmins`] if `admins` is empty, [`Error::DuplicateAdmin`] if an admin is
+		///   listed twice.
+		/// - [`Error::InvalidCountryCode`] if `country` is not two uppercase ASCII letters.
+		/// - [`Error::AlreadyAdmin`] if a listed admin already acts for an institution.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::apply())]
+		pub fn apply(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			country: CountryCode,
+			domain: Vec<u8>,
+			admins: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let applicant = ensure_signed(origin)?;
+
+			let institution =
+				Self::bound_institution(name, country, domain, admins, InstitutionStatus::Pending)?;
+			let deposit = T::ApplicationDeposit::get();
+			T::Currency::hold(&HoldReason::ApplicationDeposit.into(), &applicant, deposit)?;
+			let id = Self::insert_institution(institution)?;
+
+			let deadline =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::VotingPeriod::get());
+			Applications::<T>::insert(
+				id,
+				Application {
+					applicant: applicant.clone(),
+					deposit,
+					deadline,
+					ayes: BoundedVec::new(),
+					nays: BoundedVec::new(),
+				},
+			);
+
+			Self::deposit_event(Event::Applied { institution: id, applicant });
+			Ok(())
+		}
+
+		/// Vote on an application on behalf of the signer's institution.
+		///
+		/// The origin must be signed by an admin of an active institution; each institution votes
+		/// once. The application is decided as soon as the outcome is certain.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotActiveAdmin`] if the signer does not act for an active institution.
+		/// - [`Error::NotPending`] if `institution` has no open application.
+		/// - [`Error::VotingPeriodElapsed`] if the voting period is over.
+		/// - [`Error::AlreadyVoted`] if the signer's institution has already voted.
+		/// - [`Error::TooManyInstitutions`] if the vote would admit an institution beyond
+		///   [`Config::MaxInstitutions`].
+		/// - [`Error::AlreadyAdmin`] if the vote would admit an institution one of whose admins has
+		///   started acting for another institution since it applied.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::vote())]
+		pub fn vote(
+			origin: OriginFor<T>,
+			institution: InstitutionId,
+			approve: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let voter = AdminOf::<T>::get(&who)
+				.filter(|institution| Self::is_active(*institution))
+				.ok_or(Error::<T>::NotActiveAdmin)?;
+			let mut application =
+				Applications::<T>::get(institution).ok_or(Error::<T>::NotPending)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= application.deadline,
+				Error::<T>::VotingPeriodElapsed
+			);
+			ensure!(
+				!application.ayes.contains(&voter) && !application.nays.contains(&voter),
+				Error::<T>::AlreadyVoted
+			);
+
+			let votes = if approve { &mut application.ayes } else { &mut application.nays };
+			votes.try_push(voter).map_err(|_| Error::<T>::TooManyInstitutions)?;
+			Self::deposit_event(Event::Voted { institution, voter, approve });
+
+			let active = ActiveCount::<T>::get();
+			let required = Self::required_ayes(active);
+			if application.ayes.len() as u32 >= required {
+				Self::admit(institution, application)
+			} else if application.nays.len() as u32 > active.saturating_sub(required) {
+				Self::reject(institution, application)
+			} else {
+				Applications::<T>::insert(institution, application);
+				Ok(())
+			}
+		}
+
+		/// Reject an application whose voting period has elapsed without a decision.
+		///
+		/// The origin must be signed; anyone may clean up an expired application.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotPending`] if `institution` has no open application.
+		/// - [`Error::VotingPeriodOngoing`] if votes are still accepted.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::close())]
+		pub fn close(origin: OriginFor<T>, institution: InstitutionId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let application = Applications::<T>::get(institution).ok_or(Error::<T>::NotPending)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > application.deadline,
+				Error::<T>::VotingPeriodOngoing
+			);
+
+			Self::reject(institution, application)
+		}
+
+		/// Replace the admins of an institution.
+		///
+		/// The origin must be signed by one of the institution's current admins.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotAdmin`] if the signer does not act for `institution`.
+		/// - [`Error::NoAdmins`], [`Error::DuplicateAdmin`] or [`Error::TooManyAdmins`] if `admins`
+		///   is empty, lists an account twice or is too long.
+		/// - [`Error::AlreadyAdmin`] if a listed admin acts for another institution.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_admins())]
+		pub fn set_admins(
+			origin: OriginFor<T>,
+			institution: InstitutionId,
+			admins: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(AdminOf::<T>::get(&who) == Some(institution), Error::<T>::NotAdmin);
+			let mut record =
+				Institutions::<T>::get(institution).ok_or(Error::<T>::UnknownInstitution)?;
+			let admins = Self::bound_admins(admins)?;
+			for admin in &admins {
+				ensure!(
+					AdminOf::<T>::get(admin).map_or(true, |other| other == institution),
+					Error::<T>::AlreadyAdmin
+				);
+			}
+
+			for admin in &record.admins {
+				AdminOf::<T>::remove(admin);
+			}
+			for admin in &admins {
+				AdminOf::<T>::insert(admin, institution);
+			}
+			if record.status == InstitutionStatus::Active {
+				Self::change_representative(record.admins.first(), admins.first());
+			}
+			record.admins = admins;
+			Institutions::<T>::insert(institution, record);
+
+			Self::deposit_event(Event::AdminsUpdated { institution });
+			Ok(())
+		}
+
+		/// Suspend an active institution.
+		///
+		/// The origin must be [`Config::ForceOrigin`].
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownInstitution`] if `institution` does not exist.
+		/// - [`Error::NotActive`] if it is not active.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::suspend())]
+		pub fn suspend(origin: OriginFor<T>, institution: InstitutionId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::suspend_institution(institution)
+		}
+
+		/// Reinstate a suspended institution.
+		///
+		/// The origin must be [`Config::ForceOrigin`].
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownInstitution`] if `institution` does not exist.
+		/// - [`Error::NotSuspended`] if it is not suspended.
+		/// - [`Error::TooManyInstitutions`] if the consortium is already full.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::reinstate())]
+		pub fn reinstate(origin: OriginFor<T>, institution: InstitutionId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let mut record =
+				Institutions::<T>::get(institution).ok_or(Error::<T>::UnknownInstitution)?;
+			ensure!(record.status == InstitutionStatus::Suspended, Error::<T>::NotSuspended);
+			Self::increment_active()?;
+			Self::change_representative(None, record.admins.first());
+
+			record.status = InstitutionStatus::Active;
+			Institutions::<T>::insert(institution, record);
+
+			Self::deposit_event(Event::Reinstated { institution });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> InstitutionInspect<T::AccountId> for Pallet<T> {
+		fn institution_of(who: &T::AccountId) -> Option<InstitutionId> {
+			AdminOf::<T>::get(who)
+		}
+
+		fn is_active(institution: InstitutionId) -> bool {
+			Institutions::<T>::get(institution)
+				.map_or(false, |record| record.status == InstitutionStatus::Active)
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn register_active_admin(who: &T::AccountId) -> InstitutionId {
+			let institution = Self::bound_institution(
+				b"Benchmark University".to_vec(),
+				*b"CH",
+				b"example.org".to_vec(),
+				sp_std::vec![who.clone()],
+				InstitutionStatus::Active,
+			)
+			.expect("details are within any sensible bounds");
+			let id = Self::insert_institution(institution).expect("`who` is not an admin yet");
+			Self::increment_active().expect("the consortium has room for one more institution");
+			Self::change_representative(None, Some(who));
+			id
+		}
+	}
+
+	/// Ensures that the origin is signed by an admin of an active institution, yielding the signer.
+	pub struct EnsureActiveAdmin<T>(PhantomData<T>);
+
+	impl<T: Config> EnsureOrigin<T::RuntimeOrigin> for EnsureActiveAdmin<T> {
+		type Success = T::AccountId;
+
+		fn try_origin(origin: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+			origin.into().and_then(|origin| match origin {
+				frame_system::RawOrigin::Signed(who) if Pallet::<T>::is_active_admin(&who) =>
+					Ok(who),
+				origin => Err(origin.into()),
+			})
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+			let who: T::AccountId = frame_benchmarking::account("active admin", 0, 0);
+			if !Pallet::<T>::is_active_admin(&who) {
+				Pallet::<T>::register_active_admin(&who);
+			}
+			Ok(frame_system::RawOrigin::Signed(who).into())
+		}
+	}
+
+	impl<T: Config> InstitutionSuspend for Pallet<T> {
+		fn suspend(institution: InstitutionId) {
+			// Suspending an institution that is not active is a no-op.
+			let _ = Self::suspend_institution(institution);
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Suspend an active institution, e.g. in response to misbehaviour of its validator.
+		pub fn suspend_institution(institution: InstitutionId) -> DispatchResult {
+			let mut record =
+				Institutions::<T>::get(institution).ok_or(Error::<T>::UnknownInstitution)?;
+			ensure!(record.status == InstitutionStatus::Active, Error::<T>::NotActive);
+
+			Self::change_representative(record.admins.first(), None);
+			record.status = InstitutionStatus::Suspended;
+			Institutions::<T>::insert(institution, record);
+			ActiveCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::Suspended { institution });
+			Ok(())
+		}
+
+		/// The number of ayes that admit an application when `active` institutions can vote.
+		pub fn required_ayes(active: u32) -> u32 {
+			T::AdmissionThreshold::get().mul_ceil(active).max(1)
+		}
+
+		/// Admit the applicant of `application` and release its deposit.
+		fn admit(institution: InstitutionId, application: Application<T>) -> DispatchResult {
+			Self::increment_active()?;
+			Self::release_deposit(&application)?;
+
+			Institutions::<T>::try_mutate(institution, |record| -> DispatchResult {
+				let record = record.as_mut().ok_or(Error::<T>::UnknownInstitution)?;
+				Self::index_admins(institution, &record.admins)?;
+				record.status = InstitutionStatus::Active;
+				Self::change_representative(None, record.admins.first());
+				Ok(())
+			})?;
+			Applications::<T>::remove(institution);
+
+			Self::deposit_event(Event::Admitted { institution });
+			Ok(())
+		}
+
+		/// Reject `application`, removing the institution's record and releasing the deposit.
+		fn reject(institution: InstitutionId, application: Application<T>) -> DispatchResult {
+			Self::release_deposit(&application)?;
+
+			// The admins of a pending institution were never indexed.
+			Institutions::<T>::remove(institution);
+			Applications::<T>::remove(institution);
+
+			Self::deposit_event(Event::Rejected { institution });
+			Ok(())
+		}
+
+		fn release_deposit(application: &Application<T>) -> DispatchResult {
+			T::Currency::release(
+				&HoldReason::ApplicationDeposit.into(),
+				&application.applicant,
+				application.deposit,
+				Precision::BestEffort,
+			)
+			.map(|_| ())
+		}
+
+		pub(crate) fn increment_active() -> DispatchResult {
+			ActiveCount::<T>::try_mutate(|count| {
+				ensure!(*count < T::MaxInstitutions::get(), Error::<T>::TooManyInstitutions);
+				*count += 1;
+				Ok(())
+			})
+		}
+
+		/// Replace `outgoing` with `incoming` among the representatives and notify
+		/// [`Config::Representatives`] of the change.
+		pub(crate) fn change_representative(
+			outgoing: Option<&T::AccountId>,
+			incoming: Option<&T::AccountId>,
+		) {
+			if outgoing == incoming {
+				return;
+			}
+
+			let mut representatives = Representatives::<T>::get();
+			let outgoing = outgoing
+				.filter(|who| match representatives.binary_search(who) {
+					Ok(index) => {
+						representatives.remove(index);
+						true
+					},
+					Err(_) => false,
+				})
+				.cloned();
+			// There is at most one representative per active institution, so the list cannot
+			// overflow.
+			let incoming = incoming
+				.filter(|who| match representatives.binary_search(who) {
+					Ok(_) => false,
+					Err(index) => representatives.try_insert(index, (*who).clone()).is_ok(),
+				})
+				.cloned();
+
+			T::Representatives::change_members_sorted(
+				incoming.as_slice(),
+				outgoing.as_slice(),
+				&representatives,
+			);
+			Representatives::<T>::put(representatives);
+		}
+
+		/// Store a new institution under the next identifier, indexing its admins unless it is
+		/// still pending admission.
+		pub(crate) fn insert_institution(
+			institution: Institution<T>,
+		) -> Result<InstitutionId, DispatchError> {
+			for admin in &institution.admins {
+				ensure!(!AdminOf::<T>::contains_key(admin), Error::<T>::AlreadyAdmin);
+			}
+
+			let id = NextInstitutionId::<T>::get();
+			NextInstitutionId::<T>::put(id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			if institution.status != InstitutionStatus::Pending {
+				Self::index_admins(id, &institution.admins)?;
+			}
+			Institutions::<T>::insert(id, institution);
+			Ok(id)
+		}
+
+		/// Record `admins` as acting for `institution`, failing if any of them already acts for
+		/// an institution.
+		fn index_admins(institution: InstitutionId, admins: &[T::AccountId]) -> DispatchResult {
+			for admin in admins {
+				ensure!(!AdminOf::<T>::contains_key(admin), Error::<T>::AlreadyAdmin);
+			}
+			for admin in admins {
+				AdminOf::<T>::insert(admin, institution);
+			}
+			Ok(())
+		}
+
+		/// Convert unbounded institution details into an [`Institution`], failing with the error
+		/// matching the first bound that is exceeded.
+		pub(crate) fn bound_institution(
+			name: Vec<u8>,
+			country: CountryCode,
+			domain: Vec<u8>,
+			admins: Vec<T::AccountId>,
+			status: InstitutionStatus,
+		) -> Result<Institution<T>, Error<T>> {
+			ensure!(country.iter().all(u8::is_ascii_uppercase), Error::<T>::InvalidCountryCode);
+			Ok(Institution {
+				name: name.try_into().map_err(|_| Error::<T>::NameTooLong)?,
+				country,
+				domain: domain.try_into().map_err(|_| Error::<T>::DomainTooLong)?,
+				admins: Self::bound_admins(admins)?,
+				status,
+			})
+		}
+
+		fn bound_admins(
+			admins: Vec<T::AccountId>,
+		) -> Result<BoundedVec<T::AccountId, T::MaxAdmins>, Error<T>> {
+			ensure!(!admins.is_empty(), Error::<T>::NoAdmins);
+			let mut sorted = admins.clone();
+			sorted.sort();
+			sorted.dedup();
+			ensure!(sorted.len() == admins.len(), Error::<T>::DuplicateAdmin);
+			admins.try_into().map_err(|_| Error::<T>::TooManyAdmins)
+		}
+	}
+}
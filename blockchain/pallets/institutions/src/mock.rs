@@ -0,0 +1,130 @@
+use crate as pallet_institutions;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ChangeMembers, ConstU16, ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, Perbill,
+};
+use std::cell::RefCell;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		InstitutionsModule: pallet_institutions,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = ();
+}
+
+thread_local! {
+	static COUNCIL: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+/// A stand-in for the governance collective, recording the members it was last handed.
+pub struct MockCouncil;
+
+impl ChangeMembers<u64> for MockCouncil {
+	fn change_members_sorted(_incoming: &[u64], _outgoing: &[u64], new: &[u64]) {
+		COUNCIL.with(|council| *council.borrow_mut() = new.to_vec());
+	}
+}
+
+/// The current members of the mock council.
+pub fn council() -> Vec<u64> {
+	COUNCIL.with(|council| council.borrow().clone())
+}
+
+parameter_types! {
+	pub AdmissionThreshold: Perbill = Perbill::from_rational(2u32, 3u32);
+}
+
+impl pallet_institutions::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ApplicationDeposit = ConstU64<100>;
+	type VotingPeriod = ConstU64<10>;
+	type AdmissionThreshold = AdmissionThreshold;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Representatives = MockCouncil;
+	type MaxInstitutions = ConstU32<4>;
+	type MaxAdmins = ConstU32<3>;
+	type MaxNameLength = ConstU32<32>;
+	type MaxDomainLength = ConstU32<16>;
+	type WeightInfo = ();
+}
+
+/// The free balance every test account starts with.
+pub const INITIAL_BALANCE: u64 = 1_000;
+
+// Build genesis storage according to the mock runtime: accounts 1, 2 and 3 administer the three
+// institutions of the founding consortium.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	COUNCIL.with(|council| council.borrow_mut().clear());
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..=9).map(|who| (who, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	pallet_institutions::GenesisConfig::<Test> {
+		institutions: vec![
+			(b"University of Basel".to_vec(), *b"CH", b"unibas.ch".to_vec(), vec![1]),
+			(b"ETH Zurich".to_vec(), *b"CH", b"ethz.ch".to_vec(), vec![2]),
+			(b"Sorbonne University".to_vec(), *b"FR", b"sorbonne.fr".to_vec(), vec![3]),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	storage.into()
+}
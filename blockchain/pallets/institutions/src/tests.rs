@@ -0,0 +1,343 @@
+use crate::{
+	mock::*, ActiveCount, AdminOf, Applications, EnsureActiveAdmin, Error, Event, HoldReason,
+	InstitutionInspect, InstitutionStatus, InstitutionSuspend, Institutions, Representatives,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResult,
+	traits::{fungible::InspectHold, EnsureOrigin},
+};
+use sp_runtime::DispatchError;
+
+/// The identifier the first applicant receives after the three genesis institutions.
+const APPLICANT: u32 = 3;
+
+fn apply(who: u64, admins: Vec<u64>) -> DispatchResult {
+	InstitutionsModule::apply(
+		RuntimeOrigin::signed(who),
+		b"University of Geneva".to_vec(),
+		*b"CH",
+		b"unige.ch".to_vec(),
+		admins,
+	)
+}
+
+fn vote(who: u64, institution: u32, approve: bool) -> DispatchResult {
+	InstitutionsModule::vote(RuntimeOrigin::signed(who), institution, approve)
+}
+
+fn held(who: u64) -> u64 {
+	Balances::balance_on_hold(&HoldReason::ApplicationDeposit.into(), &who)
+}
+
+fn status(institution: u32) -> Option<InstitutionStatus> {
+	Institutions::<Test>::get(institution).map(|record| record.status)
+}
+
+#[test]
+fn genesis_institutions_are_active() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ActiveCount::<Test>::get(), 3);
+		assert_eq!(status(1), Some(InstitutionStatus::Active));
+		assert_eq!(<InstitutionsModule as InstitutionInspect<u64>>::institution_of(&2), Some(1));
+		assert!(<InstitutionsModule as InstitutionInspect<u64>>::is_active_admin(&2));
+		assert!(!<InstitutionsModule as InstitutionInspect<u64>>::is_active_admin(&9));
+	});
+}
+
+#[test]
+fn apply_registers_pending_institution() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		assert_ok!(apply(5, vec![5, 6]));
+
+		let record = Institutions::<Test>::get(APPLICANT).expect("institution was registered");
+		assert_eq!(record.name.into_inner(), b"University of Geneva".to_vec());
+		assert_eq!(record.country, *b"CH");
+		assert_eq!(record.domain.into_inner(), b"unige.ch".to_vec());
+		assert_eq!(record.admins.into_inner(), vec![5, 6]);
+		assert_eq!(record.status, InstitutionStatus::Pending);
+		assert_eq!(Applications::<Test>::get(APPLICANT).unwrap().deadline, 11);
+		assert_eq!(AdminOf::<Test>::get(6), None);
+		assert_eq!(held(5), 100);
+		assert!(!<InstitutionsModule as InstitutionInspect<u64>>::is_active_admin(&5));
+		System::assert_last_event(Event::Applied { institution: APPLICANT, applicant: 5 }.into());
+	});
+}
+
+#[test]
+fn invalid_applications_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let apply_with = |name: &[u8], country: [u8; 2], domain: &[u8], admins: Vec<u64>| {
+			InstitutionsModule::apply(
+				RuntimeOrigin::signed(5),
+				name.to_vec(),
+				country,
+				domain.to_vec(),
+				admins,
+			)
+		};
+
+		assert_noop!(
+			apply_with(&[b'n'; 33], *b"CH", b"unige.ch", vec![5]),
+			Error::<Test>::NameTooLong
+		);
+		assert_noop!(
+			apply_with(b"Geneva", *b"CH", &[b'd'; 17], vec![5]),
+			Error::<Test>::DomainTooLong
+		);
+		assert_noop!(
+			apply_with(b"Geneva", *b"ch", b"unige.ch", vec![5]),
+			Error::<Test>::InvalidCountryCode
+		);
+		assert_noop!(apply_with(b"Geneva", *b"CH", b"unige.ch", vec![]), Error::<Test>::NoAdmins);
+		assert_noop!(
+			apply_with(b"Geneva", *b"CH", b"unige.ch", vec![5, 5]),
+			Error::<Test>::DuplicateAdmin
+		);
+		assert_noop!(
+			apply_with(b"Geneva", *b"CH", b"unige.ch", vec![5, 6, 7, 8]),
+			Error::<Test>::TooManyAdmins
+		);
+		assert_noop!(
+			apply_with(b"Geneva", *b"CH", b"unige.ch", vec![5, 1]),
+			Error::<Test>::AlreadyAdmin
+		);
+	});
+}
+
+#[test]
+fn votes_admit_institution() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(apply(5, vec![5]));
+
+		// Two thirds of the three active institutions must approve.
+		assert_ok!(vote(1, APPLICANT, true));
+		System::assert_last_event(
+			Event::Voted { institution: APPLICANT, voter: 0, approve: true }.into(),
+		);
+		assert_eq!(status(APPLICANT), Some(InstitutionStatus::Pending));
+
+		assert_ok!(vote(2, APPLICANT, true));
+		System::assert_last_event(Event::Admitted { institution: APPLICANT }.into());
+		assert_eq!(status(APPLICANT), Some(InstitutionStatus::Active));
+		assert!(!Applications::<Test>::contains_key(APPLICANT));
+		assert_eq!(ActiveCount::<Test>::get(), 4);
+		assert_eq!(held(5), 0);
+		assert!(<InstitutionsModule as InstitutionInspect<u64>>::is_active_admin(&5));
+	});
+}
+
+#[test]
+fn only_active_institutions_vote_once() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(apply(5, vec![5]));
+
+		assert_noop!(vote(9, APPLICANT, true), Error::<Test>::NotActiveAdmin);
+		assert_noop!(vote(5, APPLICANT, true), Error::<Test>::NotActiveAdmin);
+		assert_noop!(vote(1, 7, true), Error::<Test>::NotPending);
+
+		assert_ok!(vote(1, APPLICANT, false));
+		assert_noop!(vote(1, APPLICANT, true), Error::<Test>::AlreadyVoted);
+	});
+}
+
+#[test]
+fn pending_applications_do_not_claim_admins() {
+	new_test_ext().execute_with(|| {
+		// Account 9 lists admins without their consent.
+		assert_ok!(apply(9, vec![5, 6]));
+		assert_eq!(<InstitutionsModule as InstitutionInspect<u64>>::institution_of(&5), None);
+
+		// The listed admins remain free to be listed elsewhere, or to act for another institution.
+		assert_ok!(apply(5, vec![5]));
+		assert_ok!(InstitutionsModule::set_admins(RuntimeOrigin::signed(1), 0, vec![1, 6]));
+		assert_eq!(AdminOf::<Test>::get(6), Some(0));
+
+		// The first application can then no longer be admitted.
+		assert_ok!(vote(1, APPLICANT, true));
+		assert_noop!(vote(2, APPLICANT, true), Error::<Test>::AlreadyAdmin);
+		assert_eq!(status(APPLICANT), Some(InstitutionStatus::Pending));
+	});
+}
+
+#[test]
+fn votes_reject_institution() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(apply(5, vec![5, 6]));
+
+		assert_ok!(vote(1, APPLICANT, false));
+		assert_eq!(status(APPLICANT), Some(InstitutionStatus::Pending));
+
+		// With two nays, two ayes out of three can no longer be reached.
+		assert_ok!(vote(3, APPLICANT, false));
+		System::assert_last_event(Event::Rejected { institution: APPLICANT }.into());
+		assert_eq!(status(APPLICANT), None);
+		assert!(!Applications::<Test>::contains_key(APPLICANT));
+		assert_eq!(AdminOf::<Test>::get(5), None);
+		assert_eq!(AdminOf::<Test>::get(6), None);
+		assert_eq!(held(5), 0);
+	});
+}
+
+#[test]
+fn expired_applications_are_closed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(apply(5, vec![5]));
+		assert_ok!(vote(1, APPLICANT, true));
+
+		System::set_block_number(11);
+		assert_noop!(
+			InstitutionsModule::close(RuntimeOrigin::signed(9), APPLICANT),
+			Error::<Test>::VotingPeriodOngoing
+		);
+
+		System::set_block_number(12);
+		assert_noop!(vote(2, APPLICANT, true), Error::<Test>::VotingPeriodElapsed);
+		assert_ok!(InstitutionsModule::close(RuntimeOrigin::signed(9), APPLICANT));
+		System::assert_last_event(Event::Rejected { institution: APPLICANT }.into());
+		assert_eq!(status(APPLICANT), None);
+		assert_eq!(held(5), 0);
+		assert_noop!(
+			InstitutionsModule::close(RuntimeOrigin::signed(9), APPLICANT),
+			Error::<Test>::NotPending
+		);
+	});
+}
+
+#[test]
+fn admissions_are_capped() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(apply(5, vec![5]));
+		assert_ok!(vote(1, APPLICANT, true));
+		assert_ok!(vote(2, APPLICANT, true));
+		assert_eq!(ActiveCount::<Test>::get(), 4);
+
+		assert_ok!(apply(6, vec![6]));
+		assert_ok!(vote(1, APPLICANT + 1, true));
+		assert_ok!(vote(2, APPLICANT + 1, true));
+		assert_noop!(vote(3, APPLICANT + 1, true), Error::<Test>::TooManyInstitutions);
+	});
+}
+
+#[test]
+fn admins_can_be_replaced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			InstitutionsModule::set_admins(RuntimeOrigin::signed(2), 0, vec![7]),
+			Error::<Test>::NotAdmin
+		);
+		assert_noop!(
+			InstitutionsModule::set_admins(RuntimeOrigin::signed(1), 0, vec![1, 2]),
+			Error::<Test>::AlreadyAdmin
+		);
+
+		assert_ok!(InstitutionsModule::set_admins(RuntimeOrigin::signed(1), 0, vec![7, 8]));
+		System::assert_last_event(Event::AdminsUpdated { institution: 0 }.into());
+		assert_eq!(Institutions::<Test>::get(0).unwrap().admins.into_inner(), vec![7, 8]);
+		assert_eq!(AdminOf::<Test>::get(1), None);
+		assert_eq!(AdminOf::<Test>::get(7), Some(0));
+		assert!(<InstitutionsModule as InstitutionInspect<u64>>::is_active_admin(&8));
+	});
+}
+
+#[test]
+fn suspension_requires_force_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			InstitutionsModule::suspend(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(InstitutionsModule::suspend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::Suspended { institution: 0 }.into());
+		assert_eq!(status(0), Some(InstitutionStatus::Suspended));
+		assert_eq!(ActiveCount::<Test>::get(), 2);
+		assert!(!<InstitutionsModule as InstitutionInspect<u64>>::is_active_admin(&1));
+		assert_noop!(
+			InstitutionsModule::suspend(RuntimeOrigin::root(), 0),
+			Error::<Test>::NotActive
+		);
+
+		assert_ok!(apply(5, vec![5]));
+		assert_noop!(vote(1, APPLICANT, true), Error::<Test>::NotActiveAdmin);
+
+		assert_ok!(InstitutionsModule::reinstate(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::Reinstated { institution: 0 }.into());
+		assert_eq!(status(0), Some(InstitutionStatus::Active));
+		assert_eq!(ActiveCount::<Test>::get(), 3);
+		assert_noop!(
+			InstitutionsModule::reinstate(RuntimeOrigin::root(), 0),
+			Error::<Test>::NotSuspended
+		);
+	});
+}
+
+#[test]
+fn misbehaving_institutions_can_be_suspended() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(apply(5, vec![5]));
+
+		<InstitutionsModule as InstitutionSuspend>::suspend(1);
+		System::assert_last_event(Event::Suspended { institution: 1 }.into());
+		assert_eq!(status(1), Some(InstitutionStatus::Suspended));
+		assert_eq!(ActiveCount::<Test>::get(), 2);
+
+		// Pending and already suspended institutions are left alone.
+		<InstitutionsModule as InstitutionSuspend>::suspend(APPLICANT);
+		<InstitutionsModule as InstitutionSuspend>::suspend(1);
+		assert_eq!(status(APPLICANT), Some(InstitutionStatus::Pending));
+		assert_eq!(ActiveCount::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn active_admin_origin_yields_the_signer() {
+	new_test_ext().execute_with(|| {
+		let ensure = |origin: RuntimeOrigin| EnsureActiveAdmin::<Test>::try_origin(origin).ok();
+		assert_eq!(ensure(RuntimeOrigin::signed(2)), Some(2));
+		assert_eq!(ensure(RuntimeOrigin::signed(9)), None);
+		assert_eq!(ensure(RuntimeOrigin::root()), None);
+
+		assert_ok!(InstitutionsModule::suspend(RuntimeOrigin::root(), 1));
+		assert_eq!(ensure(RuntimeOrigin::signed(2)), None);
+	});
+}
+
+#[test]
+fn representatives_follow_active_institutions() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Representatives::<Test>::get().into_inner(), vec![1, 2, 3]);
+		assert_eq!(council(), vec![1, 2, 3]);
+
+		// Admitted institutions are represented by their first admin.
+		assert_ok!(apply(9, vec![6, 5]));
+		assert_ok!(vote(1, APPLICANT, true));
+		assert_ok!(vote(2, APPLICANT, true));
+		assert_eq!(council(), vec![1, 2, 3, 6]);
+
+		assert_ok!(InstitutionsModule::suspend(RuntimeOrigin::root(), 1));
+		assert_eq!(council(), vec![1, 3, 6]);
+
+		// Replacing the first admin of an active institution replaces its representative...
+		assert_ok!(InstitutionsModule::set_admins(RuntimeOrigin::signed(1), 0, vec![7, 1]));
+		assert_eq!(council(), vec![3, 6, 7]);
+		// ...while suspended institutions stay unrepresented.
+		assert_ok!(InstitutionsModule::set_admins(RuntimeOrigin::signed(2), 1, vec![8]));
+		assert_eq!(council(), vec![3, 6, 7]);
+
+		assert_ok!(InstitutionsModule::reinstate(RuntimeOrigin::root(), 1));
+		assert_eq!(council(), vec![3, 6, 7, 8]);
+		assert_eq!(Representatives::<Test>::get().into_inner(), council());
+	});
+}
@@ -0,0 +1,138 @@
+
+//! Weights for pallet_institutions
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_institutions` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_institutions.
+pub trait WeightInfo {
+	fn apply() -> Weight;
+	fn vote() -> Weight;
+	fn close() -> Weight;
+	fn set_admins() -> Weight;
+	fn suspend() -> Weight;
+	fn reinstate() -> Weight;
+}
+
+/// Weights for pallet_institutions using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Institutions AdminOf (r:16 w:0)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Institutions NextInstitutionId (r:1 w:1)
+	/// Storage: Institutions Applications (r:0 w:1)
+	/// Storage: Institutions Institutions (r:0 w:1)
+	fn apply() -> Weight {
+		Weight::from_parts(143_000_000, 42040)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Institutions AdminOf (r:17 w:16)
+	/// Storage: Institutions Institutions (r:2 w:1)
+	/// Storage: Institutions Applications (r:1 w:1)
+	/// Storage: Institutions ActiveCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Institutions Representatives (r:1 w:1)
+	fn vote() -> Weight {
+		Weight::from_parts(99_000_000, 27775)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+	}
+	/// Storage: Institutions Applications (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Institutions Institutions (r:1 w:1)
+	fn close() -> Weight {
+		Weight::from_parts(72_000_000, 4748)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Institutions AdminOf (r:17 w:32)
+	/// Storage: Institutions Institutions (r:1 w:1)
+	/// Storage: Institutions Representatives (r:1 w:1)
+	fn set_admins() -> Weight {
+		Weight::from_parts(119_000_000, 42040)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(34_u64))
+	}
+	/// Storage: Institutions Institutions (r:1 w:1)
+	/// Storage: Institutions ActiveCount (r:1 w:1)
+	/// Storage: Institutions Representatives (r:1 w:1)
+	fn suspend() -> Weight {
+		Weight::from_parts(20_000_000, 9679)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Institutions Institutions (r:1 w:1)
+	/// Storage: Institutions ActiveCount (r:1 w:1)
+	/// Storage: Institutions Representatives (r:1 w:1)
+	fn reinstate() -> Weight {
+		Weight::from_parts(20_000_000, 9679)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Institutions AdminOf (r:16 w:0)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Institutions NextInstitutionId (r:1 w:1)
+	/// Storage: Institutions Applications (r:0 w:1)
+	/// Storage: Institutions Institutions (r:0 w:1)
+	fn apply() -> Weight {
+		Weight::from_parts(143_000_000, 42040)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Institutions AdminOf (r:17 w:16)
+	/// Storage: Institutions Institutions (r:2 w:1)
+	/// Storage: Institutions Applications (r:1 w:1)
+	/// Storage: Institutions ActiveCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Institutions Representatives (r:1 w:1)
+	fn vote() -> Weight {
+		Weight::from_parts(99_000_000, 27775)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+	}
+	/// Storage: Institutions Applications (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Institutions Institutions (r:1 w:1)
+	fn close() -> Weight {
+		Weight::from_parts(72_000_000, 4748)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Institutions AdminOf (r:17 w:32)
+	/// Storage: Institutions Institutions (r:1 w:1)
+	/// Storage: Institutions Representatives (r:1 w:1)
+	fn set_admins() -> Weight {
+		Weight::from_parts(119_000_000, 42040)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(34_u64))
+	}
+	/// Storage: Institutions Institutions (r:1 w:1)
+	/// Storage: Institutions ActiveCount (r:1 w:1)
+	/// Storage: Institutions Representatives (r:1 w:1)
+	fn suspend() -> Weight {
+		Weight::from_parts(20_000_000, 9679)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Institutions Institutions (r:1 w:1)
+	/// Storage: Institutions ActiveCount (r:1 w:1)
+	/// Storage: Institutions Representatives (r:1 w:1)
+	fn reinstate() -> Weight {
+		Weight::from_parts(20_000_000, 9679)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
@@ -0,0 +1,17 @@
+//! Runtime API of the integrity pallet, through which verifiers learn whether a document they are
+//! checking has been retracted, corrected or flagged.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_integrity::IntegrityStatus;
+
+sp_api::decl_runtime_apis! {
+	/// The standing of the documents in the registry.
+	pub trait IntegrityApi<Hash> where Hash: Codec {
+		/// The integrity status of the document registered under `content_hash`, or `None` if it
+		/// is not a final entry of the registry.
+		fn integrity_status(content_hash: Hash) -> Option<IntegrityStatus<Hash>>;
+	}
+}
@@ -0,0 +1,119 @@
+//! Benchmarking setup for pallet-integrity
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as IntegrityPallet;
+use frame_benchmarking::v2::*;
+use frame_support::{sp_runtime::traits::Hash, traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+/// `T::MaxEvidence` distinct evidence hashes.
+fn max_evidence<T: Config>() -> Vec<T::Hash> {
+	(0..T::MaxEvidence::get()).map(|i| T::Hashing::hash_of(&i)).collect()
+}
+
+/// Designate the whitelisted caller as an editor and fill the notices of `document` but for
+/// `free` of them, with expressions of concern carrying the most evidence, returning the caller.
+fn editor_with_notices<T: Config>(document: T::Hash, free: u32) -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	Editors::<T>::insert(&caller, ());
+	let notice = Notice::<T> {
+		kind: NoticeKind::ExpressionOfConcern,
+		reason: ReasonCode::Other,
+		evidence: BoundedVec::truncate_from(max_evidence::<T>()),
+		issuer: caller.clone(),
+		issued_at: frame_system::Pallet::<T>::block_number(),
+	};
+	let notices = (free..T::MaxNotices::get()).map(|_| notice.clone()).collect();
+	Notices::<T>::insert(document, BoundedVec::truncate_from(notices));
+	caller
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn add_editor() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let editor: T::AccountId = account("editor", 0, 0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, editor.clone());
+
+		assert!(Editors::<T>::contains_key(editor));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_editor() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let editor: T::AccountId = account("editor", 0, 0);
+		Editors::<T>::insert(&editor, ());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, editor.clone());
+
+		assert!(!Editors::<T>::contains_key(editor));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn retract() {
+		let (document, _) = T::BenchmarkHelper::registered_documents();
+		let caller = editor_with_notices::<T>(document, 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), document, ReasonCode::Fabrication, max_evidence::<T>());
+
+		assert_eq!(Pallet::<T>::integrity_status(&document), Some(IntegrityStatus::Retracted));
+	}
+
+	#[benchmark]
+	fn correct() {
+		let (document, replacement) = T::BenchmarkHelper::registered_documents();
+		let caller = editor_with_notices::<T>(document, 2);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			document,
+			replacement,
+			ReasonCode::HonestError,
+			max_evidence::<T>(),
+		);
+
+		assert_eq!(
+			Pallet::<T>::integrity_status(&document),
+			Some(IntegrityStatus::Corrected { replacement })
+		);
+	}
+
+	#[benchmark]
+	fn express_concern() {
+		let (document, _) = T::BenchmarkHelper::registered_documents();
+		let caller = editor_with_notices::<T>(document, 2);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), document, ReasonCode::Irreproducible, max_evidence::<T>());
+
+		assert_eq!(Notices::<T>::get(document).len() as u32, T::MaxNotices::get() - 1);
+	}
+
+	#[benchmark]
+	fn resolve_concern() {
+		let (document, _) = T::BenchmarkHelper::registered_documents();
+		let caller = editor_with_notices::<T>(document, 2);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), document, ReasonCode::Other, max_evidence::<T>());
+
+		assert_eq!(Pallet::<T>::integrity_status(&document), Some(IntegrityStatus::Clean));
+	}
+
+	impl_benchmark_test_suite!(IntegrityPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
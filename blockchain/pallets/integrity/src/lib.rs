@@ -0,0 +1,506 @@
+//! # Integrity Pallet
+//!
+//! Annotates registered documents with retractions, corrections and expressions of concern.
+//!
+//! ## Overview
+//!
+//! Records in the registry are never deleted, but what is later learnt about them must be visible
+//! to whoever checks them. This pallet keeps, for each content hash, the append-only list of
+//! [`Notice`]s issued against it. A notice is one of:
+//!
+//! - a retraction, after which the document must no longer be relied upon and no further notice can
+//!   be issued;
+//! - a correction, linking to the content hash of the corrected document;
+//! - an expression of concern, flagging a document while the matter is investigated;
+//! - or the resolution of the concerns expressed so far, once the investigation is closed.
+//!
+//! Each notice carries a [`ReasonCode`] and the hashes of the evidence supporting it, e.g. of an
+//! investigation report. Notices are issued by the editors designated by
+//! [`Config::ManagerOrigin`]; the registrant of a document (see [`Config::Documents`]) can only
+//! correct it, and at most [`Config::MaxRegistrantCorrections`] times, so that editors keep room
+//! for their own notices. The last of the [`Config::MaxNotices`] notices of a document is kept for
+//! a retraction, so that a document can always be retracted however many notices it has.
+//!
+//! The [`IntegrityStatus`] of a document follows from all its notices, by precedence: a retraction
+//! overrides everything else, an expression of concern that no editor has resolved overrides
+//! corrections, and the latest correction names the document that replaces it. The status is
+//! exposed to verifiers through the runtime API of the `pallet-integrity-runtime-api` crate.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`add_editor`](Pallet::add_editor) - Designate an account allowed to issue notices.
+//! - [`remove_editor`](Pallet::remove_editor) - Revoke the designation of an editor.
+//! - [`retract`](Pallet::retract) - Issue a retraction notice.
+//! - [`correct`](Pallet::correct) - Issue a correction notice, linked to the corrected document.
+//! - [`express_concern`](Pallet::express_concern) - Issue an expression of concern.
+//! - [`resolve_concern`](Pallet::resolve_concern) - Resolve the concerns expressed so far.
+//!
+//! Run `cargo doc --package pallet-integrity --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+pub use speem_primitives::DocumentRegistrant;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*, traits::Contains, CloneNoBound, EqNoBound, PartialEqNoBound,
+		RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	/// Why a notice was issued.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum ReasonCode {
+		/// An error made in good faith, e.g. in the data or its analysis.
+		HonestError,
+		/// The results cannot be reproduced.
+		Irreproducible,
+		/// Data or results were fabricated or falsified.
+		Fabrication,
+		/// Someone else's work was used without credit.
+		Plagiarism,
+		/// The work was already published elsewhere.
+		DuplicatePublication,
+		/// The byline is disputed.
+		AuthorshipDispute,
+		/// The research breached ethical standards, e.g. on consent.
+		EthicsViolation,
+		/// The peer review of the work was manipulated.
+		ReviewManipulation,
+		/// A reason the other codes do not cover, detailed in the evidence.
+		Other,
+	}
+
+	/// What a notice states about a document.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum NoticeKind<Hash> {
+		/// The document is retracted.
+		Retraction,
+		/// The document is superseded by a corrected one.
+		Correction {
+			/// The content hash of the corrected document.
+			replacement: Hash,
+		},
+		/// The document is under investigation.
+		ExpressionOfConcern,
+		/// The investigation is closed and the concerns expressed so far are resolved.
+		ConcernResolved,
+	}
+
+	/// The standing of a registered document, as its notices leave it.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum IntegrityStatus<Hash> {
+		/// No notice has been issued against the document.
+		Clean,
+		/// An expression of concern has not been resolved yet.
+		UnderConcern,
+		/// The document has been corrected, and no concern about it is outstanding.
+		Corrected {
+			/// The content hash of the latest corrected document.
+			replacement: Hash,
+		},
+		/// The document has been retracted.
+		Retracted,
+	}
+
+	/// A notice issued against a registered document.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Notice<T: Config> {
+		/// What the notice states.
+		pub kind: NoticeKind<T::Hash>,
+		/// Why it was issued.
+		pub reason: ReasonCode,
+		/// The hashes of the evidence supporting it.
+		pub evidence: BoundedVec<T::Hash, T::MaxEvidence>,
+		/// The registrant or editor who issued it.
+		pub issuer: T::AccountId,
+		/// The block in which it was issued.
+		pub issued_at: BlockNumberFor<T>,
+	}
+
+	/// Provides benchmarks with state that this pallet does not control.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Hash> {
+		/// Make sure two distinct documents are final in the registry and return their content
+		/// hashes.
+		fn registered_documents() -> (Hash, Hash);
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin allowed to designate and revoke editors.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The registry of documents notices are issued against, and of their registrants.
+		type Documents: Contains<Self::Hash> + DocumentRegistrant<Self::AccountId, Self::Hash>;
+		/// The maximum number of evidence hashes a notice carries.
+		#[pallet::constant]
+		type MaxEvidence: Get<u32>;
+		/// The maximum number of notices issued against a document, the last of which is kept for a
+		/// retraction.
+		#[pallet::constant]
+		type MaxNotices: Get<u32>;
+		/// The maximum number of corrections the registrant of a document issues against it, which
+		/// must leave editors at least one of the slots not kept for a retraction.
+		#[pallet::constant]
+		type MaxRegistrantCorrections: Get<u32>;
+		/// Helper for benchmarks to obtain documents the registry contains.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::Hash>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The accounts allowed to issue notices against any document.
+	#[pallet::storage]
+	pub type Editors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// The notices issued against each document, oldest first, keyed by content hash.
+	#[pallet::storage]
+	pub type Notices<T: Config> =
+		StorageMap<_, Identity, T::Hash, BoundedVec<Notice<T>, T::MaxNotices>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account has been designated as an editor.
+		EditorAdded {
+			/// The editor.
+			editor: T::AccountId,
+		},
+		/// The designation of an editor has been revoked.
+		EditorRemoved {
+			/// The former editor.
+			editor: T::AccountId,
+		},
+		/// A notice has been issued against a document.
+		NoticeIssued {
+			/// The content hash of the document.
+			content_hash: T::Hash,
+			/// What the notice states.
+			kind: NoticeKind<T::Hash>,
+			/// Why it was issued.
+			reason: ReasonCode,
+			/// The registrant or editor who issued it.
+			issuer: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already an editor.
+		AlreadyEditor,
+		/// The account is not an editor.
+		NotEditor,
+		/// The document is not a final entry of the registry.
+		UnregisteredDocument,
+		/// The signer is not an editor, nor the registrant of the document correcting it.
+		NotAuthorised,
+		/// The notice carries no evidence.
+		NoEvidence,
+		/// The notice carries more evidence than [`Config::MaxEvidence`] allows.
+		TooMuchEvidence,
+		/// The document has no room for another notice, the last of [`Config::MaxNotices`] being
+		/// kept for a retraction.
+		TooManyNotices,
+		/// The document has been retracted, after which no notice can be issued.
+		AlreadyRetracted,
+		/// The corrected document is not a final entry of the registry.
+		UnregisteredReplacement,
+		/// A document cannot be corrected by itself.
+		SelfReplacement,
+		/// No expression of concern about the document is outstanding.
+		NoConcern,
+		/// The registrant has already issued [`Config::MaxRegistrantCorrections`] corrections
+		/// against the document.
+		TooManyCorrections,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(
+				T::MaxRegistrantCorrections::get() < T::MaxNotices::get().saturating_sub(1),
+				"registrants must leave editors room for their notices"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Designate `editor` as allowed to issue notices against any document.
+		///
+		/// The origin must be [`Config::ManagerOrigin`].
+		///
+		/// ## Errors
+		///
+		/// - [`Error::AlreadyEditor`] if `editor` is already an editor.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::add_editor())]
+		pub fn add_editor(origin: OriginFor<T>, editor: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(!Editors::<T>::contains_key(&editor), Error::<T>::AlreadyEditor);
+			Editors::<T>::insert(&editor, ());
+
+			Self::deposit_event(Event::EditorAdded { editor });
+			Ok(())
+		}
+
+		/// Revoke the designation of `editor`. The notices it issued remain.
+		///
+		/// The origin must be [`Config::ManagerOrigin`].
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotEditor`] if `editor` is not an editor.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_editor())]
+		pub fn remove_editor(origin: OriginFor<T>, editor: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(Editors::<T>::take(&editor).is_some(), Error::<T>::NotEditor);
+
+			Self::deposit_event(Event::EditorRemoved { editor });
+			Ok(())
+		}
+
+		/// Retract a registered document.
+		///
+		/// The origin must be signed by an editor. No notice can be issued against the document
+		/// afterwards. A retraction is always accepted, however many notices the document has.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NotAuthorised`] if the signer is not an editor.
+		/// - [`Error::NoEvidence`] or [`Error::TooMuchEvidence`] if `evidence` is empty or exceeds
+		///   [`Config::MaxEvidence`].
+		/// - [`Error::AlreadyRetracted`] if the document has been retracted.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::retract())]
+		pub fn retract(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			reason: ReasonCode,
+			evidence: Vec<T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::issue(who, content_hash, NoticeKind::Retraction, reason, evidence)
+		}
+
+		/// Link a registered document to the registered document that corrects it.
+		///
+		/// The origin must be signed by the registrant of the document or by an editor. The
+		/// registrant issues at most [`Config::MaxRegistrantCorrections`] corrections.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredReplacement`] if `replacement` is not a final registry entry.
+		/// - [`Error::SelfReplacement`] if `replacement` is `content_hash`.
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NotAuthorised`] if the signer is neither its registrant nor an editor.
+		/// - [`Error::NoEvidence`] or [`Error::TooMuchEvidence`] if `evidence` is empty or exceeds
+		///   [`Config::MaxEvidence`].
+		/// - [`Error::AlreadyRetracted`] if the document has been retracted.
+		/// - [`Error::TooManyNotices`] if the document has no room for another notice.
+		/// - [`Error::TooManyCorrections`] if the registrant has corrected the document as many
+		///   times as it may.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::correct())]
+		pub fn correct(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			replacement: T::Hash,
+			reason: ReasonCode,
+			evidence: Vec<T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(replacement != content_hash, Error::<T>::SelfReplacement);
+			ensure!(T::Documents::contains(&replacement), Error::<T>::UnregisteredReplacement);
+
+			Self::issue(who, content_hash, NoticeKind::Correction { replacement }, reason, evidence)
+		}
+
+		/// Flag a registered document while concerns about it are investigated.
+		///
+		/// The origin must be signed by an editor. The document stays under concern until an
+		/// editor [`resolve_concern`](Pallet::resolve_concern)s.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NotAuthorised`] if the signer is not an editor.
+		/// - [`Error::NoEvidence`] or [`Error::TooMuchEvidence`] if `evidence` is empty or exceeds
+		///   [`Config::MaxEvidence`].
+		/// - [`Error::AlreadyRetracted`] if the document has been retracted.
+		/// - [`Error::TooManyNotices`] if the document has no room for another notice.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::express_concern())]
+		pub fn express_concern(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			reason: ReasonCode,
+			evidence: Vec<T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::issue(who, content_hash, NoticeKind::ExpressionOfConcern, reason, evidence)
+		}
+
+		/// Resolve the concerns expressed about a registered document so far, e.g. once their
+		/// investigation has cleared it.
+		///
+		/// The origin must be signed by an editor.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NotAuthorised`] if the signer is not an editor.
+		/// - [`Error::NoEvidence`] or [`Error::TooMuchEvidence`] if `evidence` is empty or exceeds
+		///   [`Config::MaxEvidence`].
+		/// - [`Error::AlreadyRetracted`] if the document has been retracted.
+		/// - [`Error::NoConcern`] if no expression of concern about it is outstanding.
+		/// - [`Error::TooManyNotices`] if the document has no room for another notice.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::resolve_concern())]
+		pub fn resolve_concern(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			reason: ReasonCode,
+			evidence: Vec<T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::issue(who, content_hash, NoticeKind::ConcernResolved, reason, evidence)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The standing of the document registered under `content_hash`, or `None` if it is not a
+		/// final entry of the registry.
+		pub fn integrity_status(content_hash: &T::Hash) -> Option<IntegrityStatus<T::Hash>> {
+			if !T::Documents::contains(content_hash) {
+				return None
+			}
+			let notices = Notices::<T>::get(content_hash);
+			if notices.iter().any(|notice| notice.kind == NoticeKind::Retraction) {
+				return Some(IntegrityStatus::Retracted)
+			}
+			if Self::concern_outstanding(&notices) {
+				return Some(IntegrityStatus::UnderConcern)
+			}
+			let replacement = notices.iter().rev().find_map(|notice| match notice.kind {
+				NoticeKind::Correction { replacement } => Some(replacement),
+				_ => None,
+			});
+			Some(replacement.map_or(IntegrityStatus::Clean, |replacement| {
+				IntegrityStatus::Corrected { replacement }
+			}))
+		}
+
+		/// Whether an expression of concern among `notices` has not been resolved since.
+		fn concern_outstanding(notices: &[Notice<T>]) -> bool {
+			notices
+				.iter()
+				.rev()
+				.find_map(|notice| match notice.kind {
+					NoticeKind::ExpressionOfConcern => Some(true),
+					NoticeKind::ConcernResolved => Some(false),
+					_ => None,
+				})
+				.unwrap_or(false)
+		}
+
+		/// Append a notice of `kind` to those issued against `content_hash` on behalf of `issuer`,
+		/// provided `issuer` is allowed to.
+		fn issue(
+			issuer: T::AccountId,
+			content_hash: T::Hash,
+			kind: NoticeKind<T::Hash>,
+			reason: ReasonCode,
+			evidence: Vec<T::Hash>,
+		) -> DispatchResult {
+			ensure!(T::Documents::contains(&content_hash), Error::<T>::UnregisteredDocument);
+			let editor = Editors::<T>::contains_key(&issuer);
+			ensure!(
+				editor ||
+					(matches!(kind, NoticeKind::Correction { .. }) &&
+						T::Documents::registrant_of(&content_hash).as_ref() == Some(&issuer)),
+				Error::<T>::NotAuthorised
+			);
+			ensure!(!evidence.is_empty(), Error::<T>::NoEvidence);
+			let evidence: BoundedVec<_, T::MaxEvidence> =
+				evidence.try_into().map_err(|_| Error::<T>::TooMuchEvidence)?;
+
+			Notices::<T>::try_mutate(content_hash, |notices| {
+				ensure!(
+					!notices.iter().any(|notice| notice.kind == NoticeKind::Retraction),
+					Error::<T>::AlreadyRetracted
+				);
+				ensure!(
+					kind != NoticeKind::ConcernResolved || Self::concern_outstanding(notices),
+					Error::<T>::NoConcern
+				);
+				// The last slot is kept for a retraction, which must always be possible.
+				ensure!(
+					kind == NoticeKind::Retraction ||
+						notices.len() < (T::MaxNotices::get() as usize).saturating_sub(1),
+					Error::<T>::TooManyNotices
+				);
+				// Registrants cannot take up the slots editors need for their notices.
+				let corrections = notices
+					.iter()
+					.filter(|notice| {
+						notice.issuer == issuer &&
+							matches!(notice.kind, NoticeKind::Correction { .. })
+					})
+					.count();
+				ensure!(
+					editor || corrections < T::MaxRegistrantCorrections::get() as usize,
+					Error::<T>::TooManyCorrections
+				);
+				let issued_at = frame_system::Pallet::<T>::block_number();
+				notices
+					.try_push(Notice { kind, reason, evidence, issuer: issuer.clone(), issued_at })
+					.map_err(|_| Error::<T>::TooManyNotices)
+			})?;
+
+			Self::deposit_event(Event::NoticeIssued { content_hash, kind, reason, issuer });
+			Ok(())
+		}
+	}
+}
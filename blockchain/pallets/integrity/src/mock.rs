@@ -0,0 +1,111 @@
+use crate as pallet_integrity;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU16, ConstU32, ConstU64, Contains},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use speem_primitives::DocumentRegistrant;
+use std::cell::RefCell;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Integrity: pallet_integrity,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+thread_local! {
+	static REGISTERED: RefCell<Vec<(H256, u64)>> = RefCell::new(Vec::new());
+}
+
+/// A stand-in for the manuscript registry.
+pub struct MockDocuments;
+
+impl MockDocuments {
+	/// Mark `document` as a final registry entry registered by `registrant`.
+	pub fn register(document: H256, registrant: u64) {
+		REGISTERED.with(|registered| registered.borrow_mut().push((document, registrant)));
+	}
+}
+
+impl Contains<H256> for MockDocuments {
+	fn contains(document: &H256) -> bool {
+		Self::registrant_of(document).is_some()
+	}
+}
+
+impl DocumentRegistrant<u64, H256> for MockDocuments {
+	fn registrant_of(document: &H256) -> Option<u64> {
+		REGISTERED.with(|registered| {
+			registered
+				.borrow()
+				.iter()
+				.find(|(hash, _)| hash == document)
+				.map(|(_, who)| *who)
+		})
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<H256> for MockDocuments {
+	fn registered_documents() -> (H256, H256) {
+		let documents = (H256::repeat_byte(2), H256::repeat_byte(3));
+		Self::register(documents.0, 1_000);
+		Self::register(documents.1, 1_000);
+		documents
+	}
+}
+
+impl pallet_integrity::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRoot<u64>;
+	type Documents = MockDocuments;
+	type MaxEvidence = ConstU32<2>;
+	type MaxNotices = ConstU32<4>;
+	type MaxRegistrantCorrections = ConstU32<1>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockDocuments;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	REGISTERED.with(|registered| registered.borrow_mut().clear());
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+}
@@ -0,0 +1,262 @@
+use crate::{mock::*, Editors, Error, Event, IntegrityStatus, NoticeKind, Notices, ReasonCode};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+const DOCUMENT: H256 = H256::repeat_byte(2);
+const REPLACEMENT: H256 = H256::repeat_byte(3);
+const REPORT: H256 = H256::repeat_byte(9);
+const REGISTRANT: u64 = 1;
+const EDITOR: u64 = 5;
+
+fn retract(who: u64) -> DispatchResult {
+	Integrity::retract(RuntimeOrigin::signed(who), DOCUMENT, ReasonCode::Fabrication, vec![REPORT])
+}
+
+fn correct(who: u64) -> DispatchResult {
+	Integrity::correct(
+		RuntimeOrigin::signed(who),
+		DOCUMENT,
+		REPLACEMENT,
+		ReasonCode::HonestError,
+		vec![REPORT],
+	)
+}
+
+fn express_concern(who: u64) -> DispatchResult {
+	Integrity::express_concern(
+		RuntimeOrigin::signed(who),
+		DOCUMENT,
+		ReasonCode::Irreproducible,
+		vec![REPORT],
+	)
+}
+
+fn resolve_concern(who: u64) -> DispatchResult {
+	Integrity::resolve_concern(
+		RuntimeOrigin::signed(who),
+		DOCUMENT,
+		ReasonCode::Other,
+		vec![REPORT],
+	)
+}
+
+fn status() -> Option<IntegrityStatus<H256>> {
+	Integrity::integrity_status(&DOCUMENT)
+}
+
+#[test]
+fn manager_designates_editors() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		assert_noop!(
+			Integrity::add_editor(RuntimeOrigin::signed(REGISTRANT), EDITOR),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+		System::assert_last_event(Event::EditorAdded { editor: EDITOR }.into());
+		assert!(Editors::<Test>::contains_key(EDITOR));
+		assert_noop!(
+			Integrity::add_editor(RuntimeOrigin::root(), EDITOR),
+			Error::<Test>::AlreadyEditor
+		);
+
+		assert_ok!(Integrity::remove_editor(RuntimeOrigin::root(), EDITOR));
+		System::assert_last_event(Event::EditorRemoved { editor: EDITOR }.into());
+		assert_noop!(
+			Integrity::remove_editor(RuntimeOrigin::root(), EDITOR),
+			Error::<Test>::NotEditor
+		);
+	});
+}
+
+#[test]
+fn editors_issue_notices() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(express_concern(EDITOR), Error::<Test>::UnregisteredDocument);
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+
+		assert_noop!(express_concern(EDITOR), Error::<Test>::NotAuthorised);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+		assert_ok!(express_concern(EDITOR));
+		System::assert_last_event(
+			Event::NoticeIssued {
+				content_hash: DOCUMENT,
+				kind: NoticeKind::ExpressionOfConcern,
+				reason: ReasonCode::Irreproducible,
+				issuer: EDITOR,
+			}
+			.into(),
+		);
+
+		System::set_block_number(2);
+		assert_ok!(resolve_concern(EDITOR));
+
+		let notices = Notices::<Test>::get(DOCUMENT);
+		assert_eq!(notices.len(), 2);
+		assert_eq!(notices[1].kind, NoticeKind::ConcernResolved);
+		assert_eq!(notices[1].issuer, EDITOR);
+		assert_eq!(notices[1].issued_at, 2);
+		assert_eq!(notices[1].evidence.clone().into_inner(), vec![REPORT]);
+	});
+}
+
+#[test]
+fn registrants_only_correct() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		MockDocuments::register(REPLACEMENT, REGISTRANT);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+		assert_ok!(express_concern(EDITOR));
+
+		assert_noop!(express_concern(REGISTRANT), Error::<Test>::NotAuthorised);
+		assert_noop!(resolve_concern(REGISTRANT), Error::<Test>::NotAuthorised);
+		assert_noop!(retract(REGISTRANT), Error::<Test>::NotAuthorised);
+		assert_ok!(correct(REGISTRANT));
+		assert_eq!(Notices::<Test>::get(DOCUMENT)[1].issuer, REGISTRANT);
+	});
+}
+
+#[test]
+fn notices_need_evidence() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+		let concern_with = |evidence: Vec<H256>| {
+			Integrity::express_concern(
+				RuntimeOrigin::signed(EDITOR),
+				DOCUMENT,
+				ReasonCode::Other,
+				evidence,
+			)
+		};
+
+		assert_noop!(concern_with(vec![]), Error::<Test>::NoEvidence);
+		assert_noop!(concern_with(vec![REPORT; 3]), Error::<Test>::TooMuchEvidence);
+		assert_ok!(concern_with(vec![REPORT; 2]));
+	});
+}
+
+#[test]
+fn corrections_link_to_registered_replacements() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+
+		assert_noop!(correct(REGISTRANT), Error::<Test>::UnregisteredReplacement);
+		assert_noop!(
+			Integrity::correct(
+				RuntimeOrigin::signed(REGISTRANT),
+				DOCUMENT,
+				DOCUMENT,
+				ReasonCode::HonestError,
+				vec![REPORT],
+			),
+			Error::<Test>::SelfReplacement
+		);
+
+		MockDocuments::register(REPLACEMENT, REGISTRANT);
+		assert_ok!(correct(REGISTRANT));
+		assert_eq!(
+			Notices::<Test>::get(DOCUMENT)[0].kind,
+			NoticeKind::Correction { replacement: REPLACEMENT }
+		);
+	});
+}
+
+#[test]
+fn nothing_follows_a_retraction() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		MockDocuments::register(REPLACEMENT, REGISTRANT);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+		assert_ok!(retract(EDITOR));
+
+		assert_noop!(retract(EDITOR), Error::<Test>::AlreadyRetracted);
+		assert_noop!(correct(REGISTRANT), Error::<Test>::AlreadyRetracted);
+		assert_noop!(express_concern(EDITOR), Error::<Test>::AlreadyRetracted);
+	});
+}
+
+#[test]
+fn notices_are_bounded_but_retractions_always_fit() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		MockDocuments::register(REPLACEMENT, REGISTRANT);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+		assert_ok!(express_concern(EDITOR));
+		assert_ok!(correct(REGISTRANT));
+		assert_ok!(express_concern(EDITOR));
+
+		// The last of the four notices is kept for a retraction.
+		assert_noop!(express_concern(EDITOR), Error::<Test>::TooManyNotices);
+		assert_noop!(correct(EDITOR), Error::<Test>::TooManyNotices);
+		assert_ok!(retract(EDITOR));
+		assert_eq!(Notices::<Test>::get(DOCUMENT).len(), 4);
+	});
+}
+
+#[test]
+fn registrants_leave_room_for_editors() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		MockDocuments::register(REPLACEMENT, REGISTRANT);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+		assert_ok!(correct(REGISTRANT));
+
+		// The registrant has used its one correction; the other slots remain for editors.
+		assert_noop!(correct(REGISTRANT), Error::<Test>::TooManyCorrections);
+		assert_ok!(express_concern(EDITOR));
+		assert_ok!(resolve_concern(EDITOR));
+		assert_eq!(status(), Some(IntegrityStatus::Corrected { replacement: REPLACEMENT }));
+		assert_noop!(express_concern(EDITOR), Error::<Test>::TooManyNotices);
+		assert_ok!(retract(EDITOR));
+	});
+}
+
+#[test]
+fn only_outstanding_concerns_are_resolved() {
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+
+		assert_noop!(resolve_concern(EDITOR), Error::<Test>::NoConcern);
+		assert_ok!(express_concern(EDITOR));
+		assert_ok!(resolve_concern(EDITOR));
+		assert_noop!(resolve_concern(EDITOR), Error::<Test>::NoConcern);
+	});
+}
+
+#[test]
+fn status_follows_notices_by_precedence() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(status(), None);
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		MockDocuments::register(REPLACEMENT, REGISTRANT);
+		assert_eq!(status(), Some(IntegrityStatus::Clean));
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+
+		// A later correction does not clear a concern; only an editor's resolution does.
+		assert_ok!(express_concern(EDITOR));
+		assert_eq!(status(), Some(IntegrityStatus::UnderConcern));
+		assert_ok!(correct(REGISTRANT));
+		assert_eq!(status(), Some(IntegrityStatus::UnderConcern));
+		assert_ok!(resolve_concern(EDITOR));
+		assert_eq!(status(), Some(IntegrityStatus::Corrected { replacement: REPLACEMENT }));
+	});
+	new_test_ext().execute_with(|| {
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+		MockDocuments::register(REPLACEMENT, REGISTRANT);
+		assert_ok!(Integrity::add_editor(RuntimeOrigin::root(), EDITOR));
+
+		// A retraction overrides a correction.
+		assert_ok!(correct(REGISTRANT));
+		assert_ok!(retract(EDITOR));
+		assert_eq!(status(), Some(IntegrityStatus::Retracted));
+
+		// The corrected document has a standing of its own.
+		assert_eq!(Integrity::integrity_status(&REPLACEMENT), Some(IntegrityStatus::Clean));
+	});
+}
@@ -0,0 +1,120 @@
+
+//! Weights for pallet_integrity
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_integrity` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_integrity.
+pub trait WeightInfo {
+	fn add_editor() -> Weight;
+	fn remove_editor() -> Weight;
+	fn retract() -> Weight;
+	fn correct() -> Weight;
+	fn express_concern() -> Weight;
+	fn resolve_concern() -> Weight;
+}
+
+/// Weights for pallet_integrity using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Integrity Editors (r:1 w:1)
+	fn add_editor() -> Weight {
+		Weight::from_parts(13_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Integrity Editors (r:1 w:1)
+	fn remove_editor() -> Weight {
+		Weight::from_parts(15_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Integrity Editors (r:1 w:0)
+	/// Storage: Integrity Notices (r:1 w:1)
+	fn retract() -> Weight {
+		Weight::from_parts(45_000_000, 8730)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:2 w:0)
+	/// Storage: Integrity Editors (r:1 w:0)
+	/// Storage: Integrity Notices (r:1 w:1)
+	fn correct() -> Weight {
+		Weight::from_parts(50_000_000, 11334)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Integrity Editors (r:1 w:0)
+	/// Storage: Integrity Notices (r:1 w:1)
+	fn express_concern() -> Weight {
+		Weight::from_parts(44_000_000, 8730)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Integrity Editors (r:1 w:0)
+	/// Storage: Integrity Notices (r:1 w:1)
+	fn resolve_concern() -> Weight {
+		Weight::from_parts(45_000_000, 8730)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Integrity Editors (r:1 w:1)
+	fn add_editor() -> Weight {
+		Weight::from_parts(13_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Integrity Editors (r:1 w:1)
+	fn remove_editor() -> Weight {
+		Weight::from_parts(15_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Integrity Editors (r:1 w:0)
+	/// Storage: Integrity Notices (r:1 w:1)
+	fn retract() -> Weight {
+		Weight::from_parts(45_000_000, 8730)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:2 w:0)
+	/// Storage: Integrity Editors (r:1 w:0)
+	/// Storage: Integrity Notices (r:1 w:1)
+	fn correct() -> Weight {
+		Weight::from_parts(50_000_000, 11334)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Integrity Editors (r:1 w:0)
+	/// Storage: Integrity Notices (r:1 w:1)
+	fn express_concern() -> Weight {
+		Weight::from_parts(44_000_000, 8730)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Integrity Editors (r:1 w:0)
+	/// Storage: Integrity Notices (r:1 w:1)
+	fn resolve_concern() -> Weight {
+		Weight::from_parts(45_000_000, 8730)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
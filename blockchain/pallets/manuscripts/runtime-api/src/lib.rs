@@ -0,0 +1,45 @@
+//! Runtime API of the manuscripts pallet, through which the node's RPC and external tools query
+//! the registry without decoding raw storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_manuscripts::{
+	DocumentId, HashAlgorithm, Layout, ManuscriptStatus, MerkleCommitment, Multihash,
+};
+
+sp_api::decl_runtime_apis! {
+	/// Queries on the manuscript registry.
+	///
+	/// `Record` is the runtime's `ManuscriptRecord`.
+	pub trait ManuscriptsApi<AccountId, Hash, BlockNumber, Record>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+		Record: Codec,
+	{
+		/// The record of the manuscript registered under `content_hash`, pending or final, or
+		/// `None` if nothing is registered under it.
+		fn verify_hash(content_hash: Hash) -> Option<Record>;
+
+		/// The identifier the manuscript registered under `content_hash` was submitted under,
+		/// naming the algorithm of its content hash, or `None` if nothing is registered under it.
+		fn content_id(content_hash: Hash) -> Option<Multihash<Hash>>;
+
+		/// The content hashes of every version of `document`, oldest first.
+		fn history(document: DocumentId) -> Vec<Hash>;
+
+		/// The `page`th page of the content hashes of the manuscripts listing `author`.
+		fn by_author(author: AccountId, page: u32) -> Vec<Hash>;
+
+		/// The status of the latest version of `document`, or `None` if it does not exist.
+		fn status(document: DocumentId) -> Option<ManuscriptStatus<BlockNumber>>;
+
+		/// The Merkle commitment the manuscript registered under `content_hash` was submitted
+		/// with, or `None` if it was submitted without one or nothing is registered under it.
+		fn commitment(content_hash: Hash) -> Option<MerkleCommitment<Hash>>;
+	}
+}
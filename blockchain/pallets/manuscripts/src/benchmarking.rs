@@ -0,0 +1,214 @@
+//! Benchmarking setup for pallet-manuscripts
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ManuscriptsPallet;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	sp_runtime::{traits::Hash, Saturating},
+	traits::{
+		fungible::{Inspect, Mutate},
+		Get,
+	},
+};
+use frame_system::RawOrigin;
+use sp_std::{prelude::*, vec};
+
+/// The whitelisted caller, funded well beyond any registration deposit.
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	fund::<T>(&caller);
+	caller
+}
+
+fn fund<T: Config>(who: &T::AccountId) {
+	let amount = T::Currency::minimum_balance()
+		.saturating_add(T::DepositBase::get().saturating_mul(10u32.into()))
+		.saturating_add(T::DepositPerByte::get().saturating_mul(100_000u32.into()));
+	T::Currency::set_balance(who, amount);
+}
+
+/// Metadata at the configured maximum size, listing `lead` as its first author.
+fn max_metadata<T: Config>(lead: &T::AccountId) -> (Vec<u8>, Vec<T::AccountId>, Vec<u8>) {
+	let title = vec![b't'; T::MaxTitleLength::get() as usize];
+	let authors = sp_std::iter::once(lead.clone())
+		.chain((1..T::MaxAuthors::get()).map(|i| account::<T::AccountId>("author", i, 0)))
+		.collect::<Vec<_>>();
+	let license = vec![b'l'; T::MaxLicenseLength::get() as usize];
+	(title, authors, license)
+}
+
+/// The identifier of `content_hash` as a SHA-256 digest, whose algorithm is stored with the
+/// record.
+fn sha2<T: Config>(content_hash: T::Hash) -> ContentId<T> {
+	Multihash::new(HashAlgorithm::Sha2_256, content_hash)
+}
+
+/// A Merkle commitment to the sections of a document, which is stored with the record.
+fn commitment<T: Config>() -> Option<MerkleCommitment<T::Hash>> {
+	Some(MerkleCommitment {
+		layout: Layout::Sections,
+		root: T::Hashing::hash(b"sections"),
+		leaves: u32::MAX,
+	})
+}
+
+/// Register `content_hash` with maximum-size metadata and a commitment led by `lead`, leaving it
+/// pending.
+fn register_pending<T: Config>(lead: &T::AccountId, content_hash: T::Hash) -> Vec<T::AccountId> {
+	let (title, authors, license) = max_metadata::<T>(lead);
+	Pallet::<T>::register(
+		RawOrigin::Signed(lead.clone()).into(),
+		sha2::<T>(content_hash),
+		title,
+		T::Hashing::hash(b"abstract"),
+		authors.clone(),
+		license,
+		commitment::<T>(),
+	)
+	.expect("registration succeeds");
+	authors
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register() {
+		let caller = funded_caller::<T>();
+		let content_hash = T::Hashing::hash(b"manuscript");
+		let (title, authors, license) = max_metadata::<T>(&caller);
+
+		#[extrinsic_call]
+		register(
+			RawOrigin::Signed(caller.clone()),
+			sha2::<T>(content_hash),
+			title,
+			T::Hashing::hash(b"abstract"),
+			authors,
+			license,
+			commitment::<T>(),
+		);
+
+		assert_eq!(
+			Manuscripts::<T>::get(content_hash).map(|record| record.registrant),
+			Some(caller)
+		);
+	}
+
+	#[benchmark]
+	fn submit_revision() {
+		let caller = funded_caller::<T>();
+		let original = T::Hashing::hash(b"manuscript");
+		let revision = T::Hashing::hash(b"revision");
+		let (title, authors, license) = max_metadata::<T>(&caller);
+		for author in register_pending::<T>(&caller, original).into_iter().skip(1) {
+			Pallet::<T>::approve_authorship(RawOrigin::Signed(author).into(), original)
+				.expect("listed authors can approve");
+		}
+		let document = Manuscripts::<T>::get(original).expect("manuscript was registered").document;
+
+		#[extrinsic_call]
+		submit_revision(
+			RawOrigin::Signed(caller),
+			document,
+			original,
+			sha2::<T>(revision),
+			title,
+			T::Hashing::hash(b"abstract"),
+			authors,
+			license,
+			commitment::<T>(),
+		);
+
+		assert_eq!(Documents::<T>::get(document).map(|info| info.head), Some(revision));
+	}
+
+	#[benchmark]
+	fn approve_authorship() {
+		let caller = funded_caller::<T>();
+		let content_hash = T::Hashing::hash(b"manuscript");
+		let mut authors = register_pending::<T>(&caller, content_hash);
+		// Leave the last approval, which finalizes the submission, to the measured call.
+		let last = authors.pop().expect("at least one author");
+		for author in authors.into_iter().skip(1) {
+			Pallet::<T>::approve_authorship(RawOrigin::Signed(author).into(), content_hash)
+				.expect("listed authors can approve");
+		}
+
+		#[extrinsic_call]
+		approve_authorship(RawOrigin::Signed(last), content_hash);
+
+		assert_eq!(
+			Manuscripts::<T>::get(content_hash).map(|record| record.status),
+			Some(ManuscriptStatus::Final)
+		);
+	}
+
+	#[benchmark]
+	fn remove_expired() {
+		let caller = funded_caller::<T>();
+		let content_hash = T::Hashing::hash(b"manuscript");
+		register_pending::<T>(&caller, content_hash);
+		let expired =
+			frame_system::Pallet::<T>::block_number() + T::ApprovalPeriod::get() + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(expired);
+
+		#[extrinsic_call]
+		remove_expired(RawOrigin::Signed(caller), content_hash);
+
+		assert!(!Manuscripts::<T>::contains_key(content_hash));
+	}
+
+	#[benchmark]
+	fn withdraw() {
+		let caller = funded_caller::<T>();
+		let content_hash = T::Hashing::hash(b"manuscript");
+		register_pending::<T>(&caller, content_hash);
+
+		#[extrinsic_call]
+		withdraw(RawOrigin::Signed(caller), content_hash);
+
+		assert!(!Manuscripts::<T>::contains_key(content_hash));
+	}
+
+	#[benchmark]
+	fn propose_transfer() {
+		let caller = funded_caller::<T>();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let content_hash = T::Hashing::hash(b"manuscript");
+		register_pending::<T>(&caller, content_hash);
+
+		#[extrinsic_call]
+		propose_transfer(RawOrigin::Signed(caller), content_hash, recipient.clone());
+
+		assert_eq!(PendingTransfers::<T>::get(content_hash), Some(recipient));
+	}
+
+	#[benchmark]
+	fn accept_transfer() {
+		let caller = funded_caller::<T>();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		fund::<T>(&recipient);
+		let content_hash = T::Hashing::hash(b"manuscript");
+		register_pending::<T>(&caller, content_hash);
+		Pallet::<T>::propose_transfer(
+			RawOrigin::Signed(caller).into(),
+			content_hash,
+			recipient.clone(),
+		)
+		.expect("the registrant can offer the registration");
+
+		#[extrinsic_call]
+		accept_transfer(RawOrigin::Signed(recipient.clone()), content_hash);
+
+		assert_eq!(
+			Manuscripts::<T>::get(content_hash).map(|record| record.registrant),
+			Some(recipient)
+		);
+	}
+
+	impl_benchmark_test_suite!(ManuscriptsPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,1005 @@
+//! # Manuscripts Pallet
+//!
+//! The registry at the heart of SPEEM: academic manuscripts are recorded on chain by the hash of
+//! their content, together with a small amount of bounded metadata describing them.
+//!
+//! ## Overview
+//!
+//! A manuscript is identified by its content hash, computed off chain over the exact bytes of the
+//! submitted document with one of the supported [`HashAlgorithm`]s. Registering a manuscript
+//! stores:
+//! - the title, the hash of its abstract, the list of authors and the license it is published under
+//!   (see [`ManuscriptMetadata`])
+//! - the account that submitted it
+//! - the block number and timestamp at which it was registered
+//!
+//! Anyone holding a copy of the document can then recompute its hash and look it up in
+//! [`Manuscripts`] to verify that it was registered, when, and by whom.
+//!
+//! A content hash can only ever be registered once, and metadata is bounded by the limits in the
+//! pallet's [`Config`] so that the state each submission occupies is predictable.
+//!
+//! ### Revisions
+//!
+//! Every registration starts a new *document*, identified by a sequential [`DocumentId`]. Later
+//! versions of the same work are submitted as revisions of that document: each revision names the
+//! content hash of its parent, which must be the document's current head. The versions of a
+//! document therefore form an append-only, hash-linked chain that can be walked either forwards
+//! through [`Revisions`] or backwards through the `parent` of each [`ManuscriptRecord`]. Only the
+//! authors listed on the original registration may submit revisions.
+//!
+//! ### Hash algorithms
+//!
+//! Manuscripts are submitted under a [`ContentId`], a multihash naming the algorithm that produced
+//! the content hash alongside it, so that archives can register their documents under the SHA-256
+//! or SHA3-256 digests they already publish rather than rehashing them with BLAKE2b-256, the
+//! runtime's own hash. The registry stays keyed by the 32-byte digest alone; the algorithm is kept
+//! in [`HashAlgorithms`] so that verifiers know how to recompute it, and [`Pallet::content_id`]
+//! returns both.
+//!
+//! ### Merkle commitments
+//!
+//! A content hash only proves a document as a whole. A registration or revision may also carry a
+//! [`MerkleCommitment`] to the document's parts, the root of the Merkle tree built by
+//! [`speem_primitives::merkle`] over either its fixed-size chunks or sections of it delimited by
+//! the registrant, such as its figures and tables. Anyone holding a part and its Merkle proof can
+//! then show that the part was in the registered document, without revealing the rest of it; see
+//! [`Pallet::includes`]. Commitments are kept in [`Commitments`].
+//!
+//! ### Co-signing
+//!
+//! Listing an account as an author requires that account's consent. A submission starts out as
+//! [`ManuscriptStatus::Pending`] and only becomes [`ManuscriptStatus::Final`] once every listed
+//! author has signed [`approve_authorship`](Pallet::approve_authorship); the submitter's own
+//! approval is implied. Authors have [`Config::ApprovalPeriod`] blocks to do so, after which the
+//! submission can be removed by anyone through [`remove_expired`](Pallet::remove_expired). A
+//! document can only be revised once its latest version is final.
+//!
+//! ### Deposits
+//!
+//! Every submission occupies state that all nodes must keep forever, so the submitter pays for it:
+//! a deposit of [`Config::DepositBase`] plus [`Config::DepositPerByte`] for each byte of encoded
+//! metadata and Merkle commitment is placed on hold under [`HoldReason::ManuscriptDeposit`]. The
+//! deposit is released when a pending submission is withdrawn or expires, and when the registration
+//! is handed over to another account, who then places their own deposit in its stead.
+//!
+//! ### Queries
+//!
+//! Manuscripts are also indexed by author in [`ManuscriptsByAuthor`]. The record under a content
+//! hash, the [`history`](Pallet::history) and [`status`](Pallet::document_status) of a document
+//! and the manuscripts [listing an author](Pallet::by_author) are exposed through the runtime API
+//! of the `pallet-manuscripts-runtime-api` crate.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`register`](Pallet::register) - Register a new manuscript by its content hash.
+//! - [`submit_revision`](Pallet::submit_revision) - Append a new version to an existing document.
+//! - [`approve_authorship`](Pallet::approve_authorship) - Confirm being an author of a pending
+//!   submission.
+//! - [`remove_expired`](Pallet::remove_expired) - Clean up a submission whose approval period has
+//!   elapsed.
+//! - [`withdraw`](Pallet::withdraw) - Withdraw a pending submission and release its deposit.
+//! - [`propose_transfer`](Pallet::propose_transfer) - Offer a registration to another account.
+//! - [`accept_transfer`](Pallet::accept_transfer) - Take over a registration and its deposit.
+//!
+//! Run `cargo doc --package pallet-manuscripts --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+pub use speem_primitives::{
+	merkle,
+	multihash::{HashAlgorithm, Multihash},
+	DocumentAuthors, DocumentRegistrant, DocumentRegistration,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::traits::Hash as HashT,
+		traits::{
+			fungible::{Inspect, Mutate, MutateHold},
+			tokens::Precision,
+			Contains, Time,
+		},
+		CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	/// The moment type of the configured time provider.
+	pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+
+	/// The balance type of the configured currency.
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// The identifier a manuscript is submitted under: its content hash and the algorithm that
+	/// produced it.
+	pub type ContentId<T> = Multihash<<T as frame_system::Config>::Hash>;
+
+	/// Identifier of a document, i.e. of the chain of versions started by a registration.
+	pub type DocumentId = u64;
+
+	/// The position of a version within its document's history; the original registration is
+	/// version `0`.
+	pub type Version = u32;
+
+	/// Whether all the authors of a submission have approved it.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum ManuscriptStatus<BlockNumber> {
+		/// Some authors have yet to approve the submission.
+		Pending {
+			/// The last block in which approvals are accepted.
+			deadline: BlockNumber,
+		},
+		/// Every listed author has approved the submission.
+		Final,
+	}
+
+	/// How the leaves of the Merkle tree a document is committed to are cut from its content.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum Layout {
+		/// Chunks of [`merkle::CHUNK_SIZE`] bytes, the last of which may be shorter.
+		Chunks,
+		/// Sections of any size, in the order and with the bounds chosen by the registrant.
+		Sections,
+	}
+
+	/// A commitment to the Merkle tree over the parts of a document.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct MerkleCommitment<Hash> {
+		/// What the leaves of the tree are.
+		pub layout: Layout,
+		/// The root of the tree.
+		pub root: Hash,
+		/// The number of leaves of the tree.
+		pub leaves: u32,
+	}
+
+	impl<Hash: PartialEq> MerkleCommitment<Hash> {
+		/// Whether `proof` shows that `part` is the `index`th leaf of the committed tree, hashed
+		/// with `H`.
+		pub fn includes<H: HashT<Output = Hash>>(
+			&self,
+			index: u32,
+			part: &[u8],
+			proof: &[Hash],
+		) -> bool {
+			let root = match self.layout {
+				Layout::Chunks => merkle::root_from_proof::<H>(self.leaves, index, part, proof),
+				Layout::Sections => merkle::root_from_leaf_proof::<H>(
+					self.leaves,
+					index,
+					merkle::leaf_hash::<H>(part),
+					proof,
+				),
+			};
+			root.as_ref() == Some(&self.root)
+		}
+	}
+
+	/// Descriptive metadata attached to a registered manuscript.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct ManuscriptMetadata<T: Config> {
+		/// The title of the manuscript, UTF-8 encoded.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The hash of the manuscript's abstract.
+		pub abstract_hash: T::Hash,
+		/// The accounts of the manuscript's authors, in byline order.
+		pub authors: BoundedVec<T::AccountId, T::MaxAuthors>,
+		/// The license the manuscript is published under, e.g. an SPDX identifier.
+		pub license: BoundedVec<u8, T::MaxLicenseLength>,
+	}
+
+	/// Everything the chain knows about a registered manuscript.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct ManuscriptRecord<T: Config> {
+		/// The descriptive metadata supplied at registration.
+		pub metadata: ManuscriptMetadata<T>,
+		/// The document this manuscript is a version of.
+		pub document: DocumentId,
+		/// The position of this manuscript in the document's history.
+		pub version: Version,
+		/// The content hash of the previous version, or `None` for the original registration.
+		pub parent: Option<T::Hash>,
+		/// Whether the submission has been approved by all of its authors.
+		pub status: ManuscriptStatus<BlockNumberFor<T>>,
+		/// The account that submitted the manuscript, or that it was later transferred to.
+		pub registrant: T::AccountId,
+		/// The deposit held from the registrant for this record.
+		pub deposit: BalanceOf<T>,
+		/// The block in which the manuscript was registered.
+		pub registered_at: BlockNumberFor<T>,
+		/// The timestamp of the block in which the manuscript was registered.
+		pub timestamp: MomentOf<T>,
+	}
+
+	/// The history of a document.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct DocumentInfo<T: Config> {
+		/// The content hash of the original registration.
+		pub origin: T::Hash,
+		/// The content hash of the latest version, which the next revision must name as parent.
+		pub head: T::Hash,
+		/// The version number of [`Self::head`].
+		pub latest_version: Version,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds held for the state occupied by a manuscript record.
+		#[codec(index = 0)]
+		ManuscriptDeposit,
+	}
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency in which registration deposits are held.
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The base deposit held for every registered manuscript.
+		#[pallet::constant]
+		type DepositBase: Get<BalanceOf<Self>>;
+		/// The additional deposit held per byte of encoded manuscript metadata.
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
+		/// The source of the timestamp recorded alongside each registration.
+		type Time: Time;
+		/// The maximum length of a manuscript title, in bytes.
+		#[pallet::constant]
+		type MaxTitleLength: Get<u32>;
+		/// The maximum number of authors a manuscript can list.
+		#[pallet::constant]
+		type MaxAuthors: Get<u32>;
+		/// The maximum length of a manuscript license identifier, in bytes.
+		#[pallet::constant]
+		type MaxLicenseLength: Get<u32>;
+		/// The number of blocks co-authors have to approve a submission before it expires.
+		#[pallet::constant]
+		type ApprovalPeriod: Get<BlockNumberFor<Self>>;
+		/// The number of content hashes in each page of [`Pallet::by_author`].
+		#[pallet::constant]
+		type AuthorPageSize: Get<u32>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Registered manuscripts, keyed by content hash.
+	#[pallet::storage]
+	pub type Manuscripts<T: Config> =
+		StorageMap<_, Identity, T::Hash, ManuscriptRecord<T>, OptionQuery>;
+
+	/// The authors who have approved each pending submission so far.
+	#[pallet::storage]
+	pub type Approvals<T: Config> =
+		StorageMap<_, Identity, T::Hash, BoundedVec<T::AccountId, T::MaxAuthors>, ValueQuery>;
+
+	/// Registrations offered to another account, keyed by content hash.
+	#[pallet::storage]
+	pub type PendingTransfers<T: Config> =
+		StorageMap<_, Identity, T::Hash, T::AccountId, OptionQuery>;
+
+	/// The algorithm that produced the content hash of each registered manuscript. Manuscripts
+	/// without an entry were hashed with BLAKE2b-256, the default, which is not stored.
+	#[pallet::storage]
+	pub type HashAlgorithms<T: Config> =
+		StorageMap<_, Identity, T::Hash, HashAlgorithm, ValueQuery>;
+
+	/// The Merkle commitments registered manuscripts were submitted with, keyed by content hash.
+	#[pallet::storage]
+	pub type Commitments<T: Config> =
+		StorageMap<_, Identity, T::Hash, MerkleCommitment<T::Hash>, OptionQuery>;
+
+	/// The identifier the next registered document will receive.
+	#[pallet::storage]
+	pub type NextDocumentId<T> = StorageValue<_, DocumentId, ValueQuery>;
+
+	/// Registered documents, keyed by document identifier.
+	#[pallet::storage]
+	pub type Documents<T: Config> =
+		StorageMap<_, Twox64Concat, DocumentId, DocumentInfo<T>, OptionQuery>;
+
+	/// The content hash of every version of every document, in order.
+	#[pallet::storage]
+	pub type Revisions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DocumentId, Twox64Concat, Version, T::Hash, OptionQuery>;
+
+	/// The content hashes of the manuscripts listing each account as an author, pending or final.
+	#[pallet::storage]
+	pub type ManuscriptsByAuthor<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Identity, T::Hash, (), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A manuscript has been registered, starting a new document.
+		ManuscriptRegistered {
+			/// The document started by the registration.
+			document: DocumentId,
+			/// The content hash of the manuscript.
+			content_hash: T::Hash,
+			/// The account that registered it.
+			registrant: T::AccountId,
+		},
+		/// A new version of a document has been submitted.
+		RevisionSubmitted {
+			/// The document the revision belongs to.
+			document: DocumentId,
+			/// The version number of the revision.
+			version: Version,
+			/// The content hash of the revision.
+			content_hash: T::Hash,
+			/// The content hash of the version it revises.
+			parent: T::Hash,
+			/// The author who submitted it.
+			author: T::AccountId,
+		},
+		/// An author has approved a pending submission.
+		AuthorshipApproved {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+			/// The approving author.
+			author: T::AccountId,
+		},
+		/// Every author has approved a submission, which is now final.
+		ManuscriptFinalized {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+		},
+		/// A pending submission was removed after its approval period elapsed.
+		SubmissionExpired {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+		},
+		/// A pending submission was withdrawn by its registrant.
+		SubmissionWithdrawn {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+		},
+		/// A registrant has offered a registration to another account.
+		TransferProposed {
+			/// The content hash of the manuscript.
+			content_hash: T::Hash,
+			/// The current registrant.
+			from: T::AccountId,
+			/// The account the registration is offered to.
+			to: T::AccountId,
+		},
+		/// A registration, and the deposit backing it, has changed hands.
+		RegistrationTransferred {
+			/// The content hash of the manuscript.
+			content_hash: T::Hash,
+			/// The previous registrant, whose deposit was released.
+			from: T::AccountId,
+			/// The new registrant, from whom the deposit is now held.
+			to: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A manuscript with this content hash has already been registered.
+		AlreadyRegistered,
+		/// The title is longer than [`Config::MaxTitleLength`].
+		TitleTooLong,
+		/// More authors were listed than [`Config::MaxAuthors`] allows.
+		TooManyAuthors,
+		/// The license is longer than [`Config::MaxLicenseLength`].
+		LicenseTooLong,
+		/// A manuscript must list at least one author.
+		NoAuthors,
+		/// No document exists with the given identifier.
+		UnknownDocument,
+		/// The parent named by a revision is not the document's latest version.
+		NotLatestVersion,
+		/// Only the authors of a document's original registration may revise it.
+		NotAnAuthor,
+		/// The identifier or version counter has been exhausted.
+		Overflow,
+		/// The same account is listed as an author more than once.
+		DuplicateAuthor,
+		/// No manuscript is registered under the given content hash.
+		UnknownManuscript,
+		/// The signer is not listed as an author of the submission.
+		NotListedAuthor,
+		/// The submission has already been approved by all of its authors.
+		AlreadyFinal,
+		/// The signer has already approved the submission.
+		AlreadyApproved,
+		/// The approval period of the submission has elapsed.
+		ApprovalPeriodElapsed,
+		/// The approval period of the submission has not elapsed yet.
+		ApprovalPeriodOngoing,
+		/// A document can only be revised once its latest version is final.
+		ParentNotFinal,
+		/// Only the registrant of a manuscript may perform this operation.
+		NotRegistrant,
+		/// Only pending submissions can be withdrawn.
+		NotPending,
+		/// The registration has not been offered to the signer.
+		NoTransferOffered,
+		/// A Merkle commitment must have at least one leaf.
+		EmptyCommitment,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a manuscript by its content hash.
+		///
+		/// The manuscript is registered under the digest of `content_id`, along with the algorithm
+		/// that produced it. The origin must be signed; the signer is recorded as the registrant.
+		/// The block number and the current timestamp are recorded alongside the supplied
+		/// metadata, and the `commitment` to the parts of the manuscript, if any. The submission
+		/// stays pending until every author other than the signer has approved it.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::AlreadyRegistered`] if the content hash is already in the registry.
+		/// - [`Error::TitleTooLong`], [`Error::TooManyAuthors`] or [`Error::LicenseTooLong`] if the
+		///   metadata exceeds the configured bounds.
+		/// - [`Error::NoAuthors`] if `authors` is empty.
+		/// - [`Error::DuplicateAuthor`] if an author is listed twice.
+		/// - [`Error::EmptyCommitment`] if `commitment` has no leaves.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register())]
+		pub fn register(
+			origin: OriginFor<T>,
+			content_id: ContentId<T>,
+			title: Vec<u8>,
+			abstract_hash: T::Hash,
+			authors: Vec<T::AccountId>,
+			license: Vec<u8>,
+			commitment: Option<MerkleCommitment<T::Hash>>,
+		) -> DispatchResult {
+			let registrant = ensure_signed(origin)?;
+			let content_hash = content_id.digest;
+
+			ensure!(!Manuscripts::<T>::contains_key(content_hash), Error::<T>::AlreadyRegistered);
+
+			let metadata = Self::bound_metadata(title, abstract_hash, authors, license)?;
+			Self::check_commitment(&commitment)?;
+			let document = NextDocumentId::<T>::get();
+			NextDocumentId::<T>::put(document.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+			Documents::<T>::insert(
+				document,
+				DocumentInfo { origin: content_hash, head: content_hash, latest_version: 0 },
+			);
+
+			Self::deposit_event(Event::ManuscriptRegistered {
+				document,
+				content_hash,
+				registrant: registrant.clone(),
+			});
+			Self::insert_version(content_id, metadata, commitment, registrant, document, 0, None)
+		}
+
+		/// Submit a new version of an existing document.
+		///
+		/// The origin must be signed by one of the authors of the document's original
+		/// registration. `parent` must be the content hash of the document's latest version, so
+		/// that concurrent revisions cannot fork its history, and that version must be final. Like
+		/// a registration, the revision stays pending until all of its authors have approved it.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownDocument`] if `document` does not exist.
+		/// - [`Error::NotLatestVersion`] if `parent` is not the document's current head.
+		/// - [`Error::ParentNotFinal`] if `parent` is still awaiting approvals.
+		/// - [`Error::NotAnAuthor`] if the signer is not an original author.
+		/// - [`Error::AlreadyRegistered`] if the content hash of `content_id` is already in the
+		///   registry.
+		/// - Any of the metadata and commitment errors of [`Pallet::register`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::submit_revision())]
+		pub fn submit_revision(
+			origin: OriginFor<T>,
+			document: DocumentId,
+			parent: T::Hash,
+			content_id: ContentId<T>,
+			title: Vec<u8>,
+			abstract_hash: T::Hash,
+			authors: Vec<T::AccountId>,
+			license: Vec<u8>,
+			commitment: Option<MerkleCommitment<T::Hash>>,
+		) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+			let content_hash = content_id.digest;
+
+			let mut info = Documents::<T>::get(document).ok_or(Error::<T>::UnknownDocument)?;
+			ensure!(info.head == parent, Error::<T>::NotLatestVersion);
+			let head = Manuscripts::<T>::get(parent).ok_or(Error::<T>::UnknownDocument)?;
+			ensure!(head.status == ManuscriptStatus::Final, Error::<T>::ParentNotFinal);
+			let original = Manuscripts::<T>::get(info.origin).ok_or(Error::<T>::UnknownDocument)?;
+			ensure!(original.metadata.authors.contains(&author), Error::<T>::NotAnAuthor);
+			ensure!(!Manuscripts::<T>::contains_key(content_hash), Error::<T>::AlreadyRegistered);
+
+			let metadata = Self::bound_metadata(title, abstract_hash, authors, license)?;
+			Self::check_commitment(&commitment)?;
+			let version = info.latest_version.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+			info.head = content_hash;
+			info.latest_version = version;
+			Documents::<T>::insert(document, info);
+
+			Self::deposit_event(Event::RevisionSubmitted {
+				document,
+				version,
+				content_hash,
+				parent,
+				author: author.clone(),
+			});
+			Self::insert_version(
+				content_id,
+				metadata,
+				commitment,
+				author,
+				document,
+				version,
+				Some(parent),
+			)
+		}
+
+		/// Approve being listed as an author of a pending submission.
+		///
+		/// The origin must be signed by one of the submission's listed authors, within its
+		/// approval period. Once the last author approves, the submission becomes final.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownManuscript`] if nothing is registered under `content_hash`.
+		/// - [`Error::AlreadyFinal`] if the submission no longer needs approvals.
+		/// - [`Error::ApprovalPeriodElapsed`] if the deadline has passed.
+		/// - [`Error::NotListedAuthor`] if the signer is not listed as an author.
+		/// - [`Error::AlreadyApproved`] if the signer has already approved.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::approve_authorship())]
+		pub fn approve_authorship(origin: OriginFor<T>, content_hash: T::Hash) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+
+			let mut record =
+				Manuscripts::<T>::get(content_hash).ok_or(Error::<T>::UnknownManuscript)?;
+			let ManuscriptStatus::Pending { deadline } = record.status else {
+				return Err(Error::<T>::AlreadyFinal.into())
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::ApprovalPeriodElapsed
+			);
+			ensure!(record.metadata.authors.contains(&author), Error::<T>::NotListedAuthor);
+
+			let mut approvals = Approvals::<T>::get(content_hash);
+			ensure!(!approvals.contains(&author), Error::<T>::AlreadyApproved);
+			// Approvals are a subset of the bounded author list, so this cannot overflow.
+			approvals.try_push(author.clone()).map_err(|_| Error::<T>::TooManyAuthors)?;
+
+			Self::deposit_event(Event::AuthorshipApproved { content_hash, author });
+
+			if approvals.len() == record.metadata.authors.len() {
+				record.status = ManuscriptStatus::Final;
+				Manuscripts::<T>::insert(content_hash, record);
+				Approvals::<T>::remove(content_hash);
+				Self::deposit_event(Event::ManuscriptFinalized { content_hash });
+			} else {
+				Approvals::<T>::insert(content_hash, approvals);
+			}
+			Ok(())
+		}
+
+		/// Remove a submission whose authors did not all approve it in time.
+		///
+		/// Any signed origin may call this once the approval period has elapsed. If the submission
+		/// was a revision, its parent becomes the head of the document again; if it started a
+		/// document, the document is removed as well. The registrant's deposit is released.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownManuscript`] if nothing is registered under `content_hash`.
+		/// - [`Error::AlreadyFinal`] if the submission was approved by all of its authors.
+		/// - [`Error::ApprovalPeriodOngoing`] if authors can still approve it.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::remove_expired())]
+		pub fn remove_expired(origin: OriginFor<T>, content_hash: T::Hash) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let record =
+				Manuscripts::<T>::get(content_hash).ok_or(Error::<T>::UnknownManuscript)?;
+			let ManuscriptStatus::Pending { deadline } = record.status else {
+				return Err(Error::<T>::AlreadyFinal.into())
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > deadline,
+				Error::<T>::ApprovalPeriodOngoing
+			);
+
+			Self::remove_pending(content_hash, record)?;
+
+			Self::deposit_event(Event::SubmissionExpired { content_hash });
+			Ok(())
+		}
+
+		/// Withdraw a pending submission, releasing its deposit.
+		///
+		/// The origin must be signed by the submission's registrant. Final records are part of the
+		/// permanent record and cannot be withdrawn.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownManuscript`] if nothing is registered under `content_hash`.
+		/// - [`Error::NotRegistrant`] if the signer is not the registrant.
+		/// - [`Error::NotPending`] if the submission is already final.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		pub fn withdraw(origin: OriginFor<T>, content_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let record =
+				Manuscripts::<T>::get(content_hash).ok_or(Error::<T>::UnknownManuscript)?;
+			ensure!(record.registrant == who, Error::<T>::NotRegistrant);
+			ensure!(record.status != ManuscriptStatus::Final, Error::<T>::NotPending);
+
+			Self::remove_pending(content_hash, record)?;
+
+			Self::deposit_event(Event::SubmissionWithdrawn { content_hash });
+			Ok(())
+		}
+
+		/// Offer a registration, and the responsibility for its deposit, to another account.
+		///
+		/// The origin must be signed by the registrant. The offer replaces any previous one and
+		/// takes effect once `to` calls [`Pallet::accept_transfer`].
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownManuscript`] if nothing is registered under `content_hash`.
+		/// - [`Error::NotRegistrant`] if the signer is not the registrant.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::propose_transfer())]
+		pub fn propose_transfer(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let record =
+				Manuscripts::<T>::get(content_hash).ok_or(Error::<T>::UnknownManuscript)?;
+			ensure!(record.registrant == who, Error::<T>::NotRegistrant);
+
+			PendingTransfers::<T>::insert(content_hash, to.clone());
+
+			Self::deposit_event(Event::TransferProposed { content_hash, from: who, to });
+			Ok(())
+		}
+
+		/// Accept a registration offered through [`Pallet::propose_transfer`].
+		///
+		/// The deposit is held from the signer and the previous registrant's deposit released.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoTransferOffered`] if the registration was not offered to the signer.
+		/// - [`Error::UnknownManuscript`] if the manuscript has since been removed.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::accept_transfer())]
+		pub fn accept_transfer(origin: OriginFor<T>, content_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				PendingTransfers::<T>::get(content_hash).as_ref() == Some(&who),
+				Error::<T>::NoTransferOffered
+			);
+			let mut record =
+				Manuscripts::<T>::get(content_hash).ok_or(Error::<T>::UnknownManuscript)?;
+
+			let reason = HoldReason::ManuscriptDeposit.into();
+			T::Currency::hold(&reason, &who, record.deposit)?;
+			T::Currency::release(
+				&reason,
+				&record.registrant,
+				record.deposit,
+				Precision::BestEffort,
+			)?;
+
+			let from = sp_std::mem::replace(&mut record.registrant, who.clone());
+			Manuscripts::<T>::insert(content_hash, record);
+			PendingTransfers::<T>::remove(content_hash);
+
+			Self::deposit_event(Event::RegistrationTransferred { content_hash, from, to: who });
+			Ok(())
+		}
+	}
+
+	/// A content hash is contained in the registry once its manuscript is final.
+	impl<T: Config> Contains<T::Hash> for Pallet<T> {
+		fn contains(content_hash: &T::Hash) -> bool {
+			Manuscripts::<T>::get(content_hash)
+				.map_or(false, |record| record.status == ManuscriptStatus::Final)
+		}
+	}
+
+	impl<T: Config> DocumentAuthors<T::AccountId, T::Hash> for Pallet<T> {
+		fn authors_of(content_hash: &T::Hash) -> Vec<T::AccountId> {
+			Manuscripts::<T>::get(content_hash)
+				.map(|record| record.metadata.authors.into_inner())
+				.unwrap_or_default()
+		}
+
+		fn co_authors_of(author: &T::AccountId, max_documents: u32) -> Vec<T::AccountId> {
+			let mut co_authors = Vec::new();
+			for content_hash in
+				ManuscriptsByAuthor::<T>::iter_key_prefix(author).take(max_documents as usize)
+			{
+				// Pending manuscripts list authors who have not approved them.
+				let Some(record) = Manuscripts::<T>::get(content_hash)
+					.filter(|record| record.status == ManuscriptStatus::Final)
+				else {
+					continue
+				};
+				for other in record.metadata.authors {
+					if other != *author && !co_authors.contains(&other) {
+						co_authors.push(other);
+					}
+				}
+			}
+			co_authors
+		}
+	}
+
+	impl<T: Config> DocumentRegistrant<T::AccountId, T::Hash> for Pallet<T> {
+		fn registrant_of(content_hash: &T::Hash) -> Option<T::AccountId> {
+			Manuscripts::<T>::get(content_hash).map(|record| record.registrant)
+		}
+	}
+
+	impl<T: Config> DocumentRegistration<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+		fn registered_at(content_hash: &T::Hash) -> Option<BlockNumberFor<T>> {
+			Manuscripts::<T>::get(content_hash).map(|record| record.registered_at)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The content hashes of every version of `document`, oldest first.
+		pub fn history(document: DocumentId) -> Vec<T::Hash> {
+			let Some(info) = Documents::<T>::get(document) else { return Vec::new() };
+			(0..=info.latest_version)
+				.filter_map(|version| Revisions::<T>::get(document, version))
+				.collect()
+		}
+
+		/// The status of the latest version of `document`, or `None` if it does not exist.
+		pub fn document_status(
+			document: DocumentId,
+		) -> Option<ManuscriptStatus<BlockNumberFor<T>>> {
+			let info = Documents::<T>::get(document)?;
+			Manuscripts::<T>::get(info.head).map(|record| record.status)
+		}
+
+		/// The `page`th page of the content hashes of the manuscripts listing `author`, pending or
+		/// final, with [`Config::AuthorPageSize`] hashes per page.
+		///
+		/// Manuscripts are ordered by content hash rather than by registration, so a page can
+		/// shift as manuscripts are registered or removed.
+		pub fn by_author(author: &T::AccountId, page: u32) -> Vec<T::Hash> {
+			let page_size = T::AuthorPageSize::get() as usize;
+			ManuscriptsByAuthor::<T>::iter_key_prefix(author)
+				.skip((page as usize).saturating_mul(page_size))
+				.take(page_size)
+				.collect()
+		}
+
+		/// The identifier the manuscript registered under `content_hash` was submitted under, or
+		/// `None` if nothing is registered under it.
+		pub fn content_id(content_hash: T::Hash) -> Option<ContentId<T>> {
+			Manuscripts::<T>::contains_key(content_hash)
+				.then(|| Multihash::new(HashAlgorithms::<T>::get(content_hash), content_hash))
+		}
+
+		/// Whether `proof` shows that `part` is the `index`th chunk or section of the manuscript
+		/// registered under `content_hash`, according to the Merkle commitment it was registered
+		/// with. Manuscripts registered without one include no part.
+		pub fn includes(
+			content_hash: &T::Hash,
+			index: u32,
+			part: &[u8],
+			proof: &[T::Hash],
+		) -> bool {
+			Commitments::<T>::get(content_hash)
+				.map_or(false, |commitment| commitment.includes::<T::Hashing>(index, part, proof))
+		}
+
+		/// The deposit required for a record carrying `metadata` and `commitment`.
+		pub fn deposit_for(
+			metadata: &ManuscriptMetadata<T>,
+			commitment: Option<&MerkleCommitment<T::Hash>>,
+		) -> BalanceOf<T> {
+			let bytes = metadata.encoded_size() + commitment.map_or(0, Encode::encoded_size);
+			let bytes: BalanceOf<T> = (bytes as u32).into();
+			T::DepositBase::get().saturating_add(T::DepositPerByte::get().saturating_mul(bytes))
+		}
+
+		/// Record a new version of `document` in the registry, holding the deposit for it from
+		/// `registrant`.
+		///
+		/// The registrant's approval is implied, so the version is final straight away if they are
+		/// its only author and pending otherwise.
+		fn insert_version(
+			content_id: ContentId<T>,
+			metadata: ManuscriptMetadata<T>,
+			commitment: Option<MerkleCommitment<T::Hash>>,
+			registrant: T::AccountId,
+			document: DocumentId,
+			version: Version,
+			parent: Option<T::Hash>,
+		) -> DispatchResult {
+			let content_hash = content_id.digest;
+			let deposit = Self::deposit_for(&metadata, commitment.as_ref());
+			T::Currency::hold(&HoldReason::ManuscriptDeposit.into(), &registrant, deposit)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let self_approved = metadata.authors.contains(&registrant);
+			let status = if self_approved && metadata.authors.len() == 1 {
+				ManuscriptStatus::Final
+			} else {
+				if self_approved {
+					// A single approval always fits within the bounded author list.
+					let _ = Approvals::<T>::mutate(content_hash, |approvals| {
+						approvals.try_push(registrant.clone())
+					});
+				}
+				ManuscriptStatus::Pending { deadline: now.saturating_add(T::ApprovalPeriod::get()) }
+			};
+
+			let record = ManuscriptRecord {
+				metadata,
+				document,
+				version,
+				parent,
+				status,
+				registrant,
+				deposit,
+				registered_at: now,
+				timestamp: T::Time::now(),
+			};
+			for author in &record.metadata.authors {
+				ManuscriptsByAuthor::<T>::insert(author, content_hash, ());
+			}
+			Manuscripts::<T>::insert(content_hash, record);
+			Revisions::<T>::insert(document, version, content_hash);
+			if content_id.algorithm != HashAlgorithm::default() {
+				HashAlgorithms::<T>::insert(content_hash, content_id.algorithm);
+			}
+			if let Some(commitment) = commitment {
+				Commitments::<T>::insert(content_hash, commitment);
+			}
+
+			if status == ManuscriptStatus::Final {
+				Self::deposit_event(Event::ManuscriptFinalized { content_hash });
+			}
+			Ok(())
+		}
+
+		/// Remove a pending submission from the registry and release its deposit.
+		///
+		/// If the submission was a revision, its parent becomes the head of the document again;
+		/// if it started a document, the document is removed as well.
+		fn remove_pending(content_hash: T::Hash, record: ManuscriptRecord<T>) -> DispatchResult {
+			T::Currency::release(
+				&HoldReason::ManuscriptDeposit.into(),
+				&record.registrant,
+				record.deposit,
+				Precision::BestEffort,
+			)?;
+
+			for author in &record.metadata.authors {
+				ManuscriptsByAuthor::<T>::remove(author, content_hash);
+			}
+			Manuscripts::<T>::remove(content_hash);
+			HashAlgorithms::<T>::remove(content_hash);
+			Commitments::<T>::remove(content_hash);
+			Approvals::<T>::remove(content_hash);
+			PendingTransfers::<T>::remove(content_hash);
+			Revisions::<T>::remove(record.document, record.version);
+			match record.parent {
+				Some(parent) => Documents::<T>::mutate(record.document, |info| {
+					if let Some(info) = info {
+						info.head = parent;
+						info.latest_version = record.version.saturating_sub(1);
+					}
+				}),
+				None => Documents::<T>::remove(record.document),
+			}
+			Ok(())
+		}
+
+		/// Check that `commitment` describes a tree, which has at least one leaf.
+		fn check_commitment(commitment: &Option<MerkleCommitment<T::Hash>>) -> DispatchResult {
+			ensure!(
+				commitment.as_ref().map_or(true, |commitment| commitment.leaves > 0),
+				Error::<T>::EmptyCommitment
+			);
+			Ok(())
+		}
+
+		/// Convert unbounded call arguments into [`ManuscriptMetadata`], failing with the error
+		/// matching the first bound that is exceeded.
+		pub(crate) fn bound_metadata(
+			title: Vec<u8>,
+			abstract_hash: T::Hash,
+			authors: Vec<T::AccountId>,
+			license: Vec<u8>,
+		) -> Result<ManuscriptMetadata<T>, Error<T>> {
+			ensure!(!authors.is_empty(), Error::<T>::NoAuthors);
+			let mut unique = authors.clone();
+			unique.sort();
+			unique.dedup();
+			ensure!(unique.len() == authors.len(), Error::<T>::DuplicateAuthor);
+
+			Ok(ManuscriptMetadata {
+				title: title.try_into().map_err(|_| Error::<T>::TitleTooLong)?,
+				abstract_hash,
+				authors: authors.try_into().map_err(|_| Error::<T>::TooManyAuthors)?,
+				license: license.try_into().map_err(|_| Error::<T>::LicenseTooLong)?,
+			})
+		}
+	}
+}
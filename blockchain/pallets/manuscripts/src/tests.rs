@@ -0,0 +1,654 @@
+use crate::{
+	merkle, mock::*, Approvals, Commitments, ContentId, DocumentAuthors, DocumentRegistrant,
+	DocumentRegistration, Documents, Error, Event, HashAlgorithm, HashAlgorithms, HoldReason,
+	Layout, ManuscriptStatus, Manuscripts, MerkleCommitment, Multihash, PendingTransfers,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResult,
+	traits::{fungible::InspectHold, Contains},
+};
+use sp_core::H256;
+use sp_runtime::traits::BlakeTwo256;
+
+const CONTENT: H256 = H256::repeat_byte(1);
+const ABSTRACT: H256 = H256::repeat_byte(2);
+const REVISION: H256 = H256::repeat_byte(3);
+
+/// The identifier of content hashed with the runtime's own algorithm.
+fn blake2(content_hash: H256) -> ContentId<Test> {
+	Multihash::new(HashAlgorithm::Blake2b256, content_hash)
+}
+
+fn revise(who: u64, document: u64, parent: H256, content_hash: H256) -> DispatchResult {
+	ManuscriptsModule::submit_revision(
+		RuntimeOrigin::signed(who),
+		document,
+		parent,
+		blake2(content_hash),
+		b"On the Provenance of Papers (revised)".to_vec(),
+		ABSTRACT,
+		vec![1, 2],
+		b"CC-BY-4.0".to_vec(),
+		None,
+	)
+}
+
+fn approve(who: u64, content_hash: H256) -> DispatchResult {
+	ManuscriptsModule::approve_authorship(RuntimeOrigin::signed(who), content_hash)
+}
+
+fn held(who: u64) -> u64 {
+	Balances::balance_on_hold(&HoldReason::ManuscriptDeposit.into(), &who)
+}
+
+fn register(who: u64, content_hash: H256, authors: Vec<u64>) -> DispatchResult {
+	ManuscriptsModule::register(
+		RuntimeOrigin::signed(who),
+		blake2(content_hash),
+		b"On the Provenance of Papers".to_vec(),
+		ABSTRACT,
+		authors,
+		b"CC-BY-4.0".to_vec(),
+		None,
+	)
+}
+
+#[test]
+fn register_stores_record() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(3);
+		Timestamp::set_timestamp(42);
+
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+
+		let record = Manuscripts::<Test>::get(CONTENT).expect("manuscript was registered");
+		assert_eq!(record.registrant, 1);
+		assert_eq!(record.registered_at, 3);
+		assert_eq!(record.timestamp, 42);
+		assert_eq!(record.metadata.abstract_hash, ABSTRACT);
+		assert_eq!(record.metadata.authors.into_inner(), vec![1, 2]);
+		assert_eq!(record.metadata.license.into_inner(), b"CC-BY-4.0".to_vec());
+		System::assert_last_event(
+			Event::ManuscriptRegistered { document: 0, content_hash: CONTENT, registrant: 1 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn duplicate_hash_is_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(1, CONTENT, vec![1]));
+		assert_noop!(register(2, CONTENT, vec![2]), Error::<Test>::AlreadyRegistered);
+	});
+}
+
+#[test]
+fn oversize_metadata_is_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ManuscriptsModule::register(
+				RuntimeOrigin::signed(1),
+				blake2(CONTENT),
+				vec![b'a'; 33],
+				ABSTRACT,
+				vec![1],
+				b"CC-BY-4.0".to_vec(),
+				None,
+			),
+			Error::<Test>::TitleTooLong
+		);
+		assert_noop!(register(1, CONTENT, vec![1, 2, 3, 4, 5]), Error::<Test>::TooManyAuthors);
+		assert_noop!(
+			ManuscriptsModule::register(
+				RuntimeOrigin::signed(1),
+				blake2(CONTENT),
+				b"Title".to_vec(),
+				ABSTRACT,
+				vec![1],
+				vec![b'l'; 17],
+				None,
+			),
+			Error::<Test>::LicenseTooLong
+		);
+	});
+}
+
+#[test]
+fn authors_are_required() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(register(1, CONTENT, vec![]), Error::<Test>::NoAuthors);
+	});
+}
+
+#[test]
+fn registrations_start_new_documents() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(1, CONTENT, vec![1]));
+		assert_ok!(register(1, REVISION, vec![1]));
+
+		assert_eq!(Manuscripts::<Test>::get(CONTENT).unwrap().document, 0);
+		assert_eq!(Manuscripts::<Test>::get(REVISION).unwrap().document, 1);
+		assert_eq!(ManuscriptsModule::history(1), vec![REVISION]);
+	});
+}
+
+#[test]
+fn revisions_extend_the_history() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let third = H256::repeat_byte(4);
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+		assert_ok!(approve(2, CONTENT));
+
+		assert_ok!(revise(2, 0, CONTENT, REVISION));
+		System::assert_last_event(
+			Event::RevisionSubmitted {
+				document: 0,
+				version: 1,
+				content_hash: REVISION,
+				parent: CONTENT,
+				author: 2,
+			}
+			.into(),
+		);
+		assert_ok!(approve(1, REVISION));
+		assert_ok!(revise(1, 0, REVISION, third));
+
+		let record = Manuscripts::<Test>::get(third).unwrap();
+		assert_eq!((record.document, record.version, record.parent), (0, 2, Some(REVISION)));
+		assert_eq!(Documents::<Test>::get(0).unwrap().head, third);
+		assert_eq!(ManuscriptsModule::history(0), vec![CONTENT, REVISION, third]);
+	});
+}
+
+#[test]
+fn forks_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(1, CONTENT, vec![1]));
+		assert_ok!(revise(1, 0, CONTENT, REVISION));
+
+		assert_noop!(revise(1, 0, CONTENT, H256::repeat_byte(4)), Error::<Test>::NotLatestVersion);
+	});
+}
+
+#[test]
+fn only_original_authors_may_revise() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(3, CONTENT, vec![1]));
+		assert_ok!(approve(1, CONTENT));
+
+		assert_noop!(revise(3, 0, CONTENT, REVISION), Error::<Test>::NotAnAuthor);
+		assert_noop!(revise(1, 7, CONTENT, REVISION), Error::<Test>::UnknownDocument);
+	});
+}
+
+#[test]
+fn revisions_cannot_reuse_registered_hashes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(1, CONTENT, vec![1]));
+		assert_ok!(register(1, REVISION, vec![1]));
+
+		assert_noop!(revise(1, 0, CONTENT, REVISION), Error::<Test>::AlreadyRegistered);
+	});
+}
+
+#[test]
+fn duplicate_authors_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(register(1, CONTENT, vec![1, 2, 1]), Error::<Test>::DuplicateAuthor);
+	});
+}
+
+#[test]
+fn sole_author_submissions_are_final() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register(1, CONTENT, vec![1]));
+
+		assert_eq!(Manuscripts::<Test>::get(CONTENT).unwrap().status, ManuscriptStatus::Final);
+		System::assert_last_event(Event::ManuscriptFinalized { content_hash: CONTENT }.into());
+		assert_noop!(approve(1, CONTENT), Error::<Test>::AlreadyFinal);
+	});
+}
+
+#[test]
+fn co_authors_must_approve() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register(1, CONTENT, vec![1, 2, 3]));
+		assert_eq!(
+			Manuscripts::<Test>::get(CONTENT).unwrap().status,
+			ManuscriptStatus::Pending { deadline: 11 }
+		);
+		assert_eq!(Approvals::<Test>::get(CONTENT).into_inner(), vec![1]);
+
+		assert_noop!(approve(4, CONTENT), Error::<Test>::NotListedAuthor);
+		assert_noop!(approve(1, CONTENT), Error::<Test>::AlreadyApproved);
+
+		assert_ok!(approve(3, CONTENT));
+		System::assert_last_event(
+			Event::AuthorshipApproved { content_hash: CONTENT, author: 3 }.into(),
+		);
+		assert!(matches!(
+			Manuscripts::<Test>::get(CONTENT).unwrap().status,
+			ManuscriptStatus::Pending { .. }
+		));
+
+		assert_ok!(approve(2, CONTENT));
+		System::assert_last_event(Event::ManuscriptFinalized { content_hash: CONTENT }.into());
+		assert_eq!(Manuscripts::<Test>::get(CONTENT).unwrap().status, ManuscriptStatus::Final);
+		assert!(!Approvals::<Test>::contains_key(CONTENT));
+	});
+}
+
+#[test]
+fn only_final_manuscripts_are_contained() {
+	new_test_ext().execute_with(|| {
+		assert!(!<ManuscriptsModule as Contains<H256>>::contains(&CONTENT));
+
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+		assert!(!<ManuscriptsModule as Contains<H256>>::contains(&CONTENT));
+
+		assert_ok!(approve(2, CONTENT));
+		assert!(<ManuscriptsModule as Contains<H256>>::contains(&CONTENT));
+	});
+}
+
+#[test]
+fn authors_are_listed_in_byline_order() {
+	new_test_ext().execute_with(|| {
+		assert!(<ManuscriptsModule as DocumentAuthors<u64, H256>>::authors_of(&CONTENT).is_empty());
+
+		assert_ok!(register(1, CONTENT, vec![3, 1, 2]));
+		assert_eq!(
+			<ManuscriptsModule as DocumentAuthors<u64, H256>>::authors_of(&CONTENT),
+			vec![3, 1, 2]
+		);
+	});
+}
+
+#[test]
+fn co_authors_come_from_final_manuscripts() {
+	new_test_ext().execute_with(|| {
+		let co_authors = |author: u64, max_documents: u32| {
+			<ManuscriptsModule as DocumentAuthors<u64, H256>>::co_authors_of(&author, max_documents)
+		};
+
+		assert_ok!(register(1, CONTENT, vec![1, 2, 3]));
+		// Nobody is a co-author before every listed author has approved.
+		assert!(co_authors(1, 10).is_empty());
+
+		assert_ok!(approve(2, CONTENT));
+		assert_ok!(approve(3, CONTENT));
+		assert_eq!(co_authors(1, 10), vec![2, 3]);
+		assert_eq!(co_authors(3, 10), vec![1, 2]);
+		assert!(co_authors(3, 0).is_empty());
+	});
+}
+
+#[test]
+fn registrant_follows_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			<ManuscriptsModule as DocumentRegistrant<u64, H256>>::registrant_of(&CONTENT),
+			None
+		);
+
+		assert_ok!(register(1, CONTENT, vec![1]));
+		assert_eq!(
+			<ManuscriptsModule as DocumentRegistrant<u64, H256>>::registrant_of(&CONTENT),
+			Some(1)
+		);
+		assert_ok!(ManuscriptsModule::propose_transfer(RuntimeOrigin::signed(1), CONTENT, 2));
+		assert_ok!(ManuscriptsModule::accept_transfer(RuntimeOrigin::signed(2), CONTENT));
+		assert_eq!(
+			<ManuscriptsModule as DocumentRegistrant<u64, H256>>::registrant_of(&CONTENT),
+			Some(2)
+		);
+	});
+}
+
+#[test]
+fn registration_block_is_kept() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		assert_eq!(ManuscriptsModule::registered_at(&CONTENT), None);
+
+		assert_ok!(register(1, CONTENT, vec![1]));
+		System::set_block_number(4);
+		assert_eq!(ManuscriptsModule::registered_at(&CONTENT), Some(3));
+	});
+}
+
+#[test]
+fn manuscripts_are_paged_by_author() {
+	new_test_ext().execute_with(|| {
+		let other = H256::repeat_byte(4);
+		let third = H256::repeat_byte(5);
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+		assert_ok!(register(2, other, vec![2]));
+		assert_ok!(register(3, third, vec![3, 2]));
+
+		assert_eq!(ManuscriptsModule::by_author(&1, 0), vec![CONTENT]);
+		assert_eq!(ManuscriptsModule::by_author(&2, 0), vec![CONTENT, other]);
+		assert_eq!(ManuscriptsModule::by_author(&2, 1), vec![third]);
+		assert!(ManuscriptsModule::by_author(&2, 2).is_empty());
+
+		// Withdrawn submissions leave the index.
+		assert_ok!(ManuscriptsModule::withdraw(RuntimeOrigin::signed(1), CONTENT));
+		assert!(ManuscriptsModule::by_author(&1, 0).is_empty());
+		assert_eq!(ManuscriptsModule::by_author(&2, 0), vec![other, third]);
+	});
+}
+
+#[test]
+fn document_status_follows_the_latest_version() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(ManuscriptsModule::document_status(0), None);
+
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+		assert_eq!(
+			ManuscriptsModule::document_status(0),
+			Some(ManuscriptStatus::Pending { deadline: 11 })
+		);
+		assert_ok!(approve(2, CONTENT));
+		assert_eq!(ManuscriptsModule::document_status(0), Some(ManuscriptStatus::Final));
+
+		assert_ok!(revise(1, 0, CONTENT, REVISION));
+		assert_eq!(
+			ManuscriptsModule::document_status(0),
+			Some(ManuscriptStatus::Pending { deadline: 11 })
+		);
+	});
+}
+
+#[test]
+fn submitter_need_not_be_an_author() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(9, CONTENT, vec![1]));
+		assert!(Approvals::<Test>::get(CONTENT).is_empty());
+
+		assert_ok!(approve(1, CONTENT));
+		assert_eq!(Manuscripts::<Test>::get(CONTENT).unwrap().status, ManuscriptStatus::Final);
+	});
+}
+
+#[test]
+fn pending_parents_cannot_be_revised() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+
+		assert_noop!(revise(1, 0, CONTENT, REVISION), Error::<Test>::ParentNotFinal);
+	});
+}
+
+#[test]
+fn approvals_close_after_the_deadline() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+
+		System::set_block_number(12);
+		assert_noop!(approve(2, CONTENT), Error::<Test>::ApprovalPeriodElapsed);
+	});
+}
+
+#[test]
+fn expired_registrations_are_removed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+
+		System::set_block_number(11);
+		assert_noop!(
+			ManuscriptsModule::remove_expired(RuntimeOrigin::signed(5), CONTENT),
+			Error::<Test>::ApprovalPeriodOngoing
+		);
+
+		System::set_block_number(12);
+		assert_ok!(ManuscriptsModule::remove_expired(RuntimeOrigin::signed(5), CONTENT));
+		System::assert_last_event(Event::SubmissionExpired { content_hash: CONTENT }.into());
+		assert!(!Manuscripts::<Test>::contains_key(CONTENT));
+		assert!(!Approvals::<Test>::contains_key(CONTENT));
+		assert!(!Documents::<Test>::contains_key(0));
+		assert!(ManuscriptsModule::history(0).is_empty());
+
+		// The content hash can be registered again.
+		assert_ok!(register(1, CONTENT, vec![1]));
+	});
+}
+
+#[test]
+fn expired_revisions_restore_the_parent_as_head() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register(1, CONTENT, vec![1]));
+		assert_ok!(revise(1, 0, CONTENT, REVISION));
+
+		System::set_block_number(12);
+		assert_ok!(ManuscriptsModule::remove_expired(RuntimeOrigin::signed(5), REVISION));
+
+		let info = Documents::<Test>::get(0).unwrap();
+		assert_eq!((info.head, info.latest_version), (CONTENT, 0));
+		assert_eq!(ManuscriptsModule::history(0), vec![CONTENT]);
+		assert_noop!(
+			ManuscriptsModule::remove_expired(RuntimeOrigin::signed(5), CONTENT),
+			Error::<Test>::AlreadyFinal
+		);
+	});
+}
+
+#[test]
+fn registration_holds_a_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+
+		let record = Manuscripts::<Test>::get(CONTENT).unwrap();
+		// 10 base plus one per byte of encoded metadata.
+		let expected = 10 + codec::Encode::encoded_size(&record.metadata) as u64;
+		assert_eq!(record.deposit, expected);
+		assert_eq!(ManuscriptsModule::deposit_for(&record.metadata, None), expected);
+		assert_eq!(held(1), expected);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - expected);
+	});
+}
+
+#[test]
+fn registration_requires_funds_for_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert!(register(42, CONTENT, vec![42]).is_err());
+		assert!(!Manuscripts::<Test>::contains_key(CONTENT));
+	});
+}
+
+#[test]
+fn withdrawal_releases_the_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+
+		assert_noop!(
+			ManuscriptsModule::withdraw(RuntimeOrigin::signed(2), CONTENT),
+			Error::<Test>::NotRegistrant
+		);
+		assert_ok!(ManuscriptsModule::withdraw(RuntimeOrigin::signed(1), CONTENT));
+		System::assert_last_event(Event::SubmissionWithdrawn { content_hash: CONTENT }.into());
+		assert!(!Manuscripts::<Test>::contains_key(CONTENT));
+		assert_eq!(held(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn final_records_cannot_be_withdrawn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register(1, CONTENT, vec![1]));
+
+		assert_noop!(
+			ManuscriptsModule::withdraw(RuntimeOrigin::signed(1), CONTENT),
+			Error::<Test>::NotPending
+		);
+	});
+}
+
+#[test]
+fn expiry_releases_the_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register(1, CONTENT, vec![1, 2]));
+
+		System::set_block_number(12);
+		assert_ok!(ManuscriptsModule::remove_expired(RuntimeOrigin::signed(5), CONTENT));
+		assert_eq!(held(1), 0);
+	});
+}
+
+#[test]
+fn transfers_move_the_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register(1, CONTENT, vec![1]));
+		let deposit = held(1);
+
+		assert_noop!(
+			ManuscriptsModule::propose_transfer(RuntimeOrigin::signed(2), CONTENT, 2),
+			Error::<Test>::NotRegistrant
+		);
+		assert_ok!(ManuscriptsModule::propose_transfer(RuntimeOrigin::signed(1), CONTENT, 2));
+		System::assert_last_event(
+			Event::TransferProposed { content_hash: CONTENT, from: 1, to: 2 }.into(),
+		);
+
+		assert_noop!(
+			ManuscriptsModule::accept_transfer(RuntimeOrigin::signed(3), CONTENT),
+			Error::<Test>::NoTransferOffered
+		);
+		assert_ok!(ManuscriptsModule::accept_transfer(RuntimeOrigin::signed(2), CONTENT));
+		System::assert_last_event(
+			Event::RegistrationTransferred { content_hash: CONTENT, from: 1, to: 2 }.into(),
+		);
+
+		assert_eq!(Manuscripts::<Test>::get(CONTENT).unwrap().registrant, 2);
+		assert!(!PendingTransfers::<Test>::contains_key(CONTENT));
+		assert_eq!((held(1), held(2)), (0, deposit));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+fn register_committed(
+	who: u64,
+	content_hash: H256,
+	commitment: MerkleCommitment<H256>,
+) -> DispatchResult {
+	ManuscriptsModule::register(
+		RuntimeOrigin::signed(who),
+		blake2(content_hash),
+		b"On the Provenance of Papers".to_vec(),
+		ABSTRACT,
+		vec![1, 2],
+		b"CC-BY-4.0".to_vec(),
+		Some(commitment),
+	)
+}
+
+#[test]
+fn chunks_of_committed_manuscripts_are_included() {
+	new_test_ext().execute_with(|| {
+		let content = vec![7u8; 3 * merkle::CHUNK_SIZE + 1];
+		let commitment = MerkleCommitment {
+			layout: Layout::Chunks,
+			root: merkle::root::<BlakeTwo256>(&content),
+			leaves: merkle::chunk_count(content.len()),
+		};
+		assert_ok!(register_committed(1, CONTENT, commitment));
+		assert_eq!(Commitments::<Test>::get(CONTENT), Some(commitment));
+
+		let proof = merkle::prove::<BlakeTwo256>(&content, 3).unwrap();
+		let chunk = merkle::chunk(&content, 3).unwrap();
+		assert!(ManuscriptsModule::includes(&CONTENT, 3, chunk, &proof));
+		assert!(!ManuscriptsModule::includes(&CONTENT, 2, chunk, &proof));
+		assert!(!ManuscriptsModule::includes(&REVISION, 3, chunk, &proof));
+	});
+}
+
+#[test]
+fn sections_of_committed_manuscripts_are_included() {
+	new_test_ext().execute_with(|| {
+		let sections: [&[u8]; 3] = [b"Introduction", b"Figure 1", b"Table 1"];
+		let leaves: Vec<H256> = sections
+			.iter()
+			.map(|section| merkle::leaf_hash::<BlakeTwo256>(section))
+			.collect();
+		let commitment = MerkleCommitment {
+			layout: Layout::Sections,
+			root: merkle::root_of_leaves::<BlakeTwo256>(leaves.clone()).unwrap(),
+			leaves: 3,
+		};
+		assert_ok!(register_committed(1, CONTENT, commitment));
+
+		let proof = merkle::prove_leaf::<BlakeTwo256>(leaves, 1).unwrap();
+		assert!(ManuscriptsModule::includes(&CONTENT, 1, b"Figure 1", &proof));
+		assert!(!ManuscriptsModule::includes(&CONTENT, 1, b"Figure 2", &proof));
+
+		// Registrations without a commitment include nothing.
+		assert_ok!(register(1, REVISION, vec![1]));
+		assert!(!ManuscriptsModule::includes(&REVISION, 0, b"Introduction", &[]));
+	});
+}
+
+#[test]
+fn empty_commitments_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let commitment = MerkleCommitment { layout: Layout::Sections, root: CONTENT, leaves: 0 };
+		assert_noop!(register_committed(1, CONTENT, commitment), Error::<Test>::EmptyCommitment);
+	});
+}
+
+#[test]
+fn commitments_are_charged_and_removed_with_their_record() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let commitment = MerkleCommitment { layout: Layout::Chunks, root: ABSTRACT, leaves: 1 };
+		assert_ok!(register_committed(1, CONTENT, commitment));
+		let record = Manuscripts::<Test>::get(CONTENT).unwrap();
+		assert_eq!(held(1), ManuscriptsModule::deposit_for(&record.metadata, Some(&commitment)));
+		assert!(held(1) > ManuscriptsModule::deposit_for(&record.metadata, None));
+
+		assert_ok!(ManuscriptsModule::withdraw(RuntimeOrigin::signed(1), CONTENT));
+		assert_eq!(Commitments::<Test>::get(CONTENT), None);
+		assert_eq!(held(1), 0);
+	});
+}
+
+#[test]
+fn manuscripts_are_registered_under_any_supported_algorithm() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let sha2 = Multihash::new(HashAlgorithm::Sha2_256, CONTENT);
+		assert_ok!(ManuscriptsModule::register(
+			RuntimeOrigin::signed(1),
+			sha2,
+			b"On the Provenance of Papers".to_vec(),
+			ABSTRACT,
+			vec![1, 2],
+			b"CC-BY-4.0".to_vec(),
+			None,
+		));
+		assert_ok!(register(1, REVISION, vec![1]));
+
+		// The registry is keyed by the digest, and remembers the algorithm behind it.
+		assert!(Manuscripts::<Test>::contains_key(CONTENT));
+		assert_eq!(ManuscriptsModule::content_id(CONTENT), Some(sha2));
+		assert_eq!(ManuscriptsModule::content_id(REVISION), Some(blake2(REVISION)));
+		assert_eq!(ManuscriptsModule::content_id(ABSTRACT), None);
+		// BLAKE2b-256, the default, is not stored.
+		assert!(!HashAlgorithms::<Test>::contains_key(REVISION));
+
+		assert_ok!(ManuscriptsModule::withdraw(RuntimeOrigin::signed(1), CONTENT));
+		assert!(!HashAlgorithms::<Test>::contains_key(CONTENT));
+	});
+}
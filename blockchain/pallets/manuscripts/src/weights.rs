@@ -0,0 +1,175 @@
+
+//! Weights for pallet_manuscripts
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_manuscripts` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_manuscripts.
+pub trait WeightInfo {
+	fn register() -> Weight;
+	fn submit_revision() -> Weight;
+	fn approve_authorship() -> Weight;
+	fn remove_expired() -> Weight;
+	fn withdraw() -> Weight;
+	fn propose_transfer() -> Weight;
+	fn accept_transfer() -> Weight;
+}
+
+/// Weights for pallet_manuscripts using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Manuscripts NextDocumentId (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: Manuscripts Revisions (r:0 w:1)
+	/// Storage: Manuscripts Documents (r:0 w:1)
+	/// Storage: Manuscripts HashAlgorithms (r:0 w:1)
+	/// Storage: Manuscripts Commitments (r:0 w:1)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:0 w:64)
+	fn register() -> Weight {
+		Weight::from_parts(58_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(72_u64))
+	}
+	/// Storage: Manuscripts Documents (r:1 w:1)
+	/// Storage: Manuscripts Manuscripts (r:2 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: Manuscripts Revisions (r:0 w:1)
+	/// Storage: Manuscripts HashAlgorithms (r:0 w:1)
+	/// Storage: Manuscripts Commitments (r:0 w:1)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:0 w:64)
+	fn submit_revision() -> Weight {
+		Weight::from_parts(72_000_000, 11334)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(71_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Manuscripts Approvals (r:1 w:1)
+	fn approve_authorship() -> Weight {
+		Weight::from_parts(40_000_000, 6146)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Manuscripts Documents (r:1 w:1)
+	/// Storage: Manuscripts Approvals (r:0 w:1)
+	/// Storage: Manuscripts Revisions (r:0 w:1)
+	/// Storage: Manuscripts HashAlgorithms (r:0 w:1)
+	/// Storage: Manuscripts Commitments (r:0 w:1)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:0 w:64)
+	fn remove_expired() -> Weight {
+		Weight::from_parts(54_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(73_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Manuscripts Documents (r:1 w:1)
+	/// Storage: Manuscripts HashAlgorithms (r:0 w:1)
+	/// Storage: Manuscripts Commitments (r:0 w:1)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:0 w:64)
+	fn withdraw() -> Weight {
+		Weight::from_parts(53_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(73_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Manuscripts PendingTransfers (r:0 w:1)
+	fn propose_transfer() -> Weight {
+		Weight::from_parts(22_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts PendingTransfers (r:1 w:1)
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	fn accept_transfer() -> Weight {
+		Weight::from_parts(81_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Manuscripts NextDocumentId (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: Manuscripts Revisions (r:0 w:1)
+	/// Storage: Manuscripts Documents (r:0 w:1)
+	/// Storage: Manuscripts HashAlgorithms (r:0 w:1)
+	/// Storage: Manuscripts Commitments (r:0 w:1)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:0 w:64)
+	fn register() -> Weight {
+		Weight::from_parts(58_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(72_u64))
+	}
+	/// Storage: Manuscripts Documents (r:1 w:1)
+	/// Storage: Manuscripts Manuscripts (r:2 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: Manuscripts Revisions (r:0 w:1)
+	/// Storage: Manuscripts HashAlgorithms (r:0 w:1)
+	/// Storage: Manuscripts Commitments (r:0 w:1)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:0 w:64)
+	fn submit_revision() -> Weight {
+		Weight::from_parts(72_000_000, 11334)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(71_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Manuscripts Approvals (r:1 w:1)
+	fn approve_authorship() -> Weight {
+		Weight::from_parts(40_000_000, 6146)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Manuscripts Documents (r:1 w:1)
+	/// Storage: Manuscripts Approvals (r:0 w:1)
+	/// Storage: Manuscripts Revisions (r:0 w:1)
+	/// Storage: Manuscripts HashAlgorithms (r:0 w:1)
+	/// Storage: Manuscripts Commitments (r:0 w:1)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:0 w:64)
+	fn remove_expired() -> Weight {
+		Weight::from_parts(54_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(73_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Manuscripts Documents (r:1 w:1)
+	/// Storage: Manuscripts HashAlgorithms (r:0 w:1)
+	/// Storage: Manuscripts Commitments (r:0 w:1)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:0 w:64)
+	fn withdraw() -> Weight {
+		Weight::from_parts(53_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(73_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Manuscripts PendingTransfers (r:0 w:1)
+	fn propose_transfer() -> Weight {
+		Weight::from_parts(22_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts PendingTransfers (r:1 w:1)
+	/// Storage: Manuscripts Manuscripts (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	fn accept_transfer() -> Weight {
+		Weight::from_parts(81_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
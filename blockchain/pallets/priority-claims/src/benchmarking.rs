@@ -0,0 +1,43 @@
+//! Benchmarking setup for pallet-priority-claims
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as PriorityClaims;
+use frame_benchmarking::v2::*;
+use frame_support::sp_runtime::traits::Hash;
+use frame_system::RawOrigin;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn commit() {
+		let caller: T::AccountId = whitelisted_caller();
+		let commitment = T::Hashing::hash(b"commitment");
+
+		#[extrinsic_call]
+		commit(RawOrigin::Signed(caller.clone()), commitment);
+
+		assert!(Claims::<T>::contains_key(&caller, commitment));
+	}
+
+	#[benchmark]
+	fn reveal() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let content_hash = T::Hashing::hash(b"content");
+		let salt = [7u8; 32];
+		let document = T::BenchmarkHelper::registered_document();
+		let commitment = Pallet::<T>::commitment_of(&caller, &content_hash, &salt);
+		Pallet::<T>::commit(RawOrigin::Signed(caller.clone()).into(), commitment)?;
+
+		#[extrinsic_call]
+		reveal(RawOrigin::Signed(caller), content_hash, salt, document);
+
+		assert!(DocumentClaims::<T>::contains_key(document, commitment));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(PriorityClaims, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,275 @@
+//! # Priority Claims Pallet
+//!
+//! Lets researchers prove that they had a result at a given time without disclosing it.
+//!
+//! ## Overview
+//!
+//! A claim is made in two steps:
+//!
+//! 1. **Commit.** The researcher hashes the content of their result together with a random secret,
+//!    off chain, and submits only that commitment through [`commit`](Pallet::commit). The chain
+//!    records who made the commitment, in which block, and at what time. Nothing about the result
+//!    itself can be learnt from it.
+//! 2. **Reveal.** Once the work is published and its final document registered, the researcher
+//!    submits the content hash and secret through [`reveal`](Pallet::reveal), together with the
+//!    content hash of the final document. The chain recomputes the commitment from them and, if it
+//!    matches, publicly links the claim to the document.
+//!
+//! The commitment is computed as `Hashing::hash_of(&(claimant, content_hash, salt))`, i.e. over
+//! the SCALE encoding of the claimant's account, the content hash and the 32-byte salt. Anyone can
+//! then check, through [`Claims`] and [`DocumentClaims`], that a document's content was known to
+//! its claimant as early as the block in which the commitment was made.
+//!
+//! Binding the claimant into the commitment, and keying [`Claims`] by claimant, means a commitment
+//! copied from the transaction pool is worthless to the copier: it lands under their own account,
+//! where it can never be opened, and does not stop the original claimant from committing.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`commit`](Pallet::commit) - Record a salted commitment to an unpublished result.
+//! - [`reveal`](Pallet::reveal) - Open a commitment and link it to a registered document.
+//!
+//! Run `cargo doc --package pallet-priority-claims --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::traits::Hash,
+		traits::{Contains, Time},
+		CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+
+	/// The moment type of the configured time provider.
+	pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+
+	/// The secret mixed into a commitment so that it cannot be brute-forced from guesses of the
+	/// content.
+	pub type Salt = [u8; 32];
+
+	/// The opening of a priority claim.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Revelation<T: Config> {
+		/// The content hash the claimant committed to.
+		pub content_hash: T::Hash,
+		/// The content hash of the registered document the claim is linked to.
+		pub document: T::Hash,
+		/// The block in which the claim was revealed.
+		pub revealed_at: BlockNumberFor<T>,
+	}
+
+	/// A priority claim.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Claim<T: Config> {
+		/// The block in which the commitment was made.
+		pub committed_at: BlockNumberFor<T>,
+		/// The timestamp of the block in which the commitment was made.
+		pub timestamp: MomentOf<T>,
+		/// The opening of the commitment, once revealed.
+		pub revelation: Option<Revelation<T>>,
+	}
+
+	/// Provides benchmarks with state that this pallet does not control.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Hash> {
+		/// Make sure a document is final in the registry and return its content hash.
+		fn registered_document() -> Hash;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The source of the timestamp recorded alongside each commitment.
+		type Time: Time;
+		/// The registry of documents a claim can be revealed against.
+		type Documents: Contains<Self::Hash>;
+		/// Helper for benchmarks to obtain a document the registry contains.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::Hash>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Priority claims, keyed by claimant and then by commitment.
+	#[pallet::storage]
+	pub type Claims<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Identity,
+		T::Hash,
+		Claim<T>,
+		OptionQuery,
+	>;
+
+	/// The claimants of the revealed claims linked to each document, keyed by document content hash
+	/// and then by commitment.
+	#[pallet::storage]
+	pub type DocumentClaims<T: Config> =
+		StorageDoubleMap<_, Identity, T::Hash, Identity, T::Hash, T::AccountId, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A commitment has been recorded.
+		Committed {
+			/// The commitment.
+			commitment: T::Hash,
+			/// The account that made it.
+			claimant: T::AccountId,
+		},
+		/// A commitment has been opened and linked to a registered document.
+		Revealed {
+			/// The commitment.
+			commitment: T::Hash,
+			/// The content hash it committed to.
+			content_hash: T::Hash,
+			/// The registered document it is linked to.
+			document: T::Hash,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The signer has already recorded this commitment.
+		AlreadyCommitted,
+		/// The signer has no commitment matching the revealed content hash and salt.
+		UnknownCommitment,
+		/// The commitment has already been revealed.
+		AlreadyRevealed,
+		/// The document is not a final entry of the registry.
+		UnregisteredDocument,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Record a commitment to an unpublished result.
+		///
+		/// The origin must be signed; the signer becomes the claimant and must be the account
+		/// `commitment` was computed for (see [`Pallet::commitment_of`]). The block number and the
+		/// current timestamp are recorded alongside the commitment.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::AlreadyCommitted`] if the signer has already recorded `commitment`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::commit())]
+		pub fn commit(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let claimant = ensure_signed(origin)?;
+
+			ensure!(
+				!Claims::<T>::contains_key(&claimant, commitment),
+				Error::<T>::AlreadyCommitted
+			);
+
+			Claims::<T>::insert(
+				&claimant,
+				commitment,
+				Claim {
+					committed_at: frame_system::Pallet::<T>::block_number(),
+					timestamp: T::Time::now(),
+					revelation: None,
+				},
+			);
+
+			Self::deposit_event(Event::Committed { commitment, claimant });
+			Ok(())
+		}
+
+		/// Open a commitment and link it to a registered document.
+		///
+		/// The origin must be signed by the claimant. The commitment is recomputed from the signer,
+		/// `content_hash` and `salt`; `document` must be final in the registry.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownCommitment`] if the signer has no claim matching `content_hash` and
+		///   `salt`.
+		/// - [`Error::AlreadyRevealed`] if the claim has already been revealed.
+		/// - [`Error::UnregisteredDocument`] if `document` is not a final registry entry.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::reveal())]
+		pub fn reveal(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			salt: Salt,
+			document: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let commitment = Self::commitment_of(&who, &content_hash, &salt);
+			let mut claim =
+				Claims::<T>::get(&who, commitment).ok_or(Error::<T>::UnknownCommitment)?;
+			ensure!(claim.revelation.is_none(), Error::<T>::AlreadyRevealed);
+			ensure!(T::Documents::contains(&document), Error::<T>::UnregisteredDocument);
+
+			claim.revelation = Some(Revelation {
+				content_hash,
+				document,
+				revealed_at: frame_system::Pallet::<T>::block_number(),
+			});
+			Claims::<T>::insert(&who, commitment, claim);
+			DocumentClaims::<T>::insert(document, commitment, who);
+
+			Self::deposit_event(Event::Revealed { commitment, content_hash, document });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The commitment of `claimant` to `content_hash` under `salt`.
+		pub fn commitment_of(
+			claimant: &T::AccountId,
+			content_hash: &T::Hash,
+			salt: &Salt,
+		) -> T::Hash {
+			T::Hashing::hash_of(&(claimant, content_hash, salt))
+		}
+	}
+}
@@ -1,13 +1,14 @@
-use crate as pallet_template;
+use crate as pallet_priority_claims;
 use frame_support::{
 	derive_impl,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU64, Contains},
 };
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
+use std::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -16,7 +17,8 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
-		TemplateModule: pallet_template,
+		Timestamp: pallet_timestamp,
+		PriorityClaims: pallet_priority_claims,
 	}
 );
 
@@ -47,12 +49,53 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
-impl pallet_template::Config for Test {
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	static REGISTERED: RefCell<Vec<H256>> = RefCell::new(Vec::new());
+}
+
+/// A stand-in for the manuscript registry.
+pub struct MockDocuments;
+
+impl MockDocuments {
+	/// Mark `document` as a final registry entry.
+	pub fn register(document: H256) {
+		REGISTERED.with(|registered| registered.borrow_mut().push(document));
+	}
+}
+
+impl Contains<H256> for MockDocuments {
+	fn contains(document: &H256) -> bool {
+		REGISTERED.with(|registered| registered.borrow().contains(document))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<H256> for MockDocuments {
+	fn registered_document() -> H256 {
+		let document = H256::repeat_byte(2);
+		Self::register(document);
+		document
+	}
+}
+
+impl pallet_priority_claims::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type Time = Timestamp;
+	type Documents = MockDocuments;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockDocuments;
 	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+	REGISTERED.with(|registered| registered.borrow_mut().clear());
 	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
 }
@@ -0,0 +1,158 @@
+use crate::{mock::*, Claims, DocumentClaims, Error, Event, Revelation, Salt};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+const CONTENT: H256 = H256::repeat_byte(1);
+const DOCUMENT: H256 = H256::repeat_byte(2);
+const SALT: Salt = [7; 32];
+
+fn commitment() -> H256 {
+	PriorityClaims::commitment_of(&1, &CONTENT, &SALT)
+}
+
+#[test]
+fn commit_records_claim() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(5);
+		Timestamp::set_timestamp(1_000);
+
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+
+		let claim = Claims::<Test>::get(1, commitment()).expect("claim was recorded");
+		assert_eq!((claim.committed_at, claim.timestamp), (5, 1_000));
+		assert_eq!(claim.revelation, None);
+		System::assert_last_event(
+			Event::Committed { commitment: commitment(), claimant: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn commitments_are_unique_per_claimant() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+		assert_noop!(
+			PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()),
+			Error::<Test>::AlreadyCommitted
+		);
+	});
+}
+
+#[test]
+fn copied_commitment_neither_blocks_nor_benefits_the_copier() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// Account 2 front-runs account 1 with the same commitment.
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(2), commitment()));
+		System::set_block_number(2);
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+		MockDocuments::register(DOCUMENT);
+
+		assert_ok!(PriorityClaims::reveal(RuntimeOrigin::signed(1), CONTENT, SALT, DOCUMENT));
+		assert_eq!(DocumentClaims::<Test>::get(DOCUMENT, commitment()), Some(1));
+
+		// Knowing the opening does not let the copier open their copy.
+		assert_noop!(
+			PriorityClaims::reveal(RuntimeOrigin::signed(2), CONTENT, SALT, DOCUMENT),
+			Error::<Test>::UnknownCommitment
+		);
+		assert_eq!(Claims::<Test>::get(2, commitment()).unwrap().revelation, None);
+	});
+}
+
+#[test]
+fn same_content_commits_differ_between_claimants() {
+	new_test_ext().execute_with(|| {
+		let other = PriorityClaims::commitment_of(&2, &CONTENT, &SALT);
+		assert_ne!(other, commitment());
+
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(2), other));
+		MockDocuments::register(DOCUMENT);
+
+		assert_ok!(PriorityClaims::reveal(RuntimeOrigin::signed(1), CONTENT, SALT, DOCUMENT));
+		assert_ok!(PriorityClaims::reveal(RuntimeOrigin::signed(2), CONTENT, SALT, DOCUMENT));
+		assert_eq!(DocumentClaims::<Test>::get(DOCUMENT, commitment()), Some(1));
+		assert_eq!(DocumentClaims::<Test>::get(DOCUMENT, other), Some(2));
+	});
+}
+
+#[test]
+fn reveal_links_claim_to_document() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+		MockDocuments::register(DOCUMENT);
+
+		System::set_block_number(9);
+		assert_ok!(PriorityClaims::reveal(RuntimeOrigin::signed(1), CONTENT, SALT, DOCUMENT));
+
+		let claim = Claims::<Test>::get(1, commitment()).unwrap();
+		assert_eq!(
+			claim.revelation,
+			Some(Revelation { content_hash: CONTENT, document: DOCUMENT, revealed_at: 9 })
+		);
+		assert_eq!(DocumentClaims::<Test>::get(DOCUMENT, commitment()), Some(1));
+		System::assert_last_event(
+			Event::Revealed { commitment: commitment(), content_hash: CONTENT, document: DOCUMENT }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn reveal_requires_matching_preimage() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+		MockDocuments::register(DOCUMENT);
+
+		assert_noop!(
+			PriorityClaims::reveal(RuntimeOrigin::signed(1), CONTENT, [8; 32], DOCUMENT),
+			Error::<Test>::UnknownCommitment
+		);
+		assert_noop!(
+			PriorityClaims::reveal(RuntimeOrigin::signed(1), DOCUMENT, SALT, DOCUMENT),
+			Error::<Test>::UnknownCommitment
+		);
+	});
+}
+
+#[test]
+fn only_the_claimant_can_reveal() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+		MockDocuments::register(DOCUMENT);
+
+		assert_noop!(
+			PriorityClaims::reveal(RuntimeOrigin::signed(2), CONTENT, SALT, DOCUMENT),
+			Error::<Test>::UnknownCommitment
+		);
+	});
+}
+
+#[test]
+fn reveal_requires_registered_document() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+
+		assert_noop!(
+			PriorityClaims::reveal(RuntimeOrigin::signed(1), CONTENT, SALT, DOCUMENT),
+			Error::<Test>::UnregisteredDocument
+		);
+	});
+}
+
+#[test]
+fn claims_are_revealed_once() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PriorityClaims::commit(RuntimeOrigin::signed(1), commitment()));
+		MockDocuments::register(DOCUMENT);
+		assert_ok!(PriorityClaims::reveal(RuntimeOrigin::signed(1), CONTENT, SALT, DOCUMENT));
+
+		assert_noop!(
+			PriorityClaims::reveal(RuntimeOrigin::signed(1), CONTENT, SALT, DOCUMENT),
+			Error::<Test>::AlreadyRevealed
+		);
+	});
+}
@@ -0,0 +1,58 @@
+
+//! Weights for pallet_priority_claims
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_priority_claims` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_priority_claims.
+pub trait WeightInfo {
+	fn commit() -> Weight;
+	fn reveal() -> Weight;
+}
+
+/// Weights for pallet_priority_claims using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: PriorityClaims Claims (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	fn commit() -> Weight {
+		Weight::from_parts(17_000_000, 3618)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PriorityClaims Claims (r:1 w:1)
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: PriorityClaims DocumentClaims (r:0 w:1)
+	fn reveal() -> Weight {
+		Weight::from_parts(28_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: PriorityClaims Claims (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	fn commit() -> Weight {
+		Weight::from_parts(17_000_000, 3618)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PriorityClaims Claims (r:1 w:1)
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: PriorityClaims DocumentClaims (r:0 w:1)
+	fn reveal() -> Weight {
+		Weight::from_parts(28_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
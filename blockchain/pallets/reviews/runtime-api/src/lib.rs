@@ -0,0 +1,18 @@
+//! Runtime API of the reviews pallet, through which clients follow the peer review of a document.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_reviews::{Blinding, Recommendation, ReviewStatus, Tally};
+
+sp_api::decl_runtime_apis! {
+	/// Queries on the peer review of the documents in the registry.
+	///
+	/// `Submission` is the runtime's `Submission` of the reviews pallet.
+	pub trait ReviewsApi<Hash, Submission> where Hash: Codec, Submission: Codec {
+		/// The review of the document registered under `content_hash`, or `None` if none has been
+		/// opened.
+		fn review(content_hash: Hash) -> Option<Submission>;
+	}
+}
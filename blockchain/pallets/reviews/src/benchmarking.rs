@@ -0,0 +1,315 @@
+//! Benchmarking setup for pallet-reviews
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ReviewsPallet;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	sp_runtime::traits::{Hash, IdentifyAccount},
+	traits::Get,
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+/// A registered document whose review one of its authors has requested.
+fn requested_document<T: Config>() -> T::Hash {
+	let document = T::BenchmarkHelper::registered_document();
+	let author = T::Documents::authors_of(&document).into_iter().next().expect("has authors");
+	ReviewRequests::<T>::insert(document, author);
+	document
+}
+
+/// Open the review of a registered document with `count` reviewers, returning the editor's origin,
+/// the document and the reviewers.
+fn open_review<T: Config>(
+	count: u32,
+) -> Result<(T::RuntimeOrigin, T::Hash, Vec<T::AccountId>), BenchmarkError> {
+	let origin =
+		T::EditorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let document = requested_document::<T>();
+	let reviewers: Vec<T::AccountId> = (0..count).map(|i| account("reviewer", i, 0)).collect();
+	for reviewer in &reviewers {
+		Pallet::<T>::assign_reviewer(origin.clone(), document, reviewer.clone())?;
+	}
+	Ok((origin, document, reviewers))
+}
+
+/// `T::MaxAffiliations` distinct institutions, starting from the `set`th block of them.
+fn affiliations<T: Config>(set: u32) -> BoundedVec<InstitutionId, T::MaxAffiliations> {
+	let count = T::MaxAffiliations::get();
+	BoundedVec::truncate_from((set * count..(set + 1) * count).collect())
+}
+
+/// Fill every record the conflict checks of `reviewer` against the authors of `document` read,
+/// without any conflict between them.
+fn max_conflict_records<T: Config>(document: T::Hash, reviewer: &T::AccountId) {
+	T::BenchmarkHelper::co_authored_documents(reviewer, T::MaxCoAuthoredDocuments::get());
+	Affiliations::<T>::insert(reviewer, affiliations::<T>(0));
+	for author in T::Documents::authors_of(&document) {
+		Affiliations::<T>::insert(&author, affiliations::<T>(1));
+	}
+}
+
+/// The salt of the `index`th blind reviewer's seal and identity commitment.
+fn salt(index: u32) -> Salt {
+	[index as u8; 32]
+}
+
+/// Open a blind review of a registered document with `count` pseudonymous reviewers, returning the
+/// editor, the document, and each pseudonym with the seal of its review and its signature.
+fn open_blind_review<T: Config>(
+	count: u32,
+) -> Result<
+	(T::AccountId, T::Hash, Vec<(T::AccountId, T::Hash, T::OffchainSignature)>),
+	BenchmarkError,
+> {
+	let origin =
+		T::EditorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let document = requested_document::<T>();
+	Pallet::<T>::open_review(origin.clone(), document, Blinding::DiscloseAfterDecision)?;
+	let editor = T::EditorOrigin::ensure_origin(origin).map_err(|_| BenchmarkError::Weightless)?;
+
+	let mut pseudonyms = Vec::new();
+	for i in 0..count {
+		let public = T::BenchmarkHelper::pseudonym();
+		let seal =
+			Pallet::<T>::seal_of(&T::Hashing::hash(b"review"), Recommendation::Accept, &salt(i));
+		let signature =
+			T::BenchmarkHelper::sign(&public, &Pallet::<T>::seal_payload(&document, &seal));
+		let pseudonym = public.into_account();
+		let reviewer: T::AccountId = account("reviewer", i, 0);
+		Pallet::<T>::assign_pseudonym(
+			RawOrigin::Signed(editor.clone()).into(),
+			document,
+			pseudonym.clone(),
+			Pallet::<T>::identity_commitment_of(&reviewer, &pseudonym, &salt(i)),
+		)?;
+		pseudonyms.push((pseudonym, seal, signature));
+	}
+	Ok((editor, document, pseudonyms))
+}
+
+/// Seal the reviews of every pseudonym in `pseudonyms`.
+fn seal_all<T: Config>(
+	document: T::Hash,
+	pseudonyms: &[(T::AccountId, T::Hash, T::OffchainSignature)],
+) -> Result<(), BenchmarkError> {
+	for (pseudonym, seal, signature) in pseudonyms {
+		Pallet::<T>::seal_review(
+			RawOrigin::None.into(),
+			document,
+			pseudonym.clone(),
+			*seal,
+			signature.clone(),
+		)?;
+	}
+	Ok(())
+}
+
+/// Unseal the review of the `index`th pseudonym.
+fn unseal<T: Config>(
+	document: T::Hash,
+	index: u32,
+	pseudonym: T::AccountId,
+) -> Result<(), BenchmarkError> {
+	Pallet::<T>::unseal_review(
+		RawOrigin::None.into(),
+		document,
+		pseudonym,
+		T::Hashing::hash(b"review"),
+		Recommendation::Accept,
+		salt(index),
+	)?;
+	Ok(())
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn assign_reviewer() -> Result<(), BenchmarkError> {
+		// The first assignment also opens the review.
+		let origin =
+			T::EditorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let document = requested_document::<T>();
+		let reviewer: T::AccountId = whitelisted_caller();
+		max_conflict_records::<T>(document, &reviewer);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, document, reviewer.clone());
+
+		let submission = Submissions::<T>::get(document).expect("review was opened");
+		assert!(submission.reviewers.contains(&reviewer));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_review() -> Result<(), BenchmarkError> {
+		let (_, document, mut reviewers) = open_review::<T>(T::MaxReviewers::get())?;
+		// Submit every review but the one that completes the review, which is the measured call.
+		let last = reviewers.pop().expect("at least one reviewer is assigned");
+		for reviewer in reviewers {
+			Pallet::<T>::submit_review(
+				RawOrigin::Signed(reviewer).into(),
+				document,
+				T::Hashing::hash(b"review"),
+				Recommendation::Accept,
+			)?;
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(last), document, T::Hashing::hash(b"review"), Recommendation::Reject);
+
+		assert_eq!(
+			Submissions::<T>::get(document).map(|submission| submission.status),
+			Some(ReviewStatus::Reviewed)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn decide() -> Result<(), BenchmarkError> {
+		let (origin, document, reviewers) = open_review::<T>(T::MaxReviewers::get())?;
+		for reviewer in reviewers {
+			Pallet::<T>::submit_review(
+				RawOrigin::Signed(reviewer).into(),
+				document,
+				T::Hashing::hash(b"review"),
+				Recommendation::Accept,
+			)?;
+		}
+		let editor =
+			T::EditorOrigin::ensure_origin(origin).map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(editor), document, Recommendation::Accept);
+
+		assert_eq!(
+			Submissions::<T>::get(document).map(|submission| submission.status),
+			Some(ReviewStatus::Decided(Recommendation::Accept))
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn open_review() -> Result<(), BenchmarkError> {
+		let origin =
+			T::EditorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let document = requested_document::<T>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, document, Blinding::DiscloseAfterDecision);
+
+		assert!(Submissions::<T>::contains_key(document));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn assign_pseudonym() -> Result<(), BenchmarkError> {
+		let (editor, document, _) = open_blind_review::<T>(T::MaxReviewers::get() - 1)?;
+		let pseudonym: T::AccountId = whitelisted_caller();
+		let commitment = T::Hashing::hash(b"commitment");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(editor), document, pseudonym.clone(), commitment);
+
+		assert_eq!(IdentityCommitments::<T>::get(document, pseudonym), Some(commitment));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn seal_review() -> Result<(), BenchmarkError> {
+		let (_, document, mut pseudonyms) = open_blind_review::<T>(T::MaxReviewers::get())?;
+		let (pseudonym, seal, signature) = pseudonyms.pop().expect("at least one pseudonym");
+		seal_all::<T>(document, &pseudonyms)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::None, document, pseudonym.clone(), seal, signature);
+
+		assert_eq!(Seals::<T>::get(document, pseudonym), Some(seal));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unseal_review() -> Result<(), BenchmarkError> {
+		let (_, document, mut pseudonyms) = open_blind_review::<T>(T::MaxReviewers::get())?;
+		seal_all::<T>(document, &pseudonyms)?;
+		// Unseal every review but the one that completes the review, which is the measured call.
+		let last = pseudonyms.len() as u32 - 1;
+		let (pseudonym, _, _) = pseudonyms.pop().expect("at least one pseudonym");
+		for (i, (other, _, _)) in pseudonyms.into_iter().enumerate() {
+			unseal::<T>(document, i as u32, other)?;
+		}
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::None,
+			document,
+			pseudonym,
+			T::Hashing::hash(b"review"),
+			Recommendation::Accept,
+			salt(last),
+		);
+
+		assert_eq!(
+			Submissions::<T>::get(document).map(|submission| submission.status),
+			Some(ReviewStatus::Reviewed)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn disclose_identity() -> Result<(), BenchmarkError> {
+		let (editor, document, pseudonyms) = open_blind_review::<T>(1)?;
+		seal_all::<T>(document, &pseudonyms)?;
+		let (pseudonym, _, _) = pseudonyms.into_iter().next().expect("one pseudonym");
+		unseal::<T>(document, 0, pseudonym.clone())?;
+		Pallet::<T>::decide(RawOrigin::Signed(editor).into(), document, Recommendation::Accept)?;
+		let reviewer: T::AccountId = account("reviewer", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), document, pseudonym.clone(), reviewer.clone(), salt(0));
+
+		assert_eq!(DisclosedIdentities::<T>::get(document, pseudonym), Some(reviewer));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn declare_affiliations() {
+		let caller: T::AccountId = whitelisted_caller();
+		let declared = affiliations::<T>(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), declared.clone().into_inner());
+
+		assert_eq!(Affiliations::<T>::get(caller), declared);
+	}
+
+	#[benchmark]
+	fn declare_conflict() {
+		let caller: T::AccountId = whitelisted_caller();
+		let with: T::AccountId = account("author", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), with.clone());
+
+		assert!(Conflicts::<T>::contains_key(caller, with));
+	}
+
+	#[benchmark]
+	fn request_review() {
+		let document = T::BenchmarkHelper::registered_document();
+		let author = T::Documents::authors_of(&document).pop().expect("has authors");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(author.clone()), document);
+
+		assert_eq!(ReviewRequests::<T>::get(document), Some(author));
+	}
+
+	impl_benchmark_test_suite!(ReviewsPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,1283 @@
+//! # Reviews Pallet
+//!
+//! Tracks the peer review of manuscripts on chain.
+//!
+//! ## Overview
+//!
+//! A review is about a submission, identified by the content hash of a final manuscript in the
+//! registry (see [`Config::Documents`]). It proceeds in three steps:
+//!
+//! 1. **Assignment.** An editor, i.e. an account passing [`Config::EditorOrigin`],
+//!    [`assign_reviewer`](Pallet::assign_reviewer)s to the submission. The first assignment makes
+//!    the signer the submission's editor and sets the deadline for reviews,
+//!    [`Config::ReviewPeriod`] blocks later.
+//! 2. **Submission.** Each assigned reviewer [`submit_review`](Pallet::submit_review)s before the
+//!    deadline: the hash of the review report, kept off chain, and a [`Recommendation`]. The
+//!    submission's [`Tally`] aggregates the recommendations; once every assigned reviewer has
+//!    reported, its status moves from [`ReviewStatus::AwaitingReviews`] to
+//!    [`ReviewStatus::Reviewed`].
+//! 3. **Decision.** Once all reviews are in, or the deadline has passed with at least one review,
+//!    the editor [`decide`](Pallet::decide)s on the submission.
+//!
+//! Every step emits an event, so that authors and readers can follow the process.
+//!
+//! ## Venue Policies
+//!
+//! A manuscript submitted to a venue (see [`Config::Venues`]) is reviewed under the policy the
+//! venue had at submission:
+//!
+//! - only members of the venue's editorial board may open its review ([`Error::NotBoardMember`]);
+//! - its review is identified under [`ReviewBlinding::Open`] and blind otherwise
+//!   ([`Error::BlindingMismatch`]);
+//! - it gets no more reviewers than the policy names ([`Error::TooManyReviewers`]);
+//! - and the editor may accept it, with or without minor revisions, only if the share of reviews
+//!   recommending so reaches the policy's acceptance threshold
+//!   ([`Error::BelowAcceptanceThreshold`]).
+//!
+//! Manuscripts not submitted to any venue are reviewed without these constraints, but only once
+//! one of their authors has [`request_review`](Pallet::request_review)ed
+//! ([`Error::ReviewNotRequested`]): an opened review keeps a manuscript from being submitted to a
+//! venue, which editors must not do against the authors' will.
+//!
+//! ## Conflicts of Interest
+//!
+//! Reviewers assigned under their own accounts must not be conflicted with any author of the
+//! submission. An assignment fails, with an error naming the kind of conflict, if the reviewer:
+//!
+//! - is an author of the submission ([`Error::ReviewerIsAuthor`]);
+//! - shares an affiliation with an author, as declared with
+//!   [`declare_affiliations`](Pallet::declare_affiliations) ([`Error::SharedAffiliation`]);
+//! - is listed with an author on a final document of the registry, among the first
+//!   [`Config::MaxCoAuthoredDocuments`] documents listing the reviewer ([`Error::CoAuthorship`]);
+//! - or an author has declared a conflict with the other, with
+//!   [`declare_conflict`](Pallet::declare_conflict) ([`Error::DeclaredConflict`]).
+//!
+//! Pseudonymous reviewers cannot be checked on chain, so editors vet them before assigning them.
+//!
+//! ## Blind Review
+//!
+//! An editor can instead [`open_review`](Pallet::open_review) a submission with a [`Blinding`]
+//! that hides who the reviewers are:
+//!
+//! 1. **Assignment.** The editor derives a fresh key pair for each assignment, e.g. by hard
+//!    derivation from a venue secret with the submission's content hash and an assignment index as
+//!    junctions, hands it to the reviewer off chain and
+//!    [`assign_pseudonym`](Pallet::assign_pseudonym)s its account together with a commitment to the
+//!    reviewer's identity. No event or storage item names the reviewer.
+//! 2. **Sealing.** Before the deadline, the reviewer [`seal_review`](Pallet::seal_review)s with an
+//!    unsigned transaction authenticated by the pseudonymous key, committing to their review with
+//!    [`Pallet::seal_of`] without revealing it to the other reviewers.
+//! 3. **Unsealing.** Once every pseudonym has sealed, or the deadline has passed, reviews are
+//!    [`unseal_review`](Pallet::unseal_review)ed and counted as above. The editor may decide
+//!    without every review only [`Config::RevealPeriod`] blocks after the deadline.
+//! 4. **Disclosure.** With [`Blinding::DiscloseAfterDecision`], anyone holding the opening of an
+//!    identity commitment can [`disclose_identity`](Pallet::disclose_identity) once the editor has
+//!    decided; with [`Blinding::NeverDisclose`], identities stay off chain.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`assign_reviewer`](Pallet::assign_reviewer) - Assign a reviewer to a submission.
+//! - [`submit_review`](Pallet::submit_review) - Submit a review of a submission.
+//! - [`decide`](Pallet::decide) - Record the editor's decision on a submission.
+//! - [`open_review`](Pallet::open_review) - Open the review of a submission with a blinding.
+//! - [`assign_pseudonym`](Pallet::assign_pseudonym) - Assign a reviewer under a pseudonym.
+//! - [`seal_review`](Pallet::seal_review) - Seal a blind review.
+//! - [`unseal_review`](Pallet::unseal_review) - Unseal a blind review.
+//! - [`disclose_identity`](Pallet::disclose_identity) - Disclose a blind reviewer's identity.
+//! - [`declare_affiliations`](Pallet::declare_affiliations) - Declare the signer's affiliations.
+//! - [`declare_conflict`](Pallet::declare_conflict) - Declare a conflict with another account.
+//! - [`request_review`](Pallet::request_review) - Request the review of a manuscript outside any
+//!   venue.
+//!
+//! Run `cargo doc --package pallet-reviews --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+pub use speem_primitives::{DocumentAuthors, InstitutionId, ReviewBlinding, ReviewPolicy};
+use speem_primitives::{ReviewInspect, VenueSubmissions};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*, traits::Contains, CloneNoBound, EqNoBound, PartialEqNoBound,
+		RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{Hash, IdentifyAccount, Saturating, Verify, Zero},
+		PerThing,
+	};
+	use sp_std::prelude::*;
+
+	/// The salt blinding seals and identity commitments.
+	pub type Salt = [u8; 32];
+
+	/// The context signed along with a seal, so that signatures cannot be replayed elsewhere.
+	pub const SEAL_CONTEXT: &[u8] = b"speem/reviews/seal";
+
+	/// A reviewer's recommendation, from the most to the least favourable.
+	#[derive(
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		PartialOrd,
+		Ord,
+		RuntimeDebug,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum Recommendation {
+		/// Accept the submission as it is.
+		Accept,
+		/// Accept the submission after minor revisions.
+		MinorRevision,
+		/// Reconsider the submission after major revisions.
+		MajorRevision,
+		/// Reject the submission.
+		Reject,
+	}
+
+	/// The number of reviews making each recommendation.
+	#[derive(
+		Clone, Default, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct Tally {
+		/// Reviews recommending acceptance.
+		pub accept: u32,
+		/// Reviews recommending minor revisions.
+		pub minor_revision: u32,
+		/// Reviews recommending major revisions.
+		pub major_revision: u32,
+		/// Reviews recommending rejection.
+		pub reject: u32,
+	}
+
+	impl Tally {
+		/// Count a review making `recommendation`.
+		pub fn add(&mut self, recommendation: Recommendation) {
+			let count = match recommendation {
+				Recommendation::Accept => &mut self.accept,
+				Recommendation::MinorRevision => &mut self.minor_revision,
+				Recommendation::MajorRevision => &mut self.major_revision,
+				Recommendation::Reject => &mut self.reject,
+			};
+			*count = count.saturating_add(1);
+		}
+
+		/// The total number of reviews counted.
+		pub fn total(&self) -> u32 {
+			self.accept
+				.saturating_add(self.minor_revision)
+				.saturating_add(self.major_revision)
+				.saturating_add(self.reject)
+		}
+	}
+
+	/// Where the review of a submission stands.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum ReviewStatus {
+		/// Some assigned reviewers have not submitted their review yet.
+		AwaitingReviews,
+		/// Every assigned reviewer has submitted a review.
+		Reviewed,
+		/// The editor has decided on the submission.
+		Decided(Recommendation),
+	}
+
+	/// Whether reviewers act under their own accounts, and if not whether their identities are ever
+	/// disclosed.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum Blinding {
+		/// Reviewers are assigned and submit their reviews under their own accounts.
+		Identified,
+		/// Reviewers act under pseudonyms, and their identities can be disclosed once the editor
+		/// has decided on the submission.
+		DiscloseAfterDecision,
+		/// Reviewers act under pseudonyms that are never linked to their identities on chain.
+		NeverDisclose,
+	}
+
+	impl Blinding {
+		/// Whether reviewers act under pseudonyms.
+		pub fn is_blind(&self) -> bool {
+			*self != Blinding::Identified
+		}
+	}
+
+	/// The review of a submission.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Submission<T: Config> {
+		/// The editor handling the submission.
+		pub editor: T::AccountId,
+		/// Whether reviewers act under pseudonyms.
+		pub blinding: Blinding,
+		/// The last block in which reviews are accepted, sealed in blind reviews.
+		pub deadline: BlockNumberFor<T>,
+		/// The reviewers assigned to the submission, or their pseudonyms in blind reviews.
+		pub reviewers: BoundedVec<T::AccountId, T::MaxReviewers>,
+		/// The number of sealed reviews, in blind reviews.
+		pub sealed: u32,
+		/// The recommendations of the reviews submitted, or unsealed, so far.
+		pub tally: Tally,
+		/// Where the review stands.
+		pub status: ReviewStatus,
+	}
+
+	/// A submitted review.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Review<T: Config> {
+		/// The hash of the review report, which is kept off chain.
+		pub review_hash: T::Hash,
+		/// The reviewer's recommendation.
+		pub recommendation: Recommendation,
+		/// The block in which the review was submitted, or unsealed in blind reviews.
+		pub submitted_at: BlockNumberFor<T>,
+	}
+
+	/// Provides benchmarks with state that this pallet does not control.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Hash, AccountId, Public, Signature> {
+		/// Make sure a document with as many authors as the registry allows is final and return
+		/// its content hash.
+		fn registered_document() -> Hash;
+		/// Make sure `count` further documents listing `author`, each with as many authors as the
+		/// registry allows, are final.
+		fn co_authored_documents(author: &AccountId, count: u32);
+		/// Generate a fresh pseudonymous key pair and return its public key.
+		fn pseudonym() -> Public;
+		/// Sign `message` with the pseudonymous key `public`.
+		fn sign(public: &Public, message: &[u8]) -> Signature;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin of editors, yielding the editor's account.
+		type EditorOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+		/// The registry of documents that can be submitted for review, and of their authors.
+		type Documents: Contains<Self::Hash> + DocumentAuthors<Self::AccountId, Self::Hash>;
+		/// The venues manuscripts are submitted to, whose policies bind their review.
+		type Venues: VenueSubmissions<Self::AccountId, Self::Hash>;
+		/// A signature made with a pseudonymous key, verifiable on chain.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+		/// The public key of a pseudonymous key pair, identified on chain by its account.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+		/// The number of blocks reviewers have to submit their reviews after the first assignment.
+		#[pallet::constant]
+		type ReviewPeriod: Get<BlockNumberFor<Self>>;
+		/// The number of blocks after the deadline during which blind reviewers can still unseal
+		/// their reviews before the editor may decide without them.
+		#[pallet::constant]
+		type RevealPeriod: Get<BlockNumberFor<Self>>;
+		/// The maximum number of reviewers assigned to a submission.
+		#[pallet::constant]
+		type MaxReviewers: Get<u32>;
+		/// The maximum number of affiliations an account can declare.
+		#[pallet::constant]
+		type MaxAffiliations: Get<u32>;
+		/// The maximum number of the documents listing a reviewer that are searched for the authors
+		/// of a submission.
+		#[pallet::constant]
+		type MaxCoAuthoredDocuments: Get<u32>;
+		/// The priority of the unsigned transactions sealing and unsealing blind reviews.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// Helper for benchmarks to obtain a document the registry contains and pseudonymous keys.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::Hash,
+			Self::AccountId,
+			Self::SigningPublicKey,
+			Self::OffchainSignature,
+		>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Submissions under review, keyed by content hash.
+	#[pallet::storage]
+	pub type Submissions<T: Config> = StorageMap<_, Identity, T::Hash, Submission<T>, OptionQuery>;
+
+	/// Submitted reviews, keyed by the submission's content hash and then by reviewer, or by
+	/// pseudonym in blind reviews.
+	#[pallet::storage]
+	pub type Reviews<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::Hash,
+		Blake2_128Concat,
+		T::AccountId,
+		Review<T>,
+		OptionQuery,
+	>;
+
+	/// The seals of blind reviews that have not been unsealed yet, keyed by the submission's
+	/// content hash and then by pseudonym.
+	#[pallet::storage]
+	pub type Seals<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::Hash,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Hash,
+		OptionQuery,
+	>;
+
+	/// The commitments binding each pseudonym to the identity of the reviewer behind it, keyed by
+	/// the submission's content hash and then by pseudonym.
+	#[pallet::storage]
+	pub type IdentityCommitments<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::Hash,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Hash,
+		OptionQuery,
+	>;
+
+	/// The disclosed identities of blind reviewers, keyed by the submission's content hash and
+	/// then by pseudonym.
+	#[pallet::storage]
+	pub type DisclosedIdentities<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::Hash,
+		Blake2_128Concat,
+		T::AccountId,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// The institutions each account has declared to be affiliated with.
+	#[pallet::storage]
+	pub type Affiliations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<InstitutionId, T::MaxAffiliations>,
+		ValueQuery,
+	>;
+
+	/// The author who requested the review of each manuscript outside any venue, keyed by content
+	/// hash.
+	#[pallet::storage]
+	pub type ReviewRequests<T: Config> =
+		StorageMap<_, Identity, T::Hash, T::AccountId, OptionQuery>;
+
+	/// Conflicts of interest, keyed by the declaring account and then by the account it is
+	/// conflicted with.
+	#[pallet::storage]
+	pub type Conflicts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An editor has opened the review of a submission.
+		ReviewOpened {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+			/// The editor handling it.
+			editor: T::AccountId,
+			/// Whether reviewers act under pseudonyms.
+			blinding: Blinding,
+			/// The last block in which reviews are accepted.
+			deadline: BlockNumberFor<T>,
+		},
+		/// A reviewer has been assigned to a submission.
+		ReviewerAssigned {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+			/// The assigned reviewer, or their pseudonym in blind reviews.
+			reviewer: T::AccountId,
+			/// The last block in which the review is accepted.
+			deadline: BlockNumberFor<T>,
+		},
+		/// A blind reviewer has sealed their review.
+		ReviewSealed {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+			/// The reviewer's pseudonym.
+			pseudonym: T::AccountId,
+		},
+		/// A reviewer has submitted a review, or unsealed it in blind reviews.
+		ReviewSubmitted {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+			/// The reviewer, or their pseudonym in blind reviews.
+			reviewer: T::AccountId,
+			/// The hash of the review report.
+			review_hash: T::Hash,
+			/// The reviewer's recommendation.
+			recommendation: Recommendation,
+		},
+		/// Every assigned reviewer has submitted a review.
+		ReviewsCompleted {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+			/// The recommendations of the reviews.
+			tally: Tally,
+		},
+		/// The editor has decided on a submission.
+		Decided {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+			/// The decision.
+			decision: Recommendation,
+		},
+		/// The identity of a blind reviewer has been disclosed.
+		IdentityDisclosed {
+			/// The content hash of the submission.
+			content_hash: T::Hash,
+			/// The reviewer's pseudonym.
+			pseudonym: T::AccountId,
+			/// The reviewer behind it.
+			reviewer: T::AccountId,
+		},
+		/// An account has declared its affiliations.
+		AffiliationsDeclared {
+			/// The declaring account.
+			who: T::AccountId,
+			/// The institutions it is affiliated with.
+			affiliations: BoundedVec<InstitutionId, T::MaxAffiliations>,
+		},
+		/// An account has declared a conflict of interest with another.
+		ConflictDeclared {
+			/// The declaring account.
+			who: T::AccountId,
+			/// The account it is conflicted with.
+			with: T::AccountId,
+		},
+		/// An author has requested the review of a manuscript outside any venue.
+		ReviewRequested {
+			/// The content hash of the manuscript.
+			content_hash: T::Hash,
+			/// The requesting author.
+			author: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The document is not a final entry of the registry.
+		UnregisteredDocument,
+		/// The signer is not the editor of the submission.
+		NotEditor,
+		/// The editor cannot review a submission they handle.
+		EditorCannotReview,
+		/// The reviewer is already assigned to the submission.
+		AlreadyAssigned,
+		/// The submission already has as many reviewers as [`Config::MaxReviewers`] or its
+		/// venue's policy allow.
+		TooManyReviewers,
+		/// No submission is under review with the given content hash.
+		UnknownSubmission,
+		/// The signer is not assigned to the submission.
+		NotAssigned,
+		/// The signer has already submitted a review.
+		AlreadyReviewed,
+		/// The deadline for reviews has passed.
+		DeadlinePassed,
+		/// Not every review is in and the deadline has not passed yet.
+		ReviewsPending,
+		/// No review has been submitted.
+		NoReviews,
+		/// The editor has already decided on the submission.
+		AlreadyDecided,
+		/// The review of the submission has already been opened.
+		AlreadyOpen,
+		/// Reviewers of the submission act under pseudonyms.
+		BlindReview,
+		/// Reviewers of the submission act under their own accounts.
+		NotBlind,
+		/// The signature was not made with the pseudonymous key.
+		BadSignature,
+		/// The pseudonym has already sealed a review.
+		AlreadySealed,
+		/// The pseudonym has not sealed a review.
+		NotSealed,
+		/// The review does not match its seal.
+		SealMismatch,
+		/// Reviews cannot be unsealed before they are all sealed or the deadline has passed.
+		SealingOngoing,
+		/// The identity does not match the commitment made at assignment.
+		IdentityMismatch,
+		/// The identity has already been disclosed.
+		AlreadyDisclosed,
+		/// Identities of reviewers of the submission are never disclosed.
+		UndisclosedReview,
+		/// The editor has not decided on the submission yet.
+		NotDecided,
+		/// The reviewer is an author of the submission.
+		ReviewerIsAuthor,
+		/// The reviewer shares an affiliation with an author of the submission.
+		SharedAffiliation,
+		/// The reviewer is listed with an author of the submission on a registered document.
+		CoAuthorship,
+		/// The reviewer or an author of the submission has declared a conflict with the other.
+		DeclaredConflict,
+		/// More affiliations were declared than [`Config::MaxAffiliations`] allows.
+		TooManyAffiliations,
+		/// An account cannot declare a conflict with itself.
+		ConflictWithSelf,
+		/// The conflict has already been declared.
+		ConflictAlreadyDeclared,
+		/// The editor is not on the editorial board of the venue the submission was made to.
+		NotBoardMember,
+		/// The blinding does not match the review policy of the submission's venue.
+		BlindingMismatch,
+		/// Too few reviews recommend acceptance for the threshold of the submission's venue.
+		BelowAcceptanceThreshold,
+		/// The manuscript was not submitted to a venue and no author has requested its review.
+		ReviewNotRequested,
+		/// The signer is not an author of the manuscript.
+		NotAuthor,
+		/// The review of the manuscript has already been requested.
+		AlreadyRequested,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Assign a reviewer to a submission, under their own account.
+		///
+		/// The origin must pass [`Config::EditorOrigin`]. The first assignment to a submission
+		/// that has not been opened with [`open_review`](Pallet::open_review) opens an identified
+		/// review, makes the signer its editor and starts the review period; later assignments
+		/// must come from the same editor and share the deadline. The reviewer must not be
+		/// conflicted with any author of the submission.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::BlindReview`] if reviewers of the submission act under pseudonyms.
+		/// - [`Error::NotBoardMember`] or [`Error::BlindingMismatch`] if the first assignment would
+		///   open a review the policy of the submission's venue does not allow.
+		/// - [`Error::ReviewNotRequested`] if the first assignment would open the review of a
+		///   manuscript submitted to no venue, without its authors' request.
+		/// - [`Error::NotEditor`] if another editor handles the submission.
+		/// - [`Error::AlreadyDecided`] if the editor has already decided on it.
+		/// - [`Error::DeadlinePassed`] if the deadline for reviews has passed.
+		/// - [`Error::EditorCannotReview`] if `reviewer` is the editor.
+		/// - [`Error::AlreadyAssigned`] if `reviewer` is already assigned.
+		/// - [`Error::TooManyReviewers`] if the submission has no room for another reviewer.
+		/// - [`Error::ReviewerIsAuthor`], [`Error::SharedAffiliation`], [`Error::CoAuthorship`] or
+		///   [`Error::DeclaredConflict`] if `reviewer` is conflicted with an author.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::assign_reviewer())]
+		pub fn assign_reviewer(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			reviewer: T::AccountId,
+		) -> DispatchResult {
+			let editor = T::EditorOrigin::ensure_origin(origin)?;
+
+			ensure!(T::Documents::contains(&content_hash), Error::<T>::UnregisteredDocument);
+			let authors = T::Documents::authors_of(&content_hash);
+			let submission = match Submissions::<T>::get(content_hash) {
+				Some(submission) => {
+					ensure!(!submission.blinding.is_blind(), Error::<T>::BlindReview);
+					submission
+				},
+				None => Self::new_submission(&content_hash, editor.clone(), Blinding::Identified)?,
+			};
+			Self::ensure_unconflicted(&reviewer, &authors)?;
+
+			Self::add_reviewer(content_hash, submission, &editor, reviewer)
+		}
+
+		/// Submit a review of a submission, under the reviewer's own account.
+		///
+		/// The origin must be signed by a reviewer assigned to the submission, before its
+		/// deadline. Each reviewer submits once.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownSubmission`] if no submission is under review with `content_hash`.
+		/// - [`Error::BlindReview`] if reviewers of the submission act under pseudonyms.
+		/// - [`Error::NotAssigned`] if the signer is not assigned to it.
+		/// - [`Error::AlreadyReviewed`] if the signer has already submitted a review.
+		/// - [`Error::AlreadyDecided`] if the editor has already decided on it.
+		/// - [`Error::DeadlinePassed`] if the deadline for reviews has passed.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::submit_review())]
+		pub fn submit_review(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			review_hash: T::Hash,
+			recommendation: Recommendation,
+		) -> DispatchResult {
+			let reviewer = ensure_signed(origin)?;
+
+			let submission =
+				Submissions::<T>::get(content_hash).ok_or(Error::<T>::UnknownSubmission)?;
+			ensure!(!submission.blinding.is_blind(), Error::<T>::BlindReview);
+			ensure!(submission.reviewers.contains(&reviewer), Error::<T>::NotAssigned);
+			ensure!(
+				!Reviews::<T>::contains_key(content_hash, &reviewer),
+				Error::<T>::AlreadyReviewed
+			);
+			ensure!(
+				!matches!(submission.status, ReviewStatus::Decided(_)),
+				Error::<T>::AlreadyDecided
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= submission.deadline,
+				Error::<T>::DeadlinePassed
+			);
+
+			Self::record_review(content_hash, submission, reviewer, review_hash, recommendation);
+			Ok(())
+		}
+
+		/// Record the editor's decision on a submission.
+		///
+		/// The origin must be signed by the submission's editor, once every assigned reviewer has
+		/// submitted a review or, failing that, once the deadline has passed with at least one
+		/// review. In blind reviews, the editor must also wait for [`Config::RevealPeriod`] after
+		/// the deadline to decide without every review. A submission made to a venue can only be
+		/// accepted, with or without minor revisions, if the share of reviews recommending so
+		/// reaches the acceptance threshold of the venue's policy.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownSubmission`] if no submission is under review with `content_hash`.
+		/// - [`Error::NotEditor`] if the signer is not its editor.
+		/// - [`Error::AlreadyDecided`] if the editor has already decided on it.
+		/// - [`Error::NoReviews`] if no review has been submitted.
+		/// - [`Error::ReviewsPending`] if reviews are missing and the deadline has not passed.
+		/// - [`Error::BelowAcceptanceThreshold`] if `decision` accepts the submission against the
+		///   threshold of its venue's policy.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::decide())]
+		pub fn decide(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			decision: Recommendation,
+		) -> DispatchResult {
+			let editor = ensure_signed(origin)?;
+
+			let mut submission =
+				Submissions::<T>::get(content_hash).ok_or(Error::<T>::UnknownSubmission)?;
+			ensure!(submission.editor == editor, Error::<T>::NotEditor);
+			match submission.status {
+				ReviewStatus::Decided(_) => return Err(Error::<T>::AlreadyDecided.into()),
+				ReviewStatus::Reviewed => {},
+				ReviewStatus::AwaitingReviews => {
+					ensure!(submission.tally.total() > 0, Error::<T>::NoReviews);
+					let grace = if submission.blinding.is_blind() {
+						T::RevealPeriod::get()
+					} else {
+						Zero::zero()
+					};
+					ensure!(
+						frame_system::Pallet::<T>::block_number() >
+							submission.deadline.saturating_add(grace),
+						Error::<T>::ReviewsPending
+					);
+				},
+			}
+
+			if decision <= Recommendation::MinorRevision {
+				if let Some(policy) = T::Venues::policy_of(&content_hash) {
+					let tally = &submission.tally;
+					let favourable = tally.accept.saturating_add(tally.minor_revision);
+					ensure!(
+						favourable >= policy.acceptance_threshold.mul_ceil(tally.total()),
+						Error::<T>::BelowAcceptanceThreshold
+					);
+				}
+			}
+
+			submission.status = ReviewStatus::Decided(decision);
+			Submissions::<T>::insert(content_hash, submission);
+
+			Self::deposit_event(Event::Decided { content_hash, decision });
+			Ok(())
+		}
+
+		/// Open the review of a submission with the given blinding.
+		///
+		/// The origin must pass [`Config::EditorOrigin`] and becomes the submission's editor. The
+		/// review period starts now. If the submission was made to a venue, the editor must sit on
+		/// its board and `blinding` must match its policy; otherwise, one of its authors must have
+		/// [`request_review`](Pallet::request_review)ed.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::AlreadyOpen`] if the review of the submission has already been opened.
+		/// - [`Error::NotBoardMember`] if the signer is not on the board of the submission's venue.
+		/// - [`Error::BlindingMismatch`] if `blinding` does not match the venue's policy.
+		/// - [`Error::ReviewNotRequested`] if the submission was made to no venue and no author has
+		///   requested its review.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::open_review())]
+		pub fn open_review(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			blinding: Blinding,
+		) -> DispatchResult {
+			let editor = T::EditorOrigin::ensure_origin(origin)?;
+
+			ensure!(T::Documents::contains(&content_hash), Error::<T>::UnregisteredDocument);
+			ensure!(!Submissions::<T>::contains_key(content_hash), Error::<T>::AlreadyOpen);
+
+			let submission = Self::new_submission(&content_hash, editor.clone(), blinding)?;
+			let deadline = submission.deadline;
+			Submissions::<T>::insert(content_hash, submission);
+
+			Self::deposit_event(Event::ReviewOpened { content_hash, editor, blinding, deadline });
+			Ok(())
+		}
+
+		/// Assign a reviewer to a blind review, under a pseudonym.
+		///
+		/// The origin must be signed by the submission's editor. `pseudonym` is the account of a
+		/// key pair derived for this assignment only and handed to the reviewer off chain;
+		/// `identity_commitment` is [`identity_commitment_of`](Pallet::identity_commitment_of) the
+		/// reviewer's account, the pseudonym and a secret salt, so that the identity can later be
+		/// disclosed if the submission's blinding allows it.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownSubmission`] if the review of the submission has not been opened.
+		/// - [`Error::NotBlind`] if reviewers of the submission act under their own accounts.
+		/// - [`Error::NotEditor`], [`Error::AlreadyDecided`], [`Error::DeadlinePassed`],
+		///   [`Error::AlreadyAssigned`] or [`Error::TooManyReviewers`] as for
+		///   [`assign_reviewer`](Pallet::assign_reviewer).
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::assign_pseudonym())]
+		pub fn assign_pseudonym(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			pseudonym: T::AccountId,
+			identity_commitment: T::Hash,
+		) -> DispatchResult {
+			let editor = ensure_signed(origin)?;
+
+			let submission =
+				Submissions::<T>::get(content_hash).ok_or(Error::<T>::UnknownSubmission)?;
+			ensure!(submission.blinding.is_blind(), Error::<T>::NotBlind);
+
+			Self::add_reviewer(content_hash, submission, &editor, pseudonym.clone())?;
+			IdentityCommitments::<T>::insert(content_hash, pseudonym, identity_commitment);
+			Ok(())
+		}
+
+		/// Seal a blind review before the deadline.
+		///
+		/// This is an unsigned transaction, so that no account is exposed as its signer; it is
+		/// authenticated by `signature`, made with the pseudonymous key over
+		/// [`seal_payload`](Pallet::seal_payload). `seal` is [`seal_of`](Pallet::seal_of) the
+		/// review, which stays hidden from the other reviewers until it is unsealed.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownSubmission`] if no submission is under review with `content_hash`.
+		/// - [`Error::NotBlind`] if reviewers of the submission act under their own accounts.
+		/// - [`Error::NotAssigned`] if `pseudonym` is not assigned to it.
+		/// - [`Error::AlreadySealed`] if `pseudonym` has already sealed a review.
+		/// - [`Error::AlreadyDecided`] if the editor has already decided on it.
+		/// - [`Error::DeadlinePassed`] if the deadline for reviews has passed.
+		/// - [`Error::BadSignature`] if `signature` was not made with the pseudonymous key.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::seal_review())]
+		pub fn seal_review(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			pseudonym: T::AccountId,
+			seal: T::Hash,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let mut submission = Self::check_seal(&content_hash, &pseudonym, &seal, &signature)?;
+			Seals::<T>::insert(content_hash, &pseudonym, seal);
+			submission.sealed.saturating_inc();
+			Submissions::<T>::insert(content_hash, submission);
+
+			Self::deposit_event(Event::ReviewSealed { content_hash, pseudonym });
+			Ok(())
+		}
+
+		/// Unseal a blind review.
+		///
+		/// This is an unsigned transaction, which anyone knowing the opening of the seal may
+		/// submit. Reviews are unsealed once every assigned reviewer has sealed theirs or the
+		/// deadline has passed, and until the editor decides.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownSubmission`] if no submission is under review with `content_hash`.
+		/// - [`Error::NotSealed`] if `pseudonym` has no sealed review.
+		/// - [`Error::SealMismatch`] if the review does not match its seal.
+		/// - [`Error::AlreadyDecided`] if the editor has already decided on the submission.
+		/// - [`Error::SealingOngoing`] if reviews are still being sealed.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::unseal_review())]
+		pub fn unseal_review(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			pseudonym: T::AccountId,
+			review_hash: T::Hash,
+			recommendation: Recommendation,
+			salt: Salt,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let submission =
+				Self::check_unseal(&content_hash, &pseudonym, &review_hash, recommendation, &salt)?;
+			Seals::<T>::remove(content_hash, &pseudonym);
+
+			Self::record_review(content_hash, submission, pseudonym, review_hash, recommendation);
+			Ok(())
+		}
+
+		/// Disclose the identity of a blind reviewer once the editor has decided.
+		///
+		/// The origin must be signed; anyone knowing the salt of the identity commitment, usually
+		/// the editor or the reviewer, may disclose it if the submission's blinding allows it.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownSubmission`] if no submission is under review with `content_hash`.
+		/// - [`Error::UndisclosedReview`] if identities are never disclosed for the submission.
+		/// - [`Error::NotBlind`] if reviewers of the submission act under their own accounts.
+		/// - [`Error::NotDecided`] if the editor has not decided on it yet.
+		/// - [`Error::NotAssigned`] if `pseudonym` is not assigned to it.
+		/// - [`Error::AlreadyDisclosed`] if the identity has already been disclosed.
+		/// - [`Error::IdentityMismatch`] if `reviewer` and `salt` do not match the commitment.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::disclose_identity())]
+		pub fn disclose_identity(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			pseudonym: T::AccountId,
+			reviewer: T::AccountId,
+			salt: Salt,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let submission =
+				Submissions::<T>::get(content_hash).ok_or(Error::<T>::UnknownSubmission)?;
+			match submission.blinding {
+				Blinding::Identified => return Err(Error::<T>::NotBlind.into()),
+				Blinding::NeverDisclose => return Err(Error::<T>::UndisclosedReview.into()),
+				Blinding::DiscloseAfterDecision => {},
+			}
+			ensure!(matches!(submission.status, ReviewStatus::Decided(_)), Error::<T>::NotDecided);
+			let commitment = IdentityCommitments::<T>::get(content_hash, &pseudonym)
+				.ok_or(Error::<T>::NotAssigned)?;
+			ensure!(
+				!DisclosedIdentities::<T>::contains_key(content_hash, &pseudonym),
+				Error::<T>::AlreadyDisclosed
+			);
+			ensure!(
+				Self::identity_commitment_of(&reviewer, &pseudonym, &salt) == commitment,
+				Error::<T>::IdentityMismatch
+			);
+
+			DisclosedIdentities::<T>::insert(content_hash, &pseudonym, &reviewer);
+
+			Self::deposit_event(Event::IdentityDisclosed { content_hash, pseudonym, reviewer });
+			Ok(())
+		}
+
+		/// Declare the institutions the signer is affiliated with, replacing any earlier
+		/// declaration.
+		///
+		/// The origin must be signed. Reviewers cannot be assigned to submissions by authors
+		/// sharing one of their affiliations.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::TooManyAffiliations`] if more than [`Config::MaxAffiliations`] are declared.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::declare_affiliations())]
+		pub fn declare_affiliations(
+			origin: OriginFor<T>,
+			affiliations: Vec<InstitutionId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let affiliations: BoundedVec<_, T::MaxAffiliations> =
+				affiliations.try_into().map_err(|_| Error::<T>::TooManyAffiliations)?;
+			Affiliations::<T>::insert(&who, &affiliations);
+
+			Self::deposit_event(Event::AffiliationsDeclared { who, affiliations });
+			Ok(())
+		}
+
+		/// Declare a conflict of interest between the signer and `with`.
+		///
+		/// The origin must be signed. Neither account can then be assigned to review a submission
+		/// the other authored.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::ConflictWithSelf`] if `with` is the signer.
+		/// - [`Error::ConflictAlreadyDeclared`] if the signer has already declared the conflict.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::declare_conflict())]
+		pub fn declare_conflict(origin: OriginFor<T>, with: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(who != with, Error::<T>::ConflictWithSelf);
+			ensure!(
+				!Conflicts::<T>::contains_key(&who, &with),
+				Error::<T>::ConflictAlreadyDeclared
+			);
+			Conflicts::<T>::insert(&who, &with, ());
+
+			Self::deposit_event(Event::ConflictDeclared { who, with });
+			Ok(())
+		}
+
+		/// Request the review of a manuscript that is not submitted to a venue.
+		///
+		/// The origin must be signed by an author of the manuscript. Editors can then open its
+		/// review, after which it can no longer be submitted to a venue.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NotAuthor`] if the signer is not one of its authors.
+		/// - [`Error::AlreadyRequested`] if its review has already been requested.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::request_review())]
+		pub fn request_review(origin: OriginFor<T>, content_hash: T::Hash) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+
+			ensure!(T::Documents::contains(&content_hash), Error::<T>::UnregisteredDocument);
+			ensure!(
+				T::Documents::authors_of(&content_hash).contains(&author),
+				Error::<T>::NotAuthor
+			);
+			ensure!(!ReviewRequests::<T>::contains_key(content_hash), Error::<T>::AlreadyRequested);
+			ReviewRequests::<T>::insert(content_hash, &author);
+
+			Self::deposit_event(Event::ReviewRequested { content_hash, author });
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (content_hash, pseudonym, step) = match call {
+				Call::seal_review { content_hash, pseudonym, seal, signature } => {
+					Self::check_seal(content_hash, pseudonym, seal, signature)
+						.map_err(Self::invalid_transaction)?;
+					(content_hash, pseudonym, "seal")
+				},
+				Call::unseal_review {
+					content_hash,
+					pseudonym,
+					review_hash,
+					recommendation,
+					salt,
+				} => {
+					Self::check_unseal(content_hash, pseudonym, review_hash, *recommendation, salt)
+						.map_err(Self::invalid_transaction)?;
+					(content_hash, pseudonym, "unseal")
+				},
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			// Each pseudonym seals and unseals once per submission.
+			ValidTransaction::with_tag_prefix("Reviews")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((step, content_hash, pseudonym))
+				.longevity(64)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> ReviewInspect<T::Hash> for Pallet<T> {
+		fn is_under_review(content_hash: &T::Hash) -> bool {
+			Submissions::<T>::contains_key(content_hash)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The seal of a review with the given report hash and recommendation under `salt`.
+		pub fn seal_of(
+			review_hash: &T::Hash,
+			recommendation: Recommendation,
+			salt: &Salt,
+		) -> T::Hash {
+			T::Hashing::hash_of(&(review_hash, recommendation, salt))
+		}
+
+		/// The commitment binding `pseudonym` to `reviewer` under `salt`.
+		pub fn identity_commitment_of(
+			reviewer: &T::AccountId,
+			pseudonym: &T::AccountId,
+			salt: &Salt,
+		) -> T::Hash {
+			T::Hashing::hash_of(&(reviewer, pseudonym, salt))
+		}
+
+		/// The message a pseudonymous key signs to seal a review.
+		pub fn seal_payload(content_hash: &T::Hash, seal: &T::Hash) -> Vec<u8> {
+			(SEAL_CONTEXT, content_hash, seal).encode()
+		}
+
+		/// A new submission of `content_hash` handled by `editor`, whose review period starts now,
+		/// if the policy of the venue it was submitted to allows it or, failing a venue, its
+		/// authors requested it.
+		fn new_submission(
+			content_hash: &T::Hash,
+			editor: T::AccountId,
+			blinding: Blinding,
+		) -> Result<Submission<T>, Error<T>> {
+			if let Some(policy) = T::Venues::policy_of(content_hash) {
+				ensure!(
+					T::Venues::is_board_member(content_hash, &editor),
+					Error::<T>::NotBoardMember
+				);
+				ensure!(
+					blinding.is_blind() != (policy.blinding == ReviewBlinding::Open),
+					Error::<T>::BlindingMismatch
+				);
+			} else {
+				ensure!(
+					ReviewRequests::<T>::contains_key(content_hash),
+					Error::<T>::ReviewNotRequested
+				);
+			}
+			Ok(Submission {
+				editor,
+				blinding,
+				deadline: frame_system::Pallet::<T>::block_number()
+					.saturating_add(T::ReviewPeriod::get()),
+				reviewers: BoundedVec::new(),
+				sealed: 0,
+				tally: Tally::default(),
+				status: ReviewStatus::AwaitingReviews,
+			})
+		}
+
+		/// Check that `reviewer` has no conflict of interest with any of `authors`.
+		fn ensure_unconflicted(
+			reviewer: &T::AccountId,
+			authors: &[T::AccountId],
+		) -> DispatchResult {
+			ensure!(!authors.contains(reviewer), Error::<T>::ReviewerIsAuthor);
+
+			let affiliations = Affiliations::<T>::get(reviewer);
+			let co_authors =
+				T::Documents::co_authors_of(reviewer, T::MaxCoAuthoredDocuments::get());
+			for author in authors {
+				if !affiliations.is_empty() {
+					ensure!(
+						!Affiliations::<T>::get(author)
+							.iter()
+							.any(|affiliation| affiliations.contains(affiliation)),
+						Error::<T>::SharedAffiliation
+					);
+				}
+				ensure!(!co_authors.contains(author), Error::<T>::CoAuthorship);
+				ensure!(
+					!Conflicts::<T>::contains_key(reviewer, author) &&
+						!Conflicts::<T>::contains_key(author, reviewer),
+					Error::<T>::DeclaredConflict
+				);
+			}
+			Ok(())
+		}
+
+		/// Assign `reviewer` to `submission` on behalf of `editor`.
+		fn add_reviewer(
+			content_hash: T::Hash,
+			mut submission: Submission<T>,
+			editor: &T::AccountId,
+			reviewer: T::AccountId,
+		) -> DispatchResult {
+			ensure!(submission.editor == *editor, Error::<T>::NotEditor);
+			ensure!(
+				!matches!(submission.status, ReviewStatus::Decided(_)),
+				Error::<T>::AlreadyDecided
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= submission.deadline,
+				Error::<T>::DeadlinePassed
+			);
+			ensure!(reviewer != *editor, Error::<T>::EditorCannotReview);
+			ensure!(!submission.reviewers.contains(&reviewer), Error::<T>::AlreadyAssigned);
+			if let Some(policy) = T::Venues::policy_of(&content_hash) {
+				ensure!(
+					(submission.reviewers.len() as u32) < policy.reviewers,
+					Error::<T>::TooManyReviewers
+				);
+			}
+
+			submission
+				.reviewers
+				.try_push(reviewer.clone())
+				.map_err(|_| Error::<T>::TooManyReviewers)?;
+			submission.status = ReviewStatus::AwaitingReviews;
+			let deadline = submission.deadline;
+			Submissions::<T>::insert(content_hash, submission);
+
+			Self::deposit_event(Event::ReviewerAssigned { content_hash, reviewer, deadline });
+			Ok(())
+		}
+
+		/// Store a review, count its recommendation and complete the review once every assigned
+		/// reviewer has submitted theirs.
+		fn record_review(
+			content_hash: T::Hash,
+			mut submission: Submission<T>,
+			reviewer: T::AccountId,
+			review_hash: T::Hash,
+			recommendation: Recommendation,
+		) {
+			let now = frame_system::Pallet::<T>::block_number();
+			Reviews::<T>::insert(
+				content_hash,
+				&reviewer,
+				Review { review_hash, recommendation, submitted_at: now },
+			);
+			submission.tally.add(recommendation);
+			Self::deposit_event(Event::ReviewSubmitted {
+				content_hash,
+				reviewer,
+				review_hash,
+				recommendation,
+			});
+
+			if submission.tally.total() as usize == submission.reviewers.len() {
+				submission.status = ReviewStatus::Reviewed;
+				Self::deposit_event(Event::ReviewsCompleted {
+					content_hash,
+					tally: submission.tally.clone(),
+				});
+			}
+			Submissions::<T>::insert(content_hash, submission);
+		}
+
+		/// Check that `pseudonym` may seal a review of `content_hash` with `seal`.
+		fn check_seal(
+			content_hash: &T::Hash,
+			pseudonym: &T::AccountId,
+			seal: &T::Hash,
+			signature: &T::OffchainSignature,
+		) -> Result<Submission<T>, Error<T>> {
+			let submission =
+				Submissions::<T>::get(content_hash).ok_or(Error::<T>::UnknownSubmission)?;
+			ensure!(submission.blinding.is_blind(), Error::<T>::NotBlind);
+			ensure!(submission.reviewers.contains(pseudonym), Error::<T>::NotAssigned);
+			ensure!(
+				!Seals::<T>::contains_key(content_hash, pseudonym) &&
+					!Reviews::<T>::contains_key(content_hash, pseudonym),
+				Error::<T>::AlreadySealed
+			);
+			ensure!(
+				!matches!(submission.status, ReviewStatus::Decided(_)),
+				Error::<T>::AlreadyDecided
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= submission.deadline,
+				Error::<T>::DeadlinePassed
+			);
+			ensure!(
+				signature.verify(&Self::seal_payload(content_hash, seal)[..], pseudonym),
+				Error::<T>::BadSignature
+			);
+			Ok(submission)
+		}
+
+		/// Check that a review of `content_hash` by `pseudonym` may be unsealed with the given
+		/// opening.
+		fn check_unseal(
+			content_hash: &T::Hash,
+			pseudonym: &T::AccountId,
+			review_hash: &T::Hash,
+			recommendation: Recommendation,
+			salt: &Salt,
+		) -> Result<Submission<T>, Error<T>> {
+			let submission =
+				Submissions::<T>::get(content_hash).ok_or(Error::<T>::UnknownSubmission)?;
+			let seal = Seals::<T>::get(content_hash, pseudonym).ok_or(Error::<T>::NotSealed)?;
+			ensure!(
+				Self::seal_of(review_hash, recommendation, salt) == seal,
+				Error::<T>::SealMismatch
+			);
+			ensure!(
+				!matches!(submission.status, ReviewStatus::Decided(_)),
+				Error::<T>::AlreadyDecided
+			);
+			ensure!(
+				submission.sealed as usize == submission.reviewers.len() ||
+					frame_system::Pallet::<T>::block_number() > submission.deadline,
+				Error::<T>::SealingOngoing
+			);
+			Ok(submission)
+		}
+
+		fn invalid_transaction(error: Error<T>) -> TransactionValidityError {
+			match error {
+				Error::<T>::BadSignature | Error::<T>::SealMismatch => InvalidTransaction::BadProof,
+				Error::<T>::DeadlinePassed |
+				Error::<T>::AlreadyDecided |
+				Error::<T>::AlreadySealed |
+				Error::<T>::NotSealed => InvalidTransaction::Stale,
+				Error::<T>::SealingOngoing => InvalidTransaction::Future,
+				_ => InvalidTransaction::Call,
+			}
+			.into()
+		}
+	}
+}
@@ -0,0 +1,182 @@
+use crate as pallet_reviews;
+use crate::{DocumentAuthors, ReviewPolicy};
+use frame_support::{
+	derive_impl,
+	traits::{ConstU16, ConstU32, ConstU64, Contains},
+};
+use frame_system::EnsureSigned;
+use sp_core::H256;
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use speem_primitives::VenueSubmissions;
+use std::{cell::RefCell, collections::BTreeMap};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Reviews: pallet_reviews,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+thread_local! {
+	static REGISTERED: RefCell<Vec<(H256, Vec<u64>)>> = RefCell::new(Vec::new());
+	static SUBMITTED: RefCell<BTreeMap<H256, (ReviewPolicy, Vec<u64>)>> =
+		RefCell::new(BTreeMap::new());
+	#[cfg(feature = "runtime-benchmarks")]
+	static PSEUDONYMS: RefCell<u64> = RefCell::new(100);
+}
+
+/// A stand-in for the manuscript registry.
+pub struct MockDocuments;
+
+impl MockDocuments {
+	/// Mark `document` as a final registry entry by `authors`.
+	pub fn register(document: H256, authors: Vec<u64>) {
+		REGISTERED.with(|registered| registered.borrow_mut().push((document, authors)));
+	}
+}
+
+impl Contains<H256> for MockDocuments {
+	fn contains(document: &H256) -> bool {
+		REGISTERED.with(|registered| registered.borrow().iter().any(|(hash, _)| hash == document))
+	}
+}
+
+impl DocumentAuthors<u64, H256> for MockDocuments {
+	fn authors_of(document: &H256) -> Vec<u64> {
+		REGISTERED.with(|registered| {
+			registered
+				.borrow()
+				.iter()
+				.find(|(hash, _)| hash == document)
+				.map(|(_, authors)| authors.clone())
+				.unwrap_or_default()
+		})
+	}
+
+	fn co_authors_of(author: &u64, max_documents: u32) -> Vec<u64> {
+		let mut co_authors = Vec::new();
+		REGISTERED.with(|registered| {
+			for (_, authors) in registered
+				.borrow()
+				.iter()
+				.filter(|(_, authors)| authors.contains(author))
+				.take(max_documents as usize)
+			{
+				co_authors.extend(authors.iter().filter(|other| *other != author));
+			}
+		});
+		co_authors
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<H256, u64, UintAuthorityId, TestSignature> for MockDocuments {
+	fn registered_document() -> H256 {
+		let document = H256::repeat_byte(2);
+		Self::register(document, (1_000..1_004).collect());
+		document
+	}
+
+	fn co_authored_documents(author: &u64, count: u32) {
+		use sp_runtime::traits::Hash;
+
+		for i in 0..count {
+			let document = BlakeTwo256::hash_of(&(author, i));
+			Self::register(document, vec![*author, 2_000 + u64::from(i)]);
+		}
+	}
+
+	fn pseudonym() -> UintAuthorityId {
+		PSEUDONYMS.with(|next| {
+			let mut next = next.borrow_mut();
+			*next += 1;
+			UintAuthorityId(*next)
+		})
+	}
+
+	fn sign(public: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(public.0, message.to_vec())
+	}
+}
+
+/// A stand-in for the venues manuscripts are submitted to.
+pub struct MockVenues;
+
+impl MockVenues {
+	/// Mark `document` as submitted under `policy` to a venue whose board is `board`.
+	pub fn submit(document: H256, policy: ReviewPolicy, board: Vec<u64>) {
+		SUBMITTED.with(|submitted| submitted.borrow_mut().insert(document, (policy, board)));
+	}
+}
+
+impl VenueSubmissions<u64, H256> for MockVenues {
+	fn policy_of(document: &H256) -> Option<ReviewPolicy> {
+		SUBMITTED.with(|submitted| submitted.borrow().get(document).map(|(policy, _)| *policy))
+	}
+
+	fn is_board_member(document: &H256, who: &u64) -> bool {
+		SUBMITTED.with(|submitted| {
+			submitted.borrow().get(document).map_or(false, |(_, board)| board.contains(who))
+		})
+	}
+}
+
+impl pallet_reviews::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type EditorOrigin = EnsureSigned<u64>;
+	type Documents = MockDocuments;
+	type Venues = MockVenues;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	type ReviewPeriod = ConstU64<10>;
+	type RevealPeriod = ConstU64<5>;
+	type MaxReviewers = ConstU32<3>;
+	type MaxAffiliations = ConstU32<2>;
+	type MaxCoAuthoredDocuments = ConstU32<2>;
+	type UnsignedPriority = ConstU64<100>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockDocuments;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	REGISTERED.with(|registered| registered.borrow_mut().clear());
+	SUBMITTED.with(|submitted| submitted.borrow_mut().clear());
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+}
@@ -0,0 +1,649 @@
+use crate::{
+	mock::*, Affiliations, Blinding, Call, Conflicts, DisclosedIdentities, Error, Event,
+	Recommendation, ReviewBlinding, ReviewPolicy, ReviewRequests, ReviewStatus,
+	Reviews as ReviewsStorage, Salt, Seals, Submissions, Tally,
+};
+use frame_support::{
+	assert_noop, assert_ok, dispatch::DispatchResult, pallet_prelude::*, sp_runtime::Percent,
+};
+use sp_core::H256;
+use sp_runtime::testing::TestSignature;
+
+const DOCUMENT: H256 = H256::repeat_byte(2);
+const OTHER_DOCUMENT: H256 = H256::repeat_byte(3);
+const EDITOR: u64 = 1;
+const AUTHORS: [u64; 2] = [10, 11];
+
+/// Register the document, with its authors requesting its review.
+fn registered() {
+	MockDocuments::register(DOCUMENT, AUTHORS.to_vec());
+	assert_ok!(Reviews::request_review(RuntimeOrigin::signed(AUTHORS[0]), DOCUMENT));
+}
+
+fn assign(reviewer: u64) -> DispatchResult {
+	Reviews::assign_reviewer(RuntimeOrigin::signed(EDITOR), DOCUMENT, reviewer)
+}
+
+fn review(reviewer: u64, recommendation: Recommendation) -> DispatchResult {
+	Reviews::submit_review(
+		RuntimeOrigin::signed(reviewer),
+		DOCUMENT,
+		H256::repeat_byte(reviewer as u8),
+		recommendation,
+	)
+}
+
+fn open_blind(blinding: Blinding) {
+	registered();
+	assert_ok!(Reviews::open_review(RuntimeOrigin::signed(EDITOR), DOCUMENT, blinding));
+}
+
+/// The salt of a pseudonym's identity commitment and seal.
+fn salt(pseudonym: u64) -> Salt {
+	[pseudonym as u8; 32]
+}
+
+fn assign_pseudonym(pseudonym: u64, reviewer: u64) -> DispatchResult {
+	let commitment = Reviews::identity_commitment_of(&reviewer, &pseudonym, &salt(pseudonym));
+	Reviews::assign_pseudonym(RuntimeOrigin::signed(EDITOR), DOCUMENT, pseudonym, commitment)
+}
+
+fn seal(pseudonym: u64, recommendation: Recommendation) -> H256 {
+	Reviews::seal_of(&H256::repeat_byte(pseudonym as u8), recommendation, &salt(pseudonym))
+}
+
+fn seal_review(pseudonym: u64, recommendation: Recommendation) -> DispatchResult {
+	let seal = seal(pseudonym, recommendation);
+	let signature = TestSignature(pseudonym, Reviews::seal_payload(&DOCUMENT, &seal));
+	Reviews::seal_review(RuntimeOrigin::none(), DOCUMENT, pseudonym, seal, signature)
+}
+
+fn unseal_review(pseudonym: u64, recommendation: Recommendation) -> DispatchResult {
+	Reviews::unseal_review(
+		RuntimeOrigin::none(),
+		DOCUMENT,
+		pseudonym,
+		H256::repeat_byte(pseudonym as u8),
+		recommendation,
+		salt(pseudonym),
+	)
+}
+
+fn status() -> Option<ReviewStatus> {
+	Submissions::<Test>::get(DOCUMENT).map(|submission| submission.status)
+}
+
+#[test]
+fn assign_reviewer_opens_review() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+		registered();
+
+		assert_ok!(assign(2));
+		System::assert_last_event(
+			Event::ReviewerAssigned { content_hash: DOCUMENT, reviewer: 2, deadline: 11 }.into(),
+		);
+
+		System::set_block_number(5);
+		assert_ok!(assign(3));
+		let submission = Submissions::<Test>::get(DOCUMENT).expect("review was opened");
+		assert_eq!(submission.editor, EDITOR);
+		assert_eq!(submission.deadline, 11);
+		assert_eq!(submission.reviewers.into_inner(), vec![2, 3]);
+		assert_eq!(submission.tally, Tally::default());
+		assert_eq!(submission.status, ReviewStatus::AwaitingReviews);
+	});
+}
+
+#[test]
+fn invalid_assignments_are_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(assign(2), Error::<Test>::UnregisteredDocument);
+
+		registered();
+		assert_noop!(assign(EDITOR), Error::<Test>::EditorCannotReview);
+		assert_ok!(assign(2));
+		assert_noop!(assign(2), Error::<Test>::AlreadyAssigned);
+		assert_noop!(
+			Reviews::assign_reviewer(RuntimeOrigin::signed(5), DOCUMENT, 3),
+			Error::<Test>::NotEditor
+		);
+
+		assert_ok!(assign(3));
+		assert_ok!(assign(4));
+		assert_noop!(assign(5), Error::<Test>::TooManyReviewers);
+	});
+}
+
+#[test]
+fn reviewers_cannot_be_assigned_after_the_deadline() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		registered();
+		assert_ok!(assign(2));
+
+		System::set_block_number(12);
+		assert_noop!(assign(3), Error::<Test>::DeadlinePassed);
+	});
+}
+
+#[test]
+fn reviews_are_tallied() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		registered();
+		assert_ok!(assign(2));
+		assert_ok!(assign(3));
+
+		assert_ok!(review(2, Recommendation::MinorRevision));
+		System::assert_last_event(
+			Event::ReviewSubmitted {
+				content_hash: DOCUMENT,
+				reviewer: 2,
+				review_hash: H256::repeat_byte(2),
+				recommendation: Recommendation::MinorRevision,
+			}
+			.into(),
+		);
+		let stored = ReviewsStorage::<Test>::get(DOCUMENT, 2).expect("review was stored");
+		assert_eq!(stored.recommendation, Recommendation::MinorRevision);
+		assert_eq!(stored.submitted_at, 1);
+		assert_eq!(status(), Some(ReviewStatus::AwaitingReviews));
+
+		assert_ok!(review(3, Recommendation::Reject));
+		let tally = Tally { minor_revision: 1, reject: 1, ..Default::default() };
+		System::assert_last_event(
+			Event::ReviewsCompleted { content_hash: DOCUMENT, tally: tally.clone() }.into(),
+		);
+		assert_eq!(Submissions::<Test>::get(DOCUMENT).unwrap().tally, tally);
+		assert_eq!(status(), Some(ReviewStatus::Reviewed));
+
+		// A late assignment reopens the review.
+		assert_ok!(assign(4));
+		assert_eq!(status(), Some(ReviewStatus::AwaitingReviews));
+	});
+}
+
+#[test]
+fn invalid_reviews_are_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(review(2, Recommendation::Accept), Error::<Test>::UnknownSubmission);
+
+		registered();
+		assert_ok!(assign(2));
+		assert_ok!(assign(3));
+		assert_noop!(review(4, Recommendation::Accept), Error::<Test>::NotAssigned);
+		assert_ok!(review(2, Recommendation::Accept));
+		assert_noop!(review(2, Recommendation::Reject), Error::<Test>::AlreadyReviewed);
+
+		System::set_block_number(12);
+		assert_noop!(review(3, Recommendation::Accept), Error::<Test>::DeadlinePassed);
+	});
+}
+
+#[test]
+fn editor_decides_once_reviews_are_in() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		registered();
+		assert_ok!(assign(2));
+		assert_ok!(review(2, Recommendation::Accept));
+
+		assert_noop!(
+			Reviews::decide(RuntimeOrigin::signed(2), DOCUMENT, Recommendation::Accept),
+			Error::<Test>::NotEditor
+		);
+		assert_ok!(Reviews::decide(
+			RuntimeOrigin::signed(EDITOR),
+			DOCUMENT,
+			Recommendation::Accept
+		));
+		System::assert_last_event(
+			Event::Decided { content_hash: DOCUMENT, decision: Recommendation::Accept }.into(),
+		);
+		assert_eq!(status(), Some(ReviewStatus::Decided(Recommendation::Accept)));
+
+		assert_noop!(
+			Reviews::decide(RuntimeOrigin::signed(EDITOR), DOCUMENT, Recommendation::Reject),
+			Error::<Test>::AlreadyDecided
+		);
+		assert_noop!(assign(3), Error::<Test>::AlreadyDecided);
+	});
+}
+
+#[test]
+fn editor_decides_on_partial_reviews_after_the_deadline() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		registered();
+		assert_ok!(assign(2));
+		assert_ok!(assign(3));
+
+		let decide = || {
+			Reviews::decide(RuntimeOrigin::signed(EDITOR), DOCUMENT, Recommendation::MajorRevision)
+		};
+		System::set_block_number(12);
+		assert_noop!(decide(), Error::<Test>::NoReviews);
+
+		System::set_block_number(11);
+		assert_ok!(review(2, Recommendation::MajorRevision));
+		assert_noop!(decide(), Error::<Test>::ReviewsPending);
+
+		System::set_block_number(12);
+		assert_ok!(decide());
+		assert_eq!(status(), Some(ReviewStatus::Decided(Recommendation::MajorRevision)));
+	});
+}
+
+#[test]
+fn venue_policies_bind_the_opening_of_reviews() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		registered();
+		let policy = ReviewPolicy {
+			blinding: ReviewBlinding::DoubleBlind,
+			reviewers: 2,
+			acceptance_threshold: Percent::from_percent(50),
+		};
+		MockVenues::submit(DOCUMENT, policy, vec![EDITOR]);
+
+		assert_noop!(
+			Reviews::open_review(RuntimeOrigin::signed(5), DOCUMENT, Blinding::NeverDisclose),
+			Error::<Test>::NotBoardMember
+		);
+		assert_noop!(
+			Reviews::assign_reviewer(RuntimeOrigin::signed(5), DOCUMENT, 2),
+			Error::<Test>::NotBoardMember
+		);
+		assert_noop!(assign(2), Error::<Test>::BlindingMismatch);
+		assert_noop!(
+			Reviews::open_review(RuntimeOrigin::signed(EDITOR), DOCUMENT, Blinding::Identified),
+			Error::<Test>::BlindingMismatch
+		);
+
+		assert_ok!(Reviews::open_review(
+			RuntimeOrigin::signed(EDITOR),
+			DOCUMENT,
+			Blinding::NeverDisclose
+		));
+		assert_ok!(assign_pseudonym(20, 2));
+		assert_ok!(assign_pseudonym(21, 3));
+		// The policy caps the reviewers below `MaxReviewers`.
+		assert_noop!(assign_pseudonym(22, 4), Error::<Test>::TooManyReviewers);
+	});
+}
+
+#[test]
+fn venue_policies_set_the_acceptance_threshold() {
+	for (threshold, accepted) in [(67, false), (60, true)] {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			registered();
+			let policy = ReviewPolicy {
+				blinding: ReviewBlinding::Open,
+				reviewers: 3,
+				acceptance_threshold: Percent::from_percent(threshold),
+			};
+			MockVenues::submit(DOCUMENT, policy, vec![EDITOR]);
+			assert_ok!(assign(2));
+			assert_ok!(assign(3));
+			assert_ok!(assign(4));
+			assert_ok!(review(2, Recommendation::Accept));
+			assert_ok!(review(3, Recommendation::MinorRevision));
+			assert_ok!(review(4, Recommendation::Reject));
+
+			let decide =
+				|decision| Reviews::decide(RuntimeOrigin::signed(EDITOR), DOCUMENT, decision);
+			if accepted {
+				assert_ok!(decide(Recommendation::Accept));
+			} else {
+				// Two favourable reviews out of three fall short of 67%.
+				assert_noop!(
+					decide(Recommendation::Accept),
+					Error::<Test>::BelowAcceptanceThreshold
+				);
+				assert_noop!(
+					decide(Recommendation::MinorRevision),
+					Error::<Test>::BelowAcceptanceThreshold
+				);
+				assert_ok!(decide(Recommendation::MajorRevision));
+			}
+		});
+	}
+}
+
+#[test]
+fn blind_reviews_use_pseudonyms() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		open_blind(Blinding::DiscloseAfterDecision);
+		System::assert_last_event(
+			Event::ReviewOpened {
+				content_hash: DOCUMENT,
+				editor: EDITOR,
+				blinding: Blinding::DiscloseAfterDecision,
+				deadline: 11,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Reviews::open_review(RuntimeOrigin::signed(EDITOR), DOCUMENT, Blinding::Identified),
+			Error::<Test>::AlreadyOpen
+		);
+
+		assert_noop!(assign(2), Error::<Test>::BlindReview);
+		assert_ok!(assign_pseudonym(20, 2));
+		System::assert_last_event(
+			Event::ReviewerAssigned { content_hash: DOCUMENT, reviewer: 20, deadline: 11 }.into(),
+		);
+		assert_noop!(review(20, Recommendation::Accept), Error::<Test>::BlindReview);
+	});
+}
+
+#[test]
+fn identified_reviews_cannot_be_sealed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		registered();
+		assert_ok!(assign(2));
+
+		assert_noop!(assign_pseudonym(20, 3), Error::<Test>::NotBlind);
+		assert_noop!(seal_review(2, Recommendation::Accept), Error::<Test>::NotBlind);
+	});
+}
+
+#[test]
+fn sealed_reviews_are_unsealed_once_all_are_sealed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		open_blind(Blinding::NeverDisclose);
+		assert_ok!(assign_pseudonym(20, 2));
+		assert_ok!(assign_pseudonym(21, 3));
+
+		let forged = seal(20, Recommendation::Accept);
+		assert_noop!(
+			Reviews::seal_review(
+				RuntimeOrigin::none(),
+				DOCUMENT,
+				20,
+				forged,
+				TestSignature(21, Reviews::seal_payload(&DOCUMENT, &forged)),
+			),
+			Error::<Test>::BadSignature
+		);
+		assert_noop!(seal_review(22, Recommendation::Accept), Error::<Test>::NotAssigned);
+
+		assert_ok!(seal_review(20, Recommendation::Accept));
+		System::assert_last_event(
+			Event::ReviewSealed { content_hash: DOCUMENT, pseudonym: 20 }.into(),
+		);
+		assert_noop!(seal_review(20, Recommendation::Reject), Error::<Test>::AlreadySealed);
+		assert_noop!(unseal_review(20, Recommendation::Accept), Error::<Test>::SealingOngoing);
+
+		assert_ok!(seal_review(21, Recommendation::Reject));
+		assert_noop!(unseal_review(20, Recommendation::Reject), Error::<Test>::SealMismatch);
+		assert_ok!(unseal_review(20, Recommendation::Accept));
+		System::assert_last_event(
+			Event::ReviewSubmitted {
+				content_hash: DOCUMENT,
+				reviewer: 20,
+				review_hash: H256::repeat_byte(20),
+				recommendation: Recommendation::Accept,
+			}
+			.into(),
+		);
+		assert!(!Seals::<Test>::contains_key(DOCUMENT, 20));
+		assert_noop!(unseal_review(20, Recommendation::Accept), Error::<Test>::NotSealed);
+		assert_noop!(seal_review(20, Recommendation::Accept), Error::<Test>::AlreadySealed);
+
+		assert_ok!(unseal_review(21, Recommendation::Reject));
+		System::assert_last_event(
+			Event::ReviewsCompleted {
+				content_hash: DOCUMENT,
+				tally: Tally { accept: 1, reject: 1, ..Default::default() },
+			}
+			.into(),
+		);
+		assert_eq!(status(), Some(ReviewStatus::Reviewed));
+	});
+}
+
+#[test]
+fn blind_decisions_on_partial_reviews_wait_for_the_reveal_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		open_blind(Blinding::NeverDisclose);
+		assert_ok!(assign_pseudonym(20, 2));
+		assert_ok!(assign_pseudonym(21, 3));
+		assert_ok!(seal_review(20, Recommendation::MinorRevision));
+
+		System::set_block_number(12);
+		assert_noop!(seal_review(21, Recommendation::Accept), Error::<Test>::DeadlinePassed);
+		assert_ok!(unseal_review(20, Recommendation::MinorRevision));
+
+		let decide = || {
+			Reviews::decide(RuntimeOrigin::signed(EDITOR), DOCUMENT, Recommendation::MinorRevision)
+		};
+		System::set_block_number(16);
+		assert_noop!(decide(), Error::<Test>::ReviewsPending);
+		System::set_block_number(17);
+		assert_ok!(decide());
+	});
+}
+
+#[test]
+fn identities_are_disclosed_after_the_decision() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		open_blind(Blinding::DiscloseAfterDecision);
+		assert_ok!(assign_pseudonym(20, 2));
+		assert_ok!(seal_review(20, Recommendation::Accept));
+		assert_ok!(unseal_review(20, Recommendation::Accept));
+
+		let disclose = |reviewer: u64| {
+			Reviews::disclose_identity(RuntimeOrigin::signed(7), DOCUMENT, 20, reviewer, salt(20))
+		};
+		assert_noop!(disclose(2), Error::<Test>::NotDecided);
+		assert_ok!(Reviews::decide(
+			RuntimeOrigin::signed(EDITOR),
+			DOCUMENT,
+			Recommendation::Accept
+		));
+
+		assert_noop!(disclose(3), Error::<Test>::IdentityMismatch);
+		assert_ok!(disclose(2));
+		System::assert_last_event(
+			Event::IdentityDisclosed { content_hash: DOCUMENT, pseudonym: 20, reviewer: 2 }.into(),
+		);
+		assert_eq!(DisclosedIdentities::<Test>::get(DOCUMENT, 20), Some(2));
+		assert_noop!(disclose(2), Error::<Test>::AlreadyDisclosed);
+	});
+}
+
+#[test]
+fn undisclosed_identities_stay_hidden() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		open_blind(Blinding::NeverDisclose);
+		assert_ok!(assign_pseudonym(20, 2));
+		assert_ok!(seal_review(20, Recommendation::Accept));
+		assert_ok!(unseal_review(20, Recommendation::Accept));
+		assert_ok!(Reviews::decide(
+			RuntimeOrigin::signed(EDITOR),
+			DOCUMENT,
+			Recommendation::Accept
+		));
+
+		assert_noop!(
+			Reviews::disclose_identity(RuntimeOrigin::signed(EDITOR), DOCUMENT, 20, 2, salt(20)),
+			Error::<Test>::UndisclosedReview
+		);
+	});
+}
+
+#[test]
+fn unsigned_seals_are_validated() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		open_blind(Blinding::NeverDisclose);
+		assert_ok!(assign_pseudonym(20, 2));
+
+		let validate = |call: &Call<Test>| {
+			<Reviews as ValidateUnsigned>::validate_unsigned(TransactionSource::External, call)
+		};
+		let seal = seal(20, Recommendation::Accept);
+		let payload = Reviews::seal_payload(&DOCUMENT, &seal);
+		let call = |signer: u64| Call::seal_review {
+			content_hash: DOCUMENT,
+			pseudonym: 20,
+			seal,
+			signature: TestSignature(signer, payload.clone()),
+		};
+
+		assert_eq!(validate(&call(21)), InvalidTransaction::BadProof.into());
+		let valid = validate(&call(20)).expect("signed by the pseudonym");
+		assert_eq!(valid.priority, 100);
+		assert_eq!(valid.provides, vec![("Reviews", ("seal", DOCUMENT, 20u64)).encode()]);
+
+		System::set_block_number(12);
+		assert_eq!(validate(&call(20)), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn conflicted_reviewers_are_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		registered();
+		assert_noop!(assign(10), Error::<Test>::ReviewerIsAuthor);
+
+		assert_ok!(Reviews::declare_affiliations(RuntimeOrigin::signed(10), vec![7]));
+		assert_ok!(Reviews::declare_affiliations(RuntimeOrigin::signed(2), vec![8, 7]));
+		assert_noop!(assign(2), Error::<Test>::SharedAffiliation);
+
+		// Reviewer 3 co-authored another document with author 11.
+		MockDocuments::register(OTHER_DOCUMENT, vec![3, 11]);
+		assert_noop!(assign(3), Error::<Test>::CoAuthorship);
+
+		assert_ok!(Reviews::declare_conflict(RuntimeOrigin::signed(4), 10));
+		assert_noop!(assign(4), Error::<Test>::DeclaredConflict);
+		assert_ok!(Reviews::declare_conflict(RuntimeOrigin::signed(11), 5));
+		assert_noop!(assign(5), Error::<Test>::DeclaredConflict);
+
+		assert_ok!(Reviews::declare_affiliations(RuntimeOrigin::signed(6), vec![8]));
+		assert_ok!(assign(6));
+	});
+}
+
+#[test]
+fn co_authorship_is_read_from_the_registry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		registered();
+
+		// The co-authored document has never been reviewed by this pallet.
+		MockDocuments::register(OTHER_DOCUMENT, vec![20, 10]);
+		assert_noop!(assign(20), Error::<Test>::CoAuthorship);
+		assert!(Submissions::<Test>::get(OTHER_DOCUMENT).is_none());
+
+		// Only the first two documents listing a reviewer are searched.
+		MockDocuments::register(H256::repeat_byte(4), vec![21, 30]);
+		MockDocuments::register(H256::repeat_byte(5), vec![21, 31]);
+		MockDocuments::register(H256::repeat_byte(6), vec![21, 11]);
+		assert_ok!(assign(21));
+	});
+}
+
+#[test]
+fn affiliations_are_declared() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Reviews::declare_affiliations(RuntimeOrigin::signed(2), vec![1, 2, 3]),
+			Error::<Test>::TooManyAffiliations
+		);
+
+		assert_ok!(Reviews::declare_affiliations(RuntimeOrigin::signed(2), vec![1, 2]));
+		assert_ok!(Reviews::declare_affiliations(RuntimeOrigin::signed(2), vec![3]));
+		System::assert_last_event(
+			Event::AffiliationsDeclared { who: 2, affiliations: vec![3].try_into().unwrap() }
+				.into(),
+		);
+		assert_eq!(Affiliations::<Test>::get(2).into_inner(), vec![3]);
+	});
+}
+
+#[test]
+fn conflicts_are_declared_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Reviews::declare_conflict(RuntimeOrigin::signed(2), 2),
+			Error::<Test>::ConflictWithSelf
+		);
+
+		assert_ok!(Reviews::declare_conflict(RuntimeOrigin::signed(2), 3));
+		System::assert_last_event(Event::ConflictDeclared { who: 2, with: 3 }.into());
+		assert!(Conflicts::<Test>::contains_key(2, 3));
+		assert!(!Conflicts::<Test>::contains_key(3, 2));
+		assert_noop!(
+			Reviews::declare_conflict(RuntimeOrigin::signed(2), 3),
+			Error::<Test>::ConflictAlreadyDeclared
+		);
+	});
+}
+
+#[test]
+fn manuscripts_outside_venues_are_reviewed_on_request() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MockDocuments::register(DOCUMENT, AUTHORS.to_vec());
+
+		// Opening a review would keep the authors from submitting the manuscript to a venue.
+		assert_noop!(assign(2), Error::<Test>::ReviewNotRequested);
+		assert_noop!(
+			Reviews::open_review(RuntimeOrigin::signed(EDITOR), DOCUMENT, Blinding::NeverDisclose),
+			Error::<Test>::ReviewNotRequested
+		);
+
+		assert_noop!(
+			Reviews::request_review(RuntimeOrigin::signed(EDITOR), DOCUMENT),
+			Error::<Test>::NotAuthor
+		);
+		assert_noop!(
+			Reviews::request_review(RuntimeOrigin::signed(AUTHORS[1]), OTHER_DOCUMENT),
+			Error::<Test>::UnregisteredDocument
+		);
+		assert_ok!(Reviews::request_review(RuntimeOrigin::signed(AUTHORS[1]), DOCUMENT));
+		System::assert_last_event(
+			Event::ReviewRequested { content_hash: DOCUMENT, author: AUTHORS[1] }.into(),
+		);
+		assert_eq!(ReviewRequests::<Test>::get(DOCUMENT), Some(AUTHORS[1]));
+		assert_noop!(
+			Reviews::request_review(RuntimeOrigin::signed(AUTHORS[0]), DOCUMENT),
+			Error::<Test>::AlreadyRequested
+		);
+
+		assert_ok!(assign(2));
+	});
+}
+
+#[test]
+fn manuscripts_submitted_to_venues_need_no_request() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MockDocuments::register(DOCUMENT, AUTHORS.to_vec());
+		let policy = ReviewPolicy {
+			blinding: ReviewBlinding::Open,
+			reviewers: 2,
+			acceptance_threshold: Percent::from_percent(50),
+		};
+		MockVenues::submit(DOCUMENT, policy, vec![EDITOR]);
+
+		assert_ok!(assign(2));
+		assert_eq!(ReviewRequests::<Test>::get(DOCUMENT), None);
+	});
+}
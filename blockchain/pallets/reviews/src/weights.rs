@@ -0,0 +1,211 @@
+
+//! Weights for pallet_reviews
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_reviews` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_reviews.
+pub trait WeightInfo {
+	fn assign_reviewer() -> Weight;
+	fn submit_review() -> Weight;
+	fn decide() -> Weight;
+	fn open_review() -> Weight;
+	fn assign_pseudonym() -> Weight;
+	fn seal_review() -> Weight;
+	fn unseal_review() -> Weight;
+	fn disclose_identity() -> Weight;
+	fn declare_affiliations() -> Weight;
+	fn declare_conflict() -> Weight;
+	fn request_review() -> Weight;
+}
+
+/// Weights for pallet_reviews using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Manuscripts Manuscripts (r:129 w:0)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:129 w:0)
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews Affiliations (r:65 w:0)
+	/// Storage: Reviews Conflicts (r:128 w:0)
+	/// Storage: Reviews ReviewRequests (r:1 w:0)
+	/// Storage: Venues Submissions (r:1 w:0)
+	/// Storage: Venues Venues (r:1 w:0)
+	fn assign_reviewer() -> Weight {
+		Weight::from_parts(1_110_000_000, 1502171)
+			.saturating_add(T::DbWeight::get().reads(455_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews Reviews (r:1 w:1)
+	fn submit_review() -> Weight {
+		Weight::from_parts(22_000_000, 3717)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Venues Submissions (r:1 w:0)
+	fn decide() -> Weight {
+		Weight::from_parts(17_000_000, 3717)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews ReviewRequests (r:1 w:0)
+	/// Storage: Venues Submissions (r:1 w:0)
+	/// Storage: Venues Venues (r:1 w:0)
+	fn open_review() -> Weight {
+		Weight::from_parts(32_000_000, 8553)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews IdentityCommitments (r:0 w:1)
+	/// Storage: Venues Submissions (r:1 w:0)
+	fn assign_pseudonym() -> Weight {
+		Weight::from_parts(22_000_000, 3717)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews Seals (r:1 w:1)
+	/// Storage: Reviews Reviews (r:1 w:0)
+	fn seal_review() -> Weight {
+		Weight::from_parts(69_000_000, 3717)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews Seals (r:1 w:1)
+	/// Storage: Reviews Reviews (r:0 w:1)
+	fn unseal_review() -> Weight {
+		Weight::from_parts(28_000_000, 3717)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:0)
+	/// Storage: Reviews IdentityCommitments (r:1 w:0)
+	/// Storage: Reviews DisclosedIdentities (r:1 w:1)
+	fn disclose_identity() -> Weight {
+		Weight::from_parts(24_000_000, 3717)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reviews Affiliations (r:0 w:1)
+	fn declare_affiliations() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reviews Conflicts (r:1 w:1)
+	fn declare_conflict() -> Weight {
+		Weight::from_parts(14_000_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Reviews ReviewRequests (r:1 w:1)
+	fn request_review() -> Weight {
+		Weight::from_parts(20_000_000, 8553)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Manuscripts Manuscripts (r:129 w:0)
+	/// Storage: Manuscripts ManuscriptsByAuthor (r:129 w:0)
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews Affiliations (r:65 w:0)
+	/// Storage: Reviews Conflicts (r:128 w:0)
+	/// Storage: Reviews ReviewRequests (r:1 w:0)
+	/// Storage: Venues Submissions (r:1 w:0)
+	/// Storage: Venues Venues (r:1 w:0)
+	fn assign_reviewer() -> Weight {
+		Weight::from_parts(1_110_000_000, 1502171)
+			.saturating_add(RocksDbWeight::get().reads(455_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews Reviews (r:1 w:1)
+	fn submit_review() -> Weight {
+		Weight::from_parts(22_000_000, 3717)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Venues Submissions (r:1 w:0)
+	fn decide() -> Weight {
+		Weight::from_parts(17_000_000, 3717)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews ReviewRequests (r:1 w:0)
+	/// Storage: Venues Submissions (r:1 w:0)
+	/// Storage: Venues Venues (r:1 w:0)
+	fn open_review() -> Weight {
+		Weight::from_parts(32_000_000, 8553)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews IdentityCommitments (r:0 w:1)
+	/// Storage: Venues Submissions (r:1 w:0)
+	fn assign_pseudonym() -> Weight {
+		Weight::from_parts(22_000_000, 3717)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews Seals (r:1 w:1)
+	/// Storage: Reviews Reviews (r:1 w:0)
+	fn seal_review() -> Weight {
+		Weight::from_parts(69_000_000, 3717)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:1)
+	/// Storage: Reviews Seals (r:1 w:1)
+	/// Storage: Reviews Reviews (r:0 w:1)
+	fn unseal_review() -> Weight {
+		Weight::from_parts(28_000_000, 3717)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Reviews Submissions (r:1 w:0)
+	/// Storage: Reviews IdentityCommitments (r:1 w:0)
+	/// Storage: Reviews DisclosedIdentities (r:1 w:1)
+	fn disclose_identity() -> Weight {
+		Weight::from_parts(24_000_000, 3717)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reviews Affiliations (r:0 w:1)
+	fn declare_affiliations() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reviews Conflicts (r:1 w:1)
+	fn declare_conflict() -> Weight {
+		Weight::from_parts(14_000_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Reviews ReviewRequests (r:1 w:1)
+	fn request_review() -> Weight {
+		Weight::from_parts(20_000_000, 8553)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,216 @@
+//! Benchmarking setup for pallet-storage-proofs
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as StorageProofsPallet;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	sp_runtime::{
+		traits::{Hash, Saturating},
+		RuntimeAppPublic,
+	},
+	traits::Get,
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_std::vec;
+
+/// A registered document, whose registrant has committed to the blob it is stored as.
+fn committed_document<T: Config>() -> (T::Hash, BlobCommitment<T::Hash>) {
+	let (document, _) = T::BenchmarkHelper::registered_document();
+	let blob = BlobCommitment {
+		blob_hash: T::Hashing::hash_of(&document),
+		chunk_root: T::Hash::default(),
+		chunk_count: 1,
+	};
+	Blobs::<T>::insert(document, blob);
+	(document, blob)
+}
+
+/// Have a fresh active institution host `document`, returning its admin and the institution.
+fn hosting<T: Config>(
+	seed: u32,
+	document: T::Hash,
+) -> Result<(T::AccountId, InstitutionId), BenchmarkError> {
+	let admin: T::AccountId = account("admin", seed, 0);
+	let institution = T::Institutions::register_active_admin(&admin);
+	let blob = Blobs::<T>::get(document).ok_or(BenchmarkError::Weightless)?;
+	Pallet::<T>::host(
+		RawOrigin::Signed(admin.clone()).into(),
+		document,
+		blob.blob_hash,
+		blob.chunk_root,
+		blob.chunk_count,
+	)?;
+	Ok((admin, institution))
+}
+
+/// Open a challenge with identifier `challenge` against `institution` on `document`, due at
+/// `deadline`.
+fn open_challenge<T: Config>(
+	institution: InstitutionId,
+	challenge: ChallengeId,
+	document: T::Hash,
+	deadline: BlockNumberFor<T>,
+) {
+	Challenges::<T>::insert(
+		institution,
+		challenge,
+		Challenge {
+			content_hash: document,
+			blob_hash: T::Hash::default(),
+			chunk_root: T::Hash::default(),
+			chunk_count: 1,
+			chunk: 0,
+			deadline,
+		},
+	);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_prover() {
+		let caller: T::AccountId = whitelisted_caller();
+		let institution = T::Institutions::register_active_admin(&caller);
+		// Replacing a previous key is the costliest case.
+		let previous = T::ProverId::generate_pair(None);
+		Provers::<T>::insert(institution, &previous);
+		InstitutionOfProver::<T>::insert(&previous, institution);
+		let prover = T::ProverId::generate_pair(None);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), prover.clone());
+
+		assert_eq!(Provers::<T>::get(institution), Some(prover));
+		assert_eq!(InstitutionOfProver::<T>::get(previous), None);
+	}
+
+	#[benchmark]
+	fn host() {
+		let caller: T::AccountId = whitelisted_caller();
+		let institution = T::Institutions::register_active_admin(&caller);
+		let (document, blob) = committed_document::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), document, blob.blob_hash, blob.chunk_root, blob.chunk_count);
+
+		assert!(Hostings::<T>::contains_key(document, institution));
+	}
+
+	#[benchmark]
+	fn unhost() -> Result<(), BenchmarkError> {
+		let (document, _) = committed_document::<T>();
+		let (admin, institution) = hosting::<T>(0, document)?;
+		// Another claim is moved into the freed slot.
+		let (_, other) = hosting::<T>(1, document)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), document);
+
+		assert!(!Hostings::<T>::contains_key(document, institution));
+		assert_eq!(HostingSlots::<T>::get(0), Some((document, other)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn respond(p: Linear<0, 32>) -> Result<(), BenchmarkError> {
+		let (document, _) = committed_document::<T>();
+		let (_, institution) = hosting::<T>(0, document)?;
+		let prover = T::ProverId::generate_pair(None);
+		Provers::<T>::insert(institution, &prover);
+
+		// The first chunk of a blob of `2^(p - 1) + 1` chunks has `p` siblings on its path.
+		let chunk_count = if p == 0 { 1 } else { (1u32 << (p - 1)) + 1 };
+		let chunk = vec![0xff; merkle::CHUNK_SIZE];
+		let proof: Vec<T::Hash> = (0..p).map(|i| T::Hashing::hash_of(&i)).collect();
+		let chunk_root = merkle::root_from_proof::<T::Hashing>(chunk_count, 0, &chunk, &proof)
+			.expect("the proof fits the tree; qed");
+		let deadline =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::ResponseWindow::get());
+		Challenges::<T>::insert(
+			institution,
+			0,
+			Challenge {
+				content_hash: document,
+				blob_hash: T::Hash::default(),
+				chunk_root,
+				chunk_count,
+				chunk: 0,
+				deadline,
+			},
+		);
+		let signature = prover
+			.sign(&Pallet::<T>::response_payload(institution, 0, &chunk, &proof))
+			.ok_or(BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::None, institution, 0, chunk, proof, signature);
+
+		assert!(!Challenges::<T>::contains_key(institution, 0));
+		assert_eq!(Records::<T>::get(institution).answered, 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_challenges(
+		e: Linear<0, { T::ChallengesPerPeriod::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (document, _) = committed_document::<T>();
+		let (_, institution) = hosting::<T>(0, document)?;
+		let deadline = T::ResponseWindow::get();
+		let mut expiring = BoundedVec::<_, T::ChallengesPerPeriod>::new();
+		for challenge in 0..e as ChallengeId {
+			open_challenge::<T>(institution, challenge, document, deadline);
+			expiring.try_push((institution, challenge)).expect("at most the bound; qed");
+		}
+		Deadlines::<T>::insert(deadline, expiring);
+
+		#[block]
+		{
+			Pallet::<T>::expire_challenges(deadline);
+		}
+
+		assert_eq!(Records::<T>::get(institution).failed, e);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn issue_challenges() -> Result<(), BenchmarkError> {
+		// Every draw lands on the one claim, so every draw issues a challenge.
+		let (_, institution) = hosting::<T>(0, committed_document::<T>().0)?;
+		let now = T::ChallengePeriod::get();
+
+		#[block]
+		{
+			Pallet::<T>::issue_challenges(now, |_| true);
+		}
+
+		assert_eq!(Records::<T>::get(institution).issued, T::ChallengesPerPeriod::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn commit_blob() {
+		let (document, registrant) = T::BenchmarkHelper::registered_document();
+		let blob_hash = T::Hashing::hash_of(&document);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(registrant), document, blob_hash, T::Hash::default(), 1);
+
+		assert!(Blobs::<T>::contains_key(document));
+	}
+
+	#[benchmark]
+	fn note_author() {
+		#[block]
+		{
+			Pallet::<T>::note_author();
+		}
+	}
+
+	impl_benchmark_test_suite!(StorageProofsPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,1009 @@
+//! # Storage Proofs Pallet
+//!
+//! Checks that institutions claiming to host documents do, with proof-of-storage challenges
+//! answered by their nodes.
+//!
+//! ## Overview
+//!
+//! Only content hashes fit on chain, so the documents themselves are kept by the nodes of the
+//! institutions, as blobs of the node's blob store, which their authors encrypt. The registrant of
+//! a document (see [`Config::Documents`]) [`commit_blob`](Pallet::commit_blob)s to the blob it is
+//! stored as: the hash of the blob, the root of the Merkle tree over its chunks, as built by
+//! [`speem_primitives::merkle`], and their number. An admin of an active institution then declares
+//! that it [`host`](Pallet::host)s the document, naming the same blob, root and number of chunks,
+//! so that only the blob the registrant committed to answers the challenges. Documents whose
+//! registrant has not committed to a blob cannot be hosted. An institution holds at most
+//! [`Config::MaxHostingsPerInstitution`] of the [`Config::MaxHostings`] claims, so that no single
+//! one can take them all up.
+//!
+//! ### Challenges
+//!
+//! Every [`Config::ChallengePeriod`] blocks, the pallet draws [`Config::ChallengesPerPeriod`]
+//! hosting claims of active institutions at random (see [`Config::Randomness`]), and challenges
+//! each institution to produce a random chunk of the document it claims to host. The institution
+//! has [`Config::ResponseWindow`] blocks to [`respond`](Pallet::respond) with the chunk and the
+//! Merkle proof that it belongs to the committed tree; a challenge still open at its deadline
+//! fails. The challenges issued to, answered and failed by each institution are tallied in its
+//! [`ProofRecord`].
+//!
+//! Randomness drawn from block hashes can be ground by the author of the last block that went into
+//! it. The institution running the validator that authored the block before a round (see
+//! [`Config::FindAuthor`]) would thereby choose its own challenges, so its claims are left out of
+//! the round and drawn in the next block instead, from randomness another validator authored.
+//!
+//! ### Provers
+//!
+//! Responses are unsigned transactions, authenticated by the prover key of the institution, which
+//! an admin sets with [`set_prover`](Pallet::set_prover). The offchain worker of a node holding a
+//! prover key in its keystore, under [`KEY_TYPE`], answers the open challenges of the key's
+//! institution on its own: it asks the RPC endpoint of the node for the chunk and its proof with
+//! `speem_getChunkProof`, and submits the response. The endpoint is `http://127.0.0.1:9944` unless
+//! the persistent offchain storage holds another URL under [`RPC_URL_KEY`]; as the method is
+//! unsafe, the endpoint must serve unsafe methods, which nodes do on their local interface unless
+//! told otherwise.
+//!
+//! An institution that stops hosting a document with [`unhost`](Pallet::unhost) is not challenged
+//! on it again, but must still answer the challenges already open.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`set_prover`](Pallet::set_prover) - Set the key an institution answers challenges with.
+//! - [`commit_blob`](Pallet::commit_blob) - Commit to the blob a registered document is stored as.
+//! - [`host`](Pallet::host) - Declare that an institution hosts a registered document.
+//! - [`unhost`](Pallet::unhost) - Withdraw a hosting claim.
+//! - [`respond`](Pallet::respond) - Answer a challenge with a chunk and its Merkle proof.
+//!
+//! Run `cargo doc --package pallet-storage-proofs --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use alloc::{format, string::String};
+use lite_json::json::JsonValue;
+use sp_application_crypto::KeyTypeId;
+use sp_std::prelude::*;
+pub use speem_primitives::{merkle, DocumentRegistrant, InstitutionId, InstitutionInspect};
+
+const LOG_TARGET: &str = "runtime::storage-proofs";
+
+/// The key type of the prover keys, with which nodes sign the responses of their institution.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"stor");
+
+/// The key of the persistent offchain storage entry holding the URL of the RPC endpoint the
+/// offchain worker fetches chunks from.
+pub const RPC_URL_KEY: &[u8] = b"speem::storage-proofs::rpc-url";
+
+/// The RPC endpoint the offchain worker fetches chunks from when no other is configured.
+const DEFAULT_RPC_URL: &str = "http://127.0.0.1:9944";
+
+/// How long the offchain worker waits for the RPC endpoint, in milliseconds.
+const HTTP_TIMEOUT_MS: u64 = 10_000;
+
+/// The number of blocks after which the offchain worker submits again a response that has not
+/// made it into a block.
+const RESUBMIT_AFTER: u32 = 5;
+
+/// The prover keys, which are sr25519 keys.
+pub mod sr25519 {
+	mod app_sr25519 {
+		use sp_application_crypto::{app_crypto, sr25519};
+		app_crypto!(sr25519, super::super::KEY_TYPE);
+	}
+
+	sp_application_crypto::with_pair! {
+		/// A prover key pair.
+		pub type ProverPair = app_sr25519::Pair;
+	}
+
+	/// A prover signature.
+	pub type ProverSignature = app_sr25519::Signature;
+
+	/// A prover identifier, i.e. the public half of a prover key.
+	pub type ProverId = app_sr25519::Public;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::DecodeAll;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Contains, FindAuthor, Randomness},
+		CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	};
+	use frame_system::{
+		offchain::{SendTransactionTypes, SubmitTransaction},
+		pallet_prelude::*,
+	};
+	use sp_runtime::{
+		offchain::{http, storage::StorageValueRef, Duration},
+		traits::{Hash, Saturating, TrailingZeroInput, Zero},
+		RuntimeAppPublic, SaturatedConversion,
+	};
+
+	/// Identifier of a challenge, unique among the challenges of an institution.
+	pub type ChallengeId = u64;
+
+	/// The signature of the prover key of `T`.
+	pub type ProverSignatureOf<T> = <<T as Config>::ProverId as RuntimeAppPublic>::Signature;
+
+	/// The context signed along with a response, so that signatures cannot be replayed elsewhere.
+	pub const RESPONSE_CONTEXT: &[u8] = b"speem/storage-proofs/response";
+
+	/// The subject of the randomness challenges are drawn with.
+	const CHALLENGE_SUBJECT: &[u8] = b"speem/storage-proofs/challenge";
+
+	/// The blob a registered document is stored as, as committed to by its registrant.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct BlobCommitment<Hash> {
+		/// The hash the blob is stored under in the blob stores of the nodes.
+		pub blob_hash: Hash,
+		/// The root of the Merkle tree over the chunks of the blob.
+		pub chunk_root: Hash,
+		/// The number of chunks of the blob.
+		pub chunk_count: u32,
+	}
+
+	/// An institution's claim to host a registered document.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Hosting<T: Config> {
+		/// The hash the document is stored under in the blob store of the institution's node.
+		pub blob_hash: T::Hash,
+		/// The root of the Merkle tree over the chunks of the blob.
+		pub chunk_root: T::Hash,
+		/// The number of chunks of the blob.
+		pub chunk_count: u32,
+		/// The position of the claim in [`HostingSlots`].
+		pub slot: u32,
+		/// The block in which the claim was made.
+		pub since: BlockNumberFor<T>,
+	}
+
+	/// A challenge to produce a chunk of a hosted document.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Challenge<T: Config> {
+		/// The content hash of the document.
+		pub content_hash: T::Hash,
+		/// The hash the document is stored under, as claimed when the challenge was issued.
+		pub blob_hash: T::Hash,
+		/// The root of the Merkle tree over the chunks of the blob, as claimed.
+		pub chunk_root: T::Hash,
+		/// The number of chunks of the blob, as claimed.
+		pub chunk_count: u32,
+		/// The index of the chunk to produce.
+		pub chunk: u32,
+		/// The block at the start of which the challenge fails if it is still open.
+		pub deadline: BlockNumberFor<T>,
+	}
+
+	/// How an institution has fared with its challenges.
+	#[derive(
+		Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ProofRecord<BlockNumber> {
+		/// The number of challenges issued to the institution.
+		pub issued: u32,
+		/// The number of challenges it answered in time.
+		pub answered: u32,
+		/// The number of challenges it failed to answer in time.
+		pub failed: u32,
+		/// The deadline of the last challenge it failed.
+		pub last_failure: Option<BlockNumber>,
+	}
+
+	/// Provides benchmarks with state that this pallet does not control.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Hash, AccountId> {
+		/// Make sure a document is final in the registry and return its content hash and
+		/// registrant.
+		fn registered_document() -> (Hash, AccountId);
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The key institutions sign their responses with.
+		type ProverId: Member
+			+ Parameter
+			+ RuntimeAppPublic
+			+ Ord
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen;
+		/// The registry of institutions, whose admins declare what their institution hosts.
+		type Institutions: InstitutionInspect<Self::AccountId>;
+		/// The registry of documents that can be hosted, and of their registrants.
+		type Documents: Contains<Self::Hash> + DocumentRegistrant<Self::AccountId, Self::Hash>;
+		/// The source of the randomness challenges are drawn with.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+		/// The institutions running the validators that author blocks.
+		type FindAuthor: FindAuthor<InstitutionId>;
+		/// The number of blocks between two rounds of challenges.
+		#[pallet::constant]
+		type ChallengePeriod: Get<BlockNumberFor<Self>>;
+		/// The number of blocks an institution has to answer a challenge.
+		#[pallet::constant]
+		type ResponseWindow: Get<BlockNumberFor<Self>>;
+		/// The number of challenges issued in each round.
+		#[pallet::constant]
+		type ChallengesPerPeriod: Get<u32>;
+		/// The maximum number of hosting claims, across institutions.
+		#[pallet::constant]
+		type MaxHostings: Get<u32>;
+		/// The maximum number of hosting claims of a single institution, so that no institution
+		/// takes up the claims of the others.
+		#[pallet::constant]
+		type MaxHostingsPerInstitution: Get<u32>;
+		/// The priority of the unsigned transactions answering challenges.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// Helper for benchmarks to obtain documents the registry contains.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::Hash, Self::AccountId>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The prover key of each institution.
+	#[pallet::storage]
+	pub type Provers<T: Config> =
+		StorageMap<_, Twox64Concat, InstitutionId, T::ProverId, OptionQuery>;
+
+	/// The institution each prover key answers for.
+	#[pallet::storage]
+	pub type InstitutionOfProver<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProverId, InstitutionId, OptionQuery>;
+
+	/// The blobs the registered documents are stored as, keyed by the content hash of the
+	/// document.
+	#[pallet::storage]
+	pub type Blobs<T: Config> =
+		StorageMap<_, Identity, T::Hash, BlobCommitment<T::Hash>, OptionQuery>;
+
+	/// The hosting claims, keyed by the content hash of the document and the hosting institution.
+	#[pallet::storage]
+	pub type Hostings<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::Hash,
+		Twox64Concat,
+		InstitutionId,
+		Hosting<T>,
+		OptionQuery,
+	>;
+
+	/// The number of hosting claims.
+	#[pallet::storage]
+	pub type HostingCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The number of hosting claims of each institution.
+	#[pallet::storage]
+	pub type HostingsOf<T> = StorageMap<_, Twox64Concat, InstitutionId, u32, ValueQuery>;
+
+	/// The hosting claims, as `(content hash, institution)`, in the slots `0..HostingCount` from
+	/// which challenges are drawn.
+	#[pallet::storage]
+	pub type HostingSlots<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (T::Hash, InstitutionId), OptionQuery>;
+
+	/// The open challenges, keyed by institution and challenge identifier.
+	#[pallet::storage]
+	pub type Challenges<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		InstitutionId,
+		Twox64Concat,
+		ChallengeId,
+		Challenge<T>,
+		OptionQuery,
+	>;
+
+	/// The identifier the next challenge will receive.
+	#[pallet::storage]
+	pub type NextChallengeId<T> = StorageValue<_, ChallengeId, ValueQuery>;
+
+	/// The institution that authored the previous block, if an institution did.
+	#[pallet::storage]
+	pub type PreviousAuthor<T> = StorageValue<_, InstitutionId, OptionQuery>;
+
+	/// The institution whose challenges in the last round are drawn in the current block, as it
+	/// authored the block before the round.
+	#[pallet::storage]
+	pub type Deferred<T> = StorageValue<_, InstitutionId, OptionQuery>;
+
+	/// The challenges issued together, keyed by their deadline, which no other challenges share.
+	#[pallet::storage]
+	pub type Deadlines<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(InstitutionId, ChallengeId), T::ChallengesPerPeriod>,
+		ValueQuery,
+	>;
+
+	/// How each institution has fared with its challenges.
+	#[pallet::storage]
+	pub type Records<T: Config> =
+		StorageMap<_, Twox64Concat, InstitutionId, ProofRecord<BlockNumberFor<T>>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An institution has set its prover key.
+		ProverSet {
+			/// The institution.
+			institution: InstitutionId,
+			/// The key it answers challenges with.
+			prover: T::ProverId,
+		},
+		/// The registrant of a document has committed to the blob it is stored as.
+		BlobCommitted {
+			/// The content hash of the document.
+			content_hash: T::Hash,
+			/// The hash of the blob.
+			blob_hash: T::Hash,
+		},
+		/// An institution has declared that it hosts a document.
+		Hosted {
+			/// The content hash of the document.
+			content_hash: T::Hash,
+			/// The hosting institution.
+			institution: InstitutionId,
+		},
+		/// An institution has withdrawn its claim to host a document.
+		Unhosted {
+			/// The content hash of the document.
+			content_hash: T::Hash,
+			/// The institution.
+			institution: InstitutionId,
+		},
+		/// An institution has been challenged to produce a chunk of a document it hosts.
+		ChallengeIssued {
+			/// The challenged institution.
+			institution: InstitutionId,
+			/// The identifier of the challenge.
+			challenge: ChallengeId,
+			/// The content hash of the document.
+			content_hash: T::Hash,
+			/// The index of the chunk to produce.
+			chunk: u32,
+			/// The block at the start of which the challenge fails if it is still open.
+			deadline: BlockNumberFor<T>,
+		},
+		/// An institution has answered a challenge.
+		ChallengeAnswered {
+			/// The institution.
+			institution: InstitutionId,
+			/// The identifier of the challenge.
+			challenge: ChallengeId,
+		},
+		/// An institution has failed to answer a challenge in time.
+		ChallengeFailed {
+			/// The institution.
+			institution: InstitutionId,
+			/// The identifier of the challenge.
+			challenge: ChallengeId,
+			/// The content hash of the document.
+			content_hash: T::Hash,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The signer is not an admin of an active institution.
+		NotActiveAdmin,
+		/// The signer is not an admin of an institution.
+		NotAdmin,
+		/// The prover key is already used by another institution.
+		ProverInUse,
+		/// The document is not a final entry of the registry.
+		UnregisteredDocument,
+		/// The signer is not the registrant of the document.
+		NotRegistrant,
+		/// A blob has at least one chunk.
+		NoChunks,
+		/// The registrant has already committed to the blob the document is stored as.
+		AlreadyCommitted,
+		/// The registrant has not committed to the blob the document is stored as.
+		NoCommitment,
+		/// The blob, Merkle root or number of chunks differ from those the registrant committed
+		/// to.
+		CommitmentMismatch,
+		/// The institution already claims to host the document.
+		AlreadyHosted,
+		/// The institution does not claim to host the document.
+		NotHosted,
+		/// There are already [`Config::MaxHostings`] hosting claims.
+		TooManyHostings,
+		/// The institution already has [`Config::MaxHostingsPerInstitution`] hosting claims.
+		TooManyHostingsOfInstitution,
+		/// The institution has no open challenge with the given identifier.
+		UnknownChallenge,
+		/// The chunk and proof do not match the Merkle root of the challenged blob.
+		InvalidProof,
+		/// The institution has not set a prover key.
+		NoProver,
+		/// The response is not signed by the prover key of the institution.
+		BadSignature,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::WeightInfo::expire_challenges(Self::expire_challenges(now));
+			if let Some(deferred) = Deferred::<T>::take() {
+				Self::issue_challenges(now, |institution| institution == deferred);
+				weight.saturating_accrue(T::WeightInfo::issue_challenges());
+			}
+			// The author of the previous block could grind the randomness of this one.
+			let grinder = PreviousAuthor::<T>::get();
+			if (now % T::ChallengePeriod::get()).is_zero() {
+				Self::issue_challenges(now, |institution| Some(institution) != grinder);
+				Deferred::<T>::set(grinder);
+				weight.saturating_accrue(T::WeightInfo::issue_challenges());
+			}
+			Self::note_author();
+			weight.saturating_add(T::WeightInfo::note_author())
+		}
+
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			Self::answer_challenges(now);
+		}
+
+		fn integrity_test() {
+			assert!(!T::ChallengePeriod::get().is_zero(), "challenges must be issued periodically");
+			assert!(!T::ResponseWindow::get().is_zero(), "challenges must be answerable");
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the key the signer's institution answers challenges with, replacing any previous
+		/// one.
+		///
+		/// The origin must be signed by an admin of an active institution.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotActiveAdmin`] if the signer does not act for an active institution.
+		/// - [`Error::ProverInUse`] if `prover` is the prover key of another institution.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_prover())]
+		pub fn set_prover(origin: OriginFor<T>, prover: T::ProverId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let institution = Self::active_institution_of(&who)?;
+			ensure!(
+				InstitutionOfProver::<T>::get(&prover).map_or(true, |other| other == institution),
+				Error::<T>::ProverInUse
+			);
+			if let Some(previous) = Provers::<T>::get(institution) {
+				InstitutionOfProver::<T>::remove(previous);
+			}
+			Provers::<T>::insert(institution, &prover);
+			InstitutionOfProver::<T>::insert(&prover, institution);
+
+			Self::deposit_event(Event::ProverSet { institution, prover });
+			Ok(())
+		}
+
+		/// Declare that the signer's institution hosts the document registered under
+		/// `content_hash`, stored as the blob `blob_hash` of `chunk_count` chunks with Merkle root
+		/// `chunk_root`.
+		///
+		/// The origin must be signed by an admin of an active institution. The blob must be the
+		/// one the registrant of the document committed to with [`Pallet::commit_blob`]. The
+		/// institution may be challenged on the document from the next round on.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotActiveAdmin`] if the signer does not act for an active institution.
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NoCommitment`] if the registrant has not committed to a blob.
+		/// - [`Error::CommitmentMismatch`] if `blob_hash`, `chunk_root` and `chunk_count` are not
+		///   those the registrant committed to.
+		/// - [`Error::AlreadyHosted`] if the institution already claims to host the document.
+		/// - [`Error::TooManyHostingsOfInstitution`] if the institution already has
+		///   [`Config::MaxHostingsPerInstitution`] claims.
+		/// - [`Error::TooManyHostings`] if there are already [`Config::MaxHostings`] claims.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::host())]
+		pub fn host(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			blob_hash: T::Hash,
+			chunk_root: T::Hash,
+			chunk_count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let institution = Self::active_institution_of(&who)?;
+			ensure!(T::Documents::contains(&content_hash), Error::<T>::UnregisteredDocument);
+			let committed = Blobs::<T>::get(content_hash).ok_or(Error::<T>::NoCommitment)?;
+			ensure!(
+				committed == BlobCommitment { blob_hash, chunk_root, chunk_count },
+				Error::<T>::CommitmentMismatch
+			);
+			ensure!(
+				!Hostings::<T>::contains_key(content_hash, institution),
+				Error::<T>::AlreadyHosted
+			);
+			let claims = HostingsOf::<T>::get(institution);
+			ensure!(
+				claims < T::MaxHostingsPerInstitution::get(),
+				Error::<T>::TooManyHostingsOfInstitution
+			);
+			let slot = HostingCount::<T>::get();
+			ensure!(slot < T::MaxHostings::get(), Error::<T>::TooManyHostings);
+
+			HostingSlots::<T>::insert(slot, (content_hash, institution));
+			HostingCount::<T>::put(slot + 1);
+			HostingsOf::<T>::insert(institution, claims + 1);
+			let since = frame_system::Pallet::<T>::block_number();
+			Hostings::<T>::insert(
+				content_hash,
+				institution,
+				Hosting { blob_hash, chunk_root, chunk_count, slot, since },
+			);
+
+			Self::deposit_event(Event::Hosted { content_hash, institution });
+			Ok(())
+		}
+
+		/// Withdraw the signer's institution's claim to host the document registered under
+		/// `content_hash`.
+		///
+		/// The origin must be signed by an admin of the institution, whatever its status. The
+		/// challenges already open on the document must still be answered.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotAdmin`] if the signer does not act for an institution.
+		/// - [`Error::NotHosted`] if the institution does not claim to host the document.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::unhost())]
+		pub fn unhost(origin: OriginFor<T>, content_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let institution = T::Institutions::institution_of(&who).ok_or(Error::<T>::NotAdmin)?;
+			let hosting =
+				Hostings::<T>::take(content_hash, institution).ok_or(Error::<T>::NotHosted)?;
+
+			// Move the last claim into the freed slot, so that the slots stay contiguous.
+			let last = HostingCount::<T>::get().saturating_sub(1);
+			if let Some(moved) = HostingSlots::<T>::take(last) {
+				if hosting.slot != last {
+					HostingSlots::<T>::insert(hosting.slot, moved);
+					Hostings::<T>::mutate(moved.0, moved.1, |claim| {
+						if let Some(claim) = claim {
+							claim.slot = hosting.slot;
+						}
+					});
+				}
+			}
+			HostingCount::<T>::put(last);
+			HostingsOf::<T>::mutate(institution, |claims| claims.saturating_dec());
+
+			Self::deposit_event(Event::Unhosted { content_hash, institution });
+			Ok(())
+		}
+
+		/// Answer a challenge with the challenged `chunk` and the Merkle `proof` that it belongs to
+		/// the challenged blob.
+		///
+		/// This is an unsigned transaction, authenticated by the `signature` of the institution's
+		/// prover key over [`Pallet::response_payload`]. It is usually submitted by the offchain
+		/// worker of the institution's node.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownChallenge`] if the institution has no such open challenge.
+		/// - [`Error::InvalidProof`] if the chunk and proof do not match the Merkle root of the
+		///   blob.
+		/// - [`Error::NoProver`] if the institution has not set a prover key.
+		/// - [`Error::BadSignature`] if `signature` is not by that key.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::respond(proof.len() as u32))]
+		pub fn respond(
+			origin: OriginFor<T>,
+			institution: InstitutionId,
+			challenge: ChallengeId,
+			chunk: Vec<u8>,
+			proof: Vec<T::Hash>,
+			signature: ProverSignatureOf<T>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			Self::check_response(institution, challenge, &chunk, &proof, &signature)?;
+			Challenges::<T>::remove(institution, challenge);
+			Records::<T>::mutate(institution, |record| record.answered.saturating_inc());
+
+			Self::deposit_event(Event::ChallengeAnswered { institution, challenge });
+			Ok(())
+		}
+
+		/// Commit to the document registered under `content_hash` being stored as the blob
+		/// `blob_hash` of `chunk_count` chunks with Merkle root `chunk_root`, such as the document
+		/// encrypted by its authors.
+		///
+		/// The origin must be signed by the registrant of the document. The commitment is made
+		/// once, as hosting claims and the challenges on them rest on it.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NotRegistrant`] if the signer is not the registrant of the document.
+		/// - [`Error::NoChunks`] if `chunk_count` is zero.
+		/// - [`Error::AlreadyCommitted`] if the registrant has already committed to a blob.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::commit_blob())]
+		pub fn commit_blob(
+			origin: OriginFor<T>,
+			content_hash: T::Hash,
+			blob_hash: T::Hash,
+			chunk_root: T::Hash,
+			chunk_count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(T::Documents::contains(&content_hash), Error::<T>::UnregisteredDocument);
+			ensure!(
+				T::Documents::registrant_of(&content_hash).as_ref() == Some(&who),
+				Error::<T>::NotRegistrant
+			);
+			ensure!(chunk_count > 0, Error::<T>::NoChunks);
+			ensure!(!Blobs::<T>::contains_key(content_hash), Error::<T>::AlreadyCommitted);
+
+			Blobs::<T>::insert(content_hash, BlobCommitment { blob_hash, chunk_root, chunk_count });
+
+			Self::deposit_event(Event::BlobCommitted { content_hash, blob_hash });
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::respond { institution, challenge, chunk, proof, signature } = call else {
+				return InvalidTransaction::Call.into()
+			};
+			let open = Self::check_response(*institution, *challenge, chunk, proof, signature)
+				.map_err(Self::invalid_transaction)?;
+
+			// Each challenge is answered once, before its deadline.
+			let now = frame_system::Pallet::<T>::block_number();
+			ValidTransaction::with_tag_prefix("StorageProofs")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((institution, challenge))
+				.longevity(open.deadline.saturating_sub(now).saturated_into())
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The message the prover key of `institution` signs to answer `challenge`.
+		pub fn response_payload(
+			institution: InstitutionId,
+			challenge: ChallengeId,
+			chunk: &[u8],
+			proof: &[T::Hash],
+		) -> Vec<u8> {
+			(RESPONSE_CONTEXT, institution, challenge, chunk, proof).encode()
+		}
+
+		/// The active institution `who` administers.
+		fn active_institution_of(who: &T::AccountId) -> Result<InstitutionId, Error<T>> {
+			T::Institutions::institution_of(who)
+				.filter(|institution| T::Institutions::is_active(*institution))
+				.ok_or(Error::<T>::NotActiveAdmin)
+		}
+
+		/// Fail the challenges whose deadline is `now` and are still open, returning how many
+		/// challenges were issued with that deadline.
+		pub(crate) fn expire_challenges(now: BlockNumberFor<T>) -> u32 {
+			let expiring = Deadlines::<T>::take(now);
+			for (institution, challenge) in &expiring {
+				let Some(open) = Challenges::<T>::take(institution, challenge) else { continue };
+				Records::<T>::mutate(institution, |record| {
+					record.failed.saturating_inc();
+					record.last_failure = Some(now);
+				});
+				Self::deposit_event(Event::ChallengeFailed {
+					institution: *institution,
+					challenge: *challenge,
+					content_hash: open.content_hash,
+				});
+			}
+			expiring.len() as u32
+		}
+
+		/// Record the institution that authored the current block, if an institution did.
+		pub(crate) fn note_author() {
+			let digest = frame_system::Pallet::<T>::digest();
+			let pre_runtime_digests = digest.logs.iter().filter_map(|log| log.as_pre_runtime());
+			PreviousAuthor::<T>::set(T::FindAuthor::find_author(pre_runtime_digests));
+		}
+
+		/// Challenge the hosts of the claims among [`Config::ChallengesPerPeriod`] randomly drawn
+		/// ones that belong to an institution for which `drawn` holds.
+		pub(crate) fn issue_challenges(
+			now: BlockNumberFor<T>,
+			drawn: impl Fn(InstitutionId) -> bool,
+		) {
+			let hostings = HostingCount::<T>::get();
+			if hostings == 0 {
+				return
+			}
+			let (seed, _) = T::Randomness::random(CHALLENGE_SUBJECT);
+			// Each batch of challenges has a deadline of its own, so that it fits the bound of
+			// `Deadlines`. Another batch may have taken it, e.g. when a runtime upgrade shortened
+			// the response window, in which case the challenges are given a little longer.
+			let mut deadline = now.saturating_add(T::ResponseWindow::get());
+			while Deadlines::<T>::contains_key(deadline) {
+				deadline.saturating_inc();
+			}
+
+			let mut issued = BoundedVec::<_, T::ChallengesPerPeriod>::new();
+			for round in 0..T::ChallengesPerPeriod::get() {
+				let draw = T::Hashing::hash_of(&(seed, round));
+				let (slot, chunk) =
+					<(u32, u32)>::decode(&mut TrailingZeroInput::new(draw.as_ref()))
+						.expect("input is padded with zeroes; qed");
+				let Some((content_hash, institution)) = HostingSlots::<T>::get(slot % hostings)
+				else {
+					continue
+				};
+				if !drawn(institution) {
+					continue
+				}
+				// Suspended institutions are not challenged until they are reinstated.
+				if !T::Institutions::is_active(institution) {
+					continue
+				}
+				let Some(hosting) = Hostings::<T>::get(content_hash, institution) else { continue };
+
+				let challenge = NextChallengeId::<T>::get();
+				// There is at most one challenge per draw.
+				if issued.try_push((institution, challenge)).is_err() {
+					break
+				}
+				NextChallengeId::<T>::put(challenge.wrapping_add(1));
+				let chunk = chunk % hosting.chunk_count;
+				Challenges::<T>::insert(
+					institution,
+					challenge,
+					Challenge {
+						content_hash,
+						blob_hash: hosting.blob_hash,
+						chunk_root: hosting.chunk_root,
+						chunk_count: hosting.chunk_count,
+						chunk,
+						deadline,
+					},
+				);
+				Records::<T>::mutate(institution, |record| record.issued.saturating_inc());
+
+				Self::deposit_event(Event::ChallengeIssued {
+					institution,
+					challenge,
+					content_hash,
+					chunk,
+					deadline,
+				});
+			}
+
+			if !issued.is_empty() {
+				Deadlines::<T>::insert(deadline, issued);
+			}
+		}
+
+		/// Check that `chunk` and `proof` answer the open `challenge` of `institution`, and that
+		/// `signature` is by its prover key.
+		fn check_response(
+			institution: InstitutionId,
+			challenge: ChallengeId,
+			chunk: &[u8],
+			proof: &[T::Hash],
+			signature: &ProverSignatureOf<T>,
+		) -> Result<Challenge<T>, Error<T>> {
+			let open =
+				Challenges::<T>::get(institution, challenge).ok_or(Error::<T>::UnknownChallenge)?;
+			ensure!(
+				merkle::verify::<T::Hashing>(
+					&open.chunk_root,
+					open.chunk_count,
+					open.chunk,
+					chunk,
+					proof
+				),
+				Error::<T>::InvalidProof
+			);
+			let prover = Provers::<T>::get(institution).ok_or(Error::<T>::NoProver)?;
+			ensure!(
+				prover.verify(
+					&Self::response_payload(institution, challenge, chunk, proof),
+					signature
+				),
+				Error::<T>::BadSignature
+			);
+			Ok(open)
+		}
+
+		fn invalid_transaction(error: Error<T>) -> TransactionValidityError {
+			match error {
+				Error::<T>::InvalidProof | Error::<T>::BadSignature => InvalidTransaction::BadProof,
+				Error::<T>::UnknownChallenge => InvalidTransaction::Stale,
+				_ => InvalidTransaction::Call,
+			}
+			.into()
+		}
+
+		/// Answer the open challenges of the institutions whose prover key is in the keystore.
+		fn answer_challenges(now: BlockNumberFor<T>) {
+			for prover in T::ProverId::all() {
+				let Some(institution) = InstitutionOfProver::<T>::get(&prover) else { continue };
+				for (challenge, open) in Challenges::<T>::iter_prefix(institution) {
+					if !Self::claim_response(institution, challenge, now) {
+						continue
+					}
+					if let Err(e) = Self::answer(&prover, institution, challenge, &open) {
+						log::warn!(
+							target: LOG_TARGET,
+							"cannot answer challenge {challenge} of institution {institution}: {e}",
+						);
+					}
+				}
+			}
+		}
+
+		/// Whether the offchain worker should submit a response to `challenge` in block `now`,
+		/// i.e. it has not submitted one in the last [`RESUBMIT_AFTER`] blocks.
+		fn claim_response(
+			institution: InstitutionId,
+			challenge: ChallengeId,
+			now: BlockNumberFor<T>,
+		) -> bool {
+			let key = (b"speem::storage-proofs::submitted", institution, challenge).encode();
+			StorageValueRef::persistent(&key)
+				.mutate(|submitted: Result<Option<BlockNumberFor<T>>, _>| match submitted {
+					Ok(Some(at)) if now < at.saturating_add(RESUBMIT_AFTER.into()) => Err(()),
+					_ => Ok(now),
+				})
+				.is_ok()
+		}
+
+		/// Fetch the challenged chunk and its proof from the node and submit the response.
+		fn answer(
+			prover: &T::ProverId,
+			institution: InstitutionId,
+			challenge: ChallengeId,
+			open: &Challenge<T>,
+		) -> Result<(), &'static str> {
+			let (chunk, proof) = Self::fetch_chunk(&open.blob_hash, open.chunk)?;
+			if !merkle::verify::<T::Hashing>(
+				&open.chunk_root,
+				open.chunk_count,
+				open.chunk,
+				&chunk,
+				&proof,
+			) {
+				return Err("the node holds a blob that does not match the hosting claim")
+			}
+			let signature = prover
+				.sign(&Self::response_payload(institution, challenge, &chunk, &proof))
+				.ok_or("the prover key cannot sign")?;
+
+			let call = Call::respond { institution, challenge, chunk, proof, signature };
+			SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+				.map_err(|()| "the response cannot be submitted")
+		}
+
+		/// Ask the RPC endpoint of the node for the `index`th chunk of the blob stored under
+		/// `blob_hash`, with its Merkle proof.
+		fn fetch_chunk(
+			blob_hash: &T::Hash,
+			index: u32,
+		) -> Result<(Vec<u8>, Vec<T::Hash>), &'static str> {
+			let url = StorageValueRef::persistent(RPC_URL_KEY).get::<Vec<u8>>().ok().flatten();
+			let url = match &url {
+				Some(url) => core::str::from_utf8(url).map_err(|_| "the RPC URL is not UTF-8")?,
+				None => DEFAULT_RPC_URL,
+			};
+			let body = format!(
+				r#"{{"id":1,"jsonrpc":"2.0","method":"speem_getChunkProof","params":["{}",{index}]}}"#,
+				array_bytes::bytes2hex("0x", blob_hash),
+			);
+
+			let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+			let response = http::Request::post(url, alloc::vec![body.into_bytes()])
+				.add_header("Content-Type", "application/json")
+				.deadline(deadline)
+				.send()
+				.map_err(|_| "the RPC endpoint cannot be reached")?
+				.try_wait(deadline)
+				.map_err(|_| "the RPC endpoint timed out")?
+				.map_err(|_| "the RPC request failed")?;
+			if response.code != 200 {
+				return Err("the RPC endpoint returned an error")
+			}
+
+			let body = response.body().collect::<Vec<u8>>();
+			Self::parse_chunk_proof(&body).ok_or("the node does not hold the blob")
+		}
+
+		/// The chunk and proof in a response to `speem_getChunkProof`.
+		fn parse_chunk_proof(body: &[u8]) -> Option<(Vec<u8>, Vec<T::Hash>)> {
+			let json = lite_json::parse_json(core::str::from_utf8(body).ok()?).ok()?;
+			let result = json_field(&json, "result")?;
+			let chunk = json_bytes(json_field(result, "chunk")?)?;
+			let JsonValue::Array(proof) = json_field(result, "proof")? else { return None };
+			let proof = proof
+				.iter()
+				.map(|node| T::Hash::decode_all(&mut &json_bytes(node)?[..]).ok())
+				.collect::<Option<_>>()?;
+			Some((chunk, proof))
+		}
+	}
+}
+
+/// The field `name` of the JSON object `value`.
+fn json_field<'a>(value: &'a JsonValue, name: &str) -> Option<&'a JsonValue> {
+	match value {
+		JsonValue::Object(fields) => fields
+			.iter()
+			.find(|(key, _)| key.iter().copied().eq(name.chars()))
+			.map(|(_, value)| value),
+		_ => None,
+	}
+}
+
+/// The bytes of the hex-encoded JSON string `value`.
+fn json_bytes(value: &JsonValue) -> Option<Vec<u8>> {
+	let JsonValue::String(chars) = value else { return None };
+	array_bytes::hex2bytes(chars.iter().collect::<String>()).ok()
+}
@@ -0,0 +1,202 @@
+use crate as pallet_storage_proofs;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU16, ConstU32, ConstU64, Contains, FindAuthor, Randomness},
+};
+use frame_system::offchain::SendTransactionTypes;
+use sp_core::H256;
+use sp_runtime::{
+	testing::{TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, ConsensusEngineId,
+};
+use speem_primitives::{DocumentRegistrant, InstitutionId, InstitutionInspect};
+use std::{
+	cell::RefCell,
+	collections::{BTreeMap, BTreeSet},
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// The extrinsics the offchain worker submits to the transaction pool.
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		StorageProofs: pallet_storage_proofs,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl<C> SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+thread_local! {
+	static ADMINS: RefCell<BTreeMap<u64, InstitutionId>> = RefCell::new(BTreeMap::new());
+	static SUSPENDED: RefCell<BTreeSet<InstitutionId>> = RefCell::new(BTreeSet::new());
+	static REGISTERED: RefCell<BTreeMap<H256, u64>> = RefCell::new(BTreeMap::new());
+	static SEED: RefCell<H256> = RefCell::new(H256::zero());
+	static AUTHOR: RefCell<Option<InstitutionId>> = RefCell::new(None);
+}
+
+/// A stand-in for the institution registry, in which account `n` administers institution `n - 1`
+/// for `n` in `1..=3`.
+pub struct MockInstitutions;
+
+impl MockInstitutions {
+	/// Suspend `institution`.
+	pub fn suspend(institution: InstitutionId) {
+		SUSPENDED.with(|suspended| suspended.borrow_mut().insert(institution));
+	}
+}
+
+impl InstitutionInspect<u64> for MockInstitutions {
+	fn institution_of(who: &u64) -> Option<InstitutionId> {
+		ADMINS.with(|admins| admins.borrow().get(who).copied())
+	}
+
+	fn is_active(institution: InstitutionId) -> bool {
+		ADMINS.with(|admins| admins.borrow().values().any(|id| *id == institution)) &&
+			!SUSPENDED.with(|suspended| suspended.borrow().contains(&institution))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn register_active_admin(who: &u64) -> InstitutionId {
+		ADMINS.with(|admins| {
+			let mut admins = admins.borrow_mut();
+			let id = admins.len() as InstitutionId;
+			admins.insert(*who, id);
+			id
+		})
+	}
+}
+
+/// A stand-in for the manuscript registry.
+pub struct MockDocuments;
+
+impl MockDocuments {
+	/// Mark `document` as a final registry entry, registered by `registrant`.
+	pub fn register(document: H256, registrant: u64) {
+		REGISTERED.with(|registered| registered.borrow_mut().insert(document, registrant));
+	}
+}
+
+impl Contains<H256> for MockDocuments {
+	fn contains(document: &H256) -> bool {
+		REGISTERED.with(|registered| registered.borrow().contains_key(document))
+	}
+}
+
+impl DocumentRegistrant<u64, H256> for MockDocuments {
+	fn registrant_of(document: &H256) -> Option<u64> {
+		REGISTERED.with(|registered| registered.borrow().get(document).copied())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<H256, u64> for MockDocuments {
+	fn registered_document() -> (H256, u64) {
+		let document = H256::repeat_byte(2);
+		Self::register(document, 5);
+		(document, 5)
+	}
+}
+
+/// A stand-in for the randomness source, which returns the seed last set.
+pub struct MockRandomness;
+
+impl MockRandomness {
+	/// Make `seed` the randomness of every subject.
+	pub fn set(seed: H256) {
+		SEED.with(|current| *current.borrow_mut() = seed);
+	}
+}
+
+impl Randomness<H256, u64> for MockRandomness {
+	fn random(_subject: &[u8]) -> (H256, u64) {
+		(SEED.with(|seed| *seed.borrow()), 0)
+	}
+}
+
+/// A stand-in for the validators authoring blocks, which finds the institution last set as the
+/// author of every block.
+pub struct MockAuthor;
+
+impl MockAuthor {
+	/// Make `institution` the author of every block.
+	pub fn set(institution: Option<InstitutionId>) {
+		AUTHOR.with(|author| *author.borrow_mut() = institution);
+	}
+}
+
+impl FindAuthor<InstitutionId> for MockAuthor {
+	fn find_author<'a, I>(_digests: I) -> Option<InstitutionId>
+	where
+		I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+	{
+		AUTHOR.with(|author| *author.borrow())
+	}
+}
+
+impl pallet_storage_proofs::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ProverId = UintAuthorityId;
+	type Institutions = MockInstitutions;
+	type Documents = MockDocuments;
+	type Randomness = MockRandomness;
+	type FindAuthor = MockAuthor;
+	type ChallengePeriod = ConstU64<10>;
+	type ResponseWindow = ConstU64<5>;
+	type ChallengesPerPeriod = ConstU32<2>;
+	type MaxHostings = ConstU32<3>;
+	type MaxHostingsPerInstitution = ConstU32<2>;
+	type UnsignedPriority = ConstU64<100>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockDocuments;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ADMINS.with(|admins| *admins.borrow_mut() = (1..=3).map(|who| (who, who as u32 - 1)).collect());
+	SUSPENDED.with(|suspended| suspended.borrow_mut().clear());
+	REGISTERED.with(|registered| registered.borrow_mut().clear());
+	MockRandomness::set(H256::zero());
+	MockAuthor::set(None);
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+}
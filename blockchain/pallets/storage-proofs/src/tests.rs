@@ -0,0 +1,576 @@
+use crate::{
+	merkle, mock::*, BlobCommitment, Blobs, Call, Challenge, ChallengeId, Challenges, Deadlines,
+	Deferred, Error, Event, HostingCount, HostingSlots, Hostings, HostingsOf, InstitutionOfProver,
+	ProofRecord, Provers, Records,
+};
+use frame_support::{
+	assert_noop, assert_ok, dispatch::DispatchResult, pallet_prelude::*,
+	traits::UnfilteredDispatchable,
+};
+use sp_core::{
+	offchain::{
+		testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	H256,
+};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
+};
+
+const DOCUMENT: H256 = H256::repeat_byte(2);
+const OTHER_DOCUMENT: H256 = H256::repeat_byte(3);
+const BLOB: H256 = H256::repeat_byte(9);
+const PROVER: u64 = 7;
+const REGISTRANT: u64 = 5;
+
+/// The document, of three chunks.
+fn document() -> Vec<u8> {
+	(0..3 * merkle::CHUNK_SIZE - 100).map(|i| i as u8).collect()
+}
+
+/// The blob the document is stored as, encrypted by its authors.
+fn blob() -> Vec<u8> {
+	document().iter().map(|byte| byte ^ 0x5a).collect()
+}
+
+fn commit_blob(document: H256) -> DispatchResult {
+	let root = merkle::root::<BlakeTwo256>(&blob());
+	StorageProofs::commit_blob(RuntimeOrigin::signed(REGISTRANT), document, BLOB, root, 3)
+}
+
+/// Register `document` and commit to the blob it is stored as.
+fn register(document: H256) {
+	MockDocuments::register(document, REGISTRANT);
+	assert_ok!(commit_blob(document));
+}
+
+fn host(admin: u64, document: H256) -> DispatchResult {
+	let root = merkle::root::<BlakeTwo256>(&blob());
+	StorageProofs::host(RuntimeOrigin::signed(admin), document, BLOB, root, 3)
+}
+
+/// Have institution 0 host the document and answer with [`PROVER`].
+fn hosted() {
+	register(DOCUMENT);
+	assert_ok!(host(1, DOCUMENT));
+	assert_ok!(StorageProofs::set_prover(RuntimeOrigin::signed(1), UintAuthorityId(PROVER)));
+}
+
+fn challenge(institution: u32, challenge: ChallengeId) -> Challenge<Test> {
+	Challenges::<Test>::get(institution, challenge).expect("the challenge is open")
+}
+
+/// The chunk and proof answering `challenge` of institution 0, signed by `signer`.
+fn response(challenge_id: ChallengeId, signer: u64) -> (Vec<u8>, Vec<H256>, TestSignature) {
+	let index = challenge(0, challenge_id).chunk;
+	let chunk = merkle::chunk(&blob(), index).unwrap().to_vec();
+	let proof = merkle::prove::<BlakeTwo256>(&blob(), index).unwrap();
+	let payload = StorageProofs::response_payload(0, challenge_id, &chunk, &proof);
+	(chunk, proof, TestSignature(signer, payload))
+}
+
+fn respond(challenge_id: ChallengeId, signer: u64) -> DispatchResult {
+	let (chunk, proof, signature) = response(challenge_id, signer);
+	StorageProofs::respond(RuntimeOrigin::none(), 0, challenge_id, chunk, proof, signature)
+}
+
+fn start_block(now: u64) {
+	System::set_block_number(now);
+	StorageProofs::on_initialize(now);
+}
+
+#[test]
+fn set_prover_replaces_the_key() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		assert_ok!(StorageProofs::set_prover(RuntimeOrigin::signed(1), UintAuthorityId(7)));
+		System::assert_last_event(
+			Event::ProverSet { institution: 0, prover: UintAuthorityId(7) }.into(),
+		);
+		assert_ok!(StorageProofs::set_prover(RuntimeOrigin::signed(1), UintAuthorityId(8)));
+		assert_eq!(Provers::<Test>::get(0), Some(UintAuthorityId(8)));
+		assert_eq!(InstitutionOfProver::<Test>::get(UintAuthorityId(7)), None);
+		assert_eq!(InstitutionOfProver::<Test>::get(UintAuthorityId(8)), Some(0));
+
+		assert_noop!(
+			StorageProofs::set_prover(RuntimeOrigin::signed(2), UintAuthorityId(8)),
+			Error::<Test>::ProverInUse
+		);
+		assert_noop!(
+			StorageProofs::set_prover(RuntimeOrigin::signed(4), UintAuthorityId(9)),
+			Error::<Test>::NotActiveAdmin
+		);
+		MockInstitutions::suspend(1);
+		assert_noop!(
+			StorageProofs::set_prover(RuntimeOrigin::signed(2), UintAuthorityId(9)),
+			Error::<Test>::NotActiveAdmin
+		);
+	});
+}
+
+#[test]
+fn host_records_the_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(DOCUMENT);
+
+		assert_ok!(host(1, DOCUMENT));
+		System::assert_last_event(Event::Hosted { content_hash: DOCUMENT, institution: 0 }.into());
+		let hosting = Hostings::<Test>::get(DOCUMENT, 0).unwrap();
+		assert_eq!(hosting.blob_hash, BLOB);
+		assert_eq!(hosting.chunk_count, 3);
+		assert_eq!((hosting.slot, hosting.since), (0, 1));
+		assert_eq!(HostingSlots::<Test>::get(0), Some((DOCUMENT, 0)));
+		assert_eq!(HostingCount::<Test>::get(), 1);
+		assert_eq!(HostingsOf::<Test>::get(0), 1);
+	});
+}
+
+#[test]
+fn invalid_claims_are_rejected() {
+	new_test_ext().execute_with(|| {
+		register(DOCUMENT);
+		register(OTHER_DOCUMENT);
+
+		assert_noop!(host(1, H256::repeat_byte(4)), Error::<Test>::UnregisteredDocument);
+		assert_noop!(host(4, DOCUMENT), Error::<Test>::NotActiveAdmin);
+		assert_ok!(host(1, DOCUMENT));
+		assert_noop!(host(1, DOCUMENT), Error::<Test>::AlreadyHosted);
+
+		assert_ok!(host(2, DOCUMENT));
+		assert_ok!(host(3, DOCUMENT));
+		assert_noop!(host(1, OTHER_DOCUMENT), Error::<Test>::TooManyHostings);
+	});
+}
+
+#[test]
+fn institutions_cannot_take_up_every_claim() {
+	new_test_ext().execute_with(|| {
+		let third_document = H256::repeat_byte(4);
+		for document in [DOCUMENT, OTHER_DOCUMENT, third_document] {
+			register(document);
+		}
+		assert_ok!(host(1, DOCUMENT));
+		assert_ok!(host(1, OTHER_DOCUMENT));
+
+		// Claims are left for the other institutions.
+		assert_noop!(host(1, third_document), Error::<Test>::TooManyHostingsOfInstitution);
+		assert_ok!(host(2, third_document));
+
+		// Withdrawn claims make room for others.
+		assert_ok!(StorageProofs::unhost(RuntimeOrigin::signed(1), DOCUMENT));
+		assert_eq!(HostingsOf::<Test>::get(0), 1);
+		assert_ok!(host(1, third_document));
+	});
+}
+
+#[test]
+fn registrants_commit_to_blobs_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let root = merkle::root::<BlakeTwo256>(&blob());
+		MockDocuments::register(DOCUMENT, REGISTRANT);
+
+		assert_noop!(commit_blob(OTHER_DOCUMENT), Error::<Test>::UnregisteredDocument);
+		assert_noop!(
+			StorageProofs::commit_blob(RuntimeOrigin::signed(1), DOCUMENT, BLOB, root, 3),
+			Error::<Test>::NotRegistrant
+		);
+		assert_noop!(
+			StorageProofs::commit_blob(RuntimeOrigin::signed(REGISTRANT), DOCUMENT, BLOB, root, 0),
+			Error::<Test>::NoChunks
+		);
+
+		assert_ok!(commit_blob(DOCUMENT));
+		System::assert_last_event(
+			Event::BlobCommitted { content_hash: DOCUMENT, blob_hash: BLOB }.into(),
+		);
+		assert_eq!(
+			Blobs::<Test>::get(DOCUMENT),
+			Some(BlobCommitment { blob_hash: BLOB, chunk_root: root, chunk_count: 3 })
+		);
+		assert_noop!(commit_blob(DOCUMENT), Error::<Test>::AlreadyCommitted);
+	});
+}
+
+#[test]
+fn claims_must_match_the_committed_blob() {
+	new_test_ext().execute_with(|| {
+		let root = merkle::root::<BlakeTwo256>(&blob());
+		register(DOCUMENT);
+		MockDocuments::register(OTHER_DOCUMENT, REGISTRANT);
+
+		// A single chunk does not stand in for the document.
+		let chunk = merkle::chunk(&blob(), 0).unwrap();
+		assert_noop!(
+			StorageProofs::host(
+				RuntimeOrigin::signed(1),
+				DOCUMENT,
+				BLOB,
+				merkle::root::<BlakeTwo256>(chunk),
+				1
+			),
+			Error::<Test>::CommitmentMismatch
+		);
+		assert_noop!(
+			StorageProofs::host(RuntimeOrigin::signed(1), DOCUMENT, BLOB, root, 4),
+			Error::<Test>::CommitmentMismatch
+		);
+		// Nor does the document for the blob it is stored as.
+		assert_noop!(
+			StorageProofs::host(
+				RuntimeOrigin::signed(1),
+				DOCUMENT,
+				BlakeTwo256::hash(&document()),
+				merkle::root::<BlakeTwo256>(&document()),
+				3
+			),
+			Error::<Test>::CommitmentMismatch
+		);
+		assert_noop!(
+			StorageProofs::host(RuntimeOrigin::signed(1), OTHER_DOCUMENT, BLOB, root, 3),
+			Error::<Test>::NoCommitment
+		);
+		assert_ok!(host(1, DOCUMENT));
+	});
+}
+
+#[test]
+fn unhost_keeps_the_slots_contiguous() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(DOCUMENT);
+		for admin in 1..=3 {
+			assert_ok!(host(admin, DOCUMENT));
+		}
+
+		assert_ok!(StorageProofs::unhost(RuntimeOrigin::signed(1), DOCUMENT));
+		System::assert_last_event(
+			Event::Unhosted { content_hash: DOCUMENT, institution: 0 }.into(),
+		);
+		assert_eq!(Hostings::<Test>::get(DOCUMENT, 0), None);
+		assert_eq!(HostingCount::<Test>::get(), 2);
+		assert_eq!(HostingSlots::<Test>::get(0), Some((DOCUMENT, 2)));
+		assert_eq!(HostingSlots::<Test>::get(2), None);
+		assert_eq!(Hostings::<Test>::get(DOCUMENT, 2).unwrap().slot, 0);
+
+		// Suspended institutions may still withdraw their claims.
+		MockInstitutions::suspend(2);
+		assert_ok!(StorageProofs::unhost(RuntimeOrigin::signed(3), DOCUMENT));
+		assert_eq!(HostingSlots::<Test>::get(0), Some((DOCUMENT, 1)));
+		assert_eq!(HostingCount::<Test>::get(), 1);
+
+		assert_noop!(
+			StorageProofs::unhost(RuntimeOrigin::signed(1), DOCUMENT),
+			Error::<Test>::NotHosted
+		);
+		assert_noop!(
+			StorageProofs::unhost(RuntimeOrigin::signed(4), DOCUMENT),
+			Error::<Test>::NotAdmin
+		);
+	});
+}
+
+#[test]
+fn challenges_are_issued_each_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+
+		start_block(9);
+		assert_eq!(Challenges::<Test>::iter().count(), 0);
+
+		start_block(10);
+		let first = challenge(0, 0);
+		assert_eq!((first.content_hash, first.blob_hash, first.deadline), (DOCUMENT, BLOB, 15));
+		assert!(first.chunk < 3);
+		System::assert_has_event(
+			Event::ChallengeIssued {
+				institution: 0,
+				challenge: 0,
+				content_hash: DOCUMENT,
+				chunk: first.chunk,
+				deadline: 15,
+			}
+			.into(),
+		);
+		assert!(Challenges::<Test>::contains_key(0, 1));
+		assert_eq!(Deadlines::<Test>::get(15).into_inner(), vec![(0, 0), (0, 1)]);
+		assert_eq!(Records::<Test>::get(0).issued, 2);
+
+		// The next round is drawn with fresh randomness.
+		MockRandomness::set(H256::repeat_byte(1));
+		start_block(20);
+		assert_eq!(Records::<Test>::get(0).issued, 4);
+	});
+}
+
+#[test]
+fn challenges_of_the_previous_author_are_drawn_in_the_next_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+
+		// Institution 0 authored the block whose hash went last into the randomness of the round.
+		MockAuthor::set(Some(0));
+		start_block(9);
+		MockAuthor::set(Some(1));
+		start_block(10);
+		assert_eq!(Challenges::<Test>::iter().count(), 0);
+		assert_eq!(Deferred::<Test>::get(), Some(0));
+
+		MockRandomness::set(H256::repeat_byte(1));
+		start_block(11);
+		assert_eq!(Deferred::<Test>::get(), None);
+		assert_eq!(challenge(0, 0).deadline, 16);
+		assert_eq!(Deadlines::<Test>::get(16).into_inner(), vec![(0, 0), (0, 1)]);
+		assert_eq!(Records::<Test>::get(0).issued, 2);
+
+		// Institution 0 is not challenged again until the next round.
+		start_block(12);
+		assert_eq!(Records::<Test>::get(0).issued, 2);
+	});
+}
+
+#[test]
+fn challenges_do_not_share_a_deadline() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+		// Challenges of an earlier round are due at the deadline of this one, e.g. because the
+		// response window was shortened since.
+		Deadlines::<Test>::insert(15, BoundedVec::truncate_from(vec![(1, 7), (1, 8)]));
+
+		start_block(10);
+		assert_eq!(challenge(0, 0).deadline, 16);
+		assert_eq!(Deadlines::<Test>::get(15).into_inner(), vec![(1, 7), (1, 8)]);
+		assert_eq!(Deadlines::<Test>::get(16).into_inner(), vec![(0, 0), (0, 1)]);
+	});
+}
+
+#[test]
+fn suspended_institutions_are_not_challenged() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+		MockInstitutions::suspend(0);
+
+		start_block(10);
+		assert_eq!(Challenges::<Test>::iter().count(), 0);
+		assert_eq!(Records::<Test>::get(0), ProofRecord::default());
+	});
+}
+
+#[test]
+fn open_challenges_fail_at_their_deadline() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+		start_block(10);
+
+		start_block(14);
+		assert_eq!(Challenges::<Test>::iter().count(), 2);
+		start_block(15);
+		assert_eq!(Challenges::<Test>::iter().count(), 0);
+		assert_eq!(Deadlines::<Test>::get(15).len(), 0);
+		System::assert_has_event(
+			Event::ChallengeFailed { institution: 0, challenge: 1, content_hash: DOCUMENT }.into(),
+		);
+		assert_eq!(
+			Records::<Test>::get(0),
+			ProofRecord { issued: 2, answered: 0, failed: 2, last_failure: Some(15) }
+		);
+	});
+}
+
+#[test]
+fn responses_close_challenges() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+		start_block(10);
+		// Challenges already open must still be answered after the claim is withdrawn.
+		assert_ok!(StorageProofs::unhost(RuntimeOrigin::signed(1), DOCUMENT));
+
+		assert_ok!(respond(0, PROVER));
+		System::assert_last_event(Event::ChallengeAnswered { institution: 0, challenge: 0 }.into());
+		assert!(!Challenges::<Test>::contains_key(0, 0));
+		assert_noop!(
+			StorageProofs::respond(
+				RuntimeOrigin::none(),
+				0,
+				0,
+				Vec::new(),
+				Vec::new(),
+				TestSignature(PROVER, Vec::new())
+			),
+			Error::<Test>::UnknownChallenge
+		);
+
+		start_block(15);
+		assert_eq!(
+			Records::<Test>::get(0),
+			ProofRecord { issued: 2, answered: 1, failed: 1, last_failure: Some(15) }
+		);
+	});
+}
+
+#[test]
+fn challenges_are_answered_with_the_blob() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+		start_block(10);
+
+		// The chunks of the document itself do not answer, as the blob differs from it.
+		let index = challenge(0, 0).chunk;
+		let chunk = merkle::chunk(&document(), index).unwrap().to_vec();
+		let proof = merkle::prove::<BlakeTwo256>(&document(), index).unwrap();
+		assert_ne!(chunk, merkle::chunk(&blob(), index).unwrap());
+		let signature =
+			TestSignature(PROVER, StorageProofs::response_payload(0, 0, &chunk, &proof));
+		assert_noop!(
+			StorageProofs::respond(RuntimeOrigin::none(), 0, 0, chunk, proof, signature),
+			Error::<Test>::InvalidProof
+		);
+
+		assert_ok!(respond(0, PROVER));
+		assert_eq!(Records::<Test>::get(0).answered, 1);
+	});
+}
+
+#[test]
+fn invalid_responses_are_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+		start_block(10);
+
+		let (chunk, proof, signature) = response(0, PROVER);
+		let mut forged = chunk.clone();
+		forged[0] ^= 1;
+		assert_noop!(
+			StorageProofs::respond(
+				RuntimeOrigin::none(),
+				0,
+				0,
+				forged,
+				proof.clone(),
+				signature.clone()
+			),
+			Error::<Test>::InvalidProof
+		);
+		assert_noop!(respond(0, PROVER + 1), Error::<Test>::BadSignature);
+		assert_noop!(
+			StorageProofs::respond(RuntimeOrigin::signed(1), 0, 0, chunk, proof, signature),
+			DispatchError::BadOrigin
+		);
+
+		Provers::<Test>::remove(0);
+		assert_noop!(respond(0, PROVER), Error::<Test>::NoProver);
+	});
+}
+
+#[test]
+fn unsigned_responses_are_validated() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+		start_block(10);
+
+		let validate = |call: &Call<Test>| {
+			<StorageProofs as ValidateUnsigned>::validate_unsigned(
+				TransactionSource::External,
+				call,
+			)
+		};
+		let call = |signer: u64| {
+			let (chunk, proof, signature) = response(0, signer);
+			Call::respond { institution: 0, challenge: 0, chunk, proof, signature }
+		};
+
+		assert_eq!(validate(&call(PROVER + 1)), InvalidTransaction::BadProof.into());
+		let valid_call = call(PROVER);
+		let valid = validate(&valid_call).expect("signed by the prover key");
+		assert_eq!(valid.priority, 100);
+		assert_eq!(valid.provides, vec![("StorageProofs", (0u32, 0u64)).encode()]);
+		assert_eq!(valid.longevity, 5);
+
+		start_block(15);
+		assert_eq!(validate(&valid_call), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn offchain_worker_answers_challenges() {
+	let (offchain, state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		hosted();
+		UintAuthorityId::set_all_keys(vec![PROVER]);
+		start_block(10);
+
+		// The node serves each challenged chunk from its blob store.
+		for (_, open) in Challenges::<Test>::iter_prefix(0) {
+			let chunk = merkle::chunk(&blob(), open.chunk).unwrap();
+			let proof = merkle::prove::<BlakeTwo256>(&blob(), open.chunk).unwrap();
+			let proof = proof
+				.iter()
+				.map(|node| format!("\"{}\"", array_bytes::bytes2hex("0x", node)))
+				.collect::<Vec<_>>()
+				.join(",");
+			state.write().expect_request(PendingRequest {
+				method: "POST".into(),
+				uri: "http://127.0.0.1:9944".into(),
+				headers: vec![("Content-Type".into(), "application/json".into())],
+				body: format!(
+					r#"{{"id":1,"jsonrpc":"2.0","method":"speem_getChunkProof","params":["{}",{}]}}"#,
+					array_bytes::bytes2hex("0x", BLOB),
+					open.chunk,
+				)
+				.into_bytes(),
+				response: Some(
+					format!(
+						r#"{{"jsonrpc":"2.0","id":1,"result":{{"chunkCount":3,"chunk":"{}","proof":[{}]}}}}"#,
+						array_bytes::bytes2hex("0x", chunk),
+						proof,
+					)
+					.into_bytes(),
+				),
+				sent: true,
+				..Default::default()
+			});
+		}
+
+		StorageProofs::offchain_worker(10);
+		// Responses are not submitted again while they may still make it into a block.
+		StorageProofs::offchain_worker(11);
+
+		let transactions = pool_state.read().transactions.clone();
+		assert_eq!(transactions.len(), 2);
+		for transaction in transactions {
+			let extrinsic = Extrinsic::decode(&mut &transaction[..]).unwrap();
+			assert_eq!(extrinsic.signature, None);
+			let RuntimeCall::StorageProofs(call) = extrinsic.call else {
+				panic!("unexpected call: {:?}", extrinsic.call)
+			};
+			assert_ok!(<StorageProofs as ValidateUnsigned>::validate_unsigned(
+				TransactionSource::Local,
+				&call
+			));
+			assert_ok!(call.dispatch_bypass_filter(RuntimeOrigin::none()));
+		}
+		assert_eq!(Records::<Test>::get(0).answered, 2);
+	});
+}
@@ -0,0 +1,201 @@
+//! Weights for pallet_storage_proofs
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_storage_proofs` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_storage_proofs.
+pub trait WeightInfo {
+	fn set_prover() -> Weight;
+	fn host() -> Weight;
+	fn unhost() -> Weight;
+	fn respond(p: u32, ) -> Weight;
+	fn expire_challenges(e: u32, ) -> Weight;
+	fn issue_challenges() -> Weight;
+	fn note_author() -> Weight;
+	fn commit_blob() -> Weight;
+}
+
+/// Weights for pallet_storage_proofs using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: StorageProofs InstitutionOfProver (r:1 w:2)
+	/// Storage: StorageProofs Provers (r:1 w:1)
+	fn set_prover() -> Weight {
+		Weight::from_parts(22_000_000, 4585)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: StorageProofs Blobs (r:1 w:0)
+	/// Storage: StorageProofs Hostings (r:1 w:1)
+	/// Storage: StorageProofs HostingsOf (r:1 w:1)
+	/// Storage: StorageProofs HostingCount (r:1 w:1)
+	/// Storage: StorageProofs HostingSlots (r:0 w:1)
+	fn host() -> Weight {
+		Weight::from_parts(37_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: StorageProofs Hostings (r:2 w:2)
+	/// Storage: StorageProofs HostingCount (r:1 w:1)
+	/// Storage: StorageProofs HostingSlots (r:1 w:2)
+	/// Storage: StorageProofs HostingsOf (r:1 w:1)
+	fn unhost() -> Weight {
+		Weight::from_parts(33_000_000, 6180)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: StorageProofs Challenges (r:1 w:1)
+	/// Storage: StorageProofs Provers (r:1 w:0)
+	/// Storage: StorageProofs Records (r:1 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn respond(p: u32, ) -> Weight {
+		Weight::from_parts(73_000_000, 3601)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: StorageProofs Deadlines (r:1 w:1)
+	/// Storage: StorageProofs Challenges (r:4 w:4)
+	/// Storage: StorageProofs Records (r:4 w:4)
+	/// The range of component `e` is `[0, 4]`.
+	fn expire_challenges(e: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 3526)
+			.saturating_add(Weight::from_parts(13_000_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2611).saturating_mul(e.into()))
+	}
+	/// Storage: StorageProofs HostingCount (r:1 w:0)
+	/// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// Storage: StorageProofs HostingSlots (r:4 w:0)
+	/// Storage: Institutions Institutions (r:4 w:0)
+	/// Storage: StorageProofs Hostings (r:4 w:0)
+	/// Storage: StorageProofs NextChallengeId (r:1 w:1)
+	/// Storage: StorageProofs Records (r:4 w:4)
+	/// Storage: StorageProofs Deadlines (r:1 w:1)
+	/// Storage: StorageProofs Challenges (r:0 w:4)
+	fn issue_challenges() -> Weight {
+		Weight::from_parts(121_000_000, 15370)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: System Digest (r:1 w:0)
+	/// Storage: StorageProofs Deferred (r:1 w:1)
+	/// Storage: StorageProofs PreviousAuthor (r:1 w:1)
+	fn note_author() -> Weight {
+		Weight::from_parts(7_000_000, 1650)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: StorageProofs Blobs (r:1 w:1)
+	fn commit_blob() -> Weight {
+		Weight::from_parts(24_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: StorageProofs InstitutionOfProver (r:1 w:2)
+	/// Storage: StorageProofs Provers (r:1 w:1)
+	fn set_prover() -> Weight {
+		Weight::from_parts(22_000_000, 4585)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: StorageProofs Blobs (r:1 w:0)
+	/// Storage: StorageProofs Hostings (r:1 w:1)
+	/// Storage: StorageProofs HostingsOf (r:1 w:1)
+	/// Storage: StorageProofs HostingCount (r:1 w:1)
+	/// Storage: StorageProofs HostingSlots (r:0 w:1)
+	fn host() -> Weight {
+		Weight::from_parts(37_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: StorageProofs Hostings (r:2 w:2)
+	/// Storage: StorageProofs HostingCount (r:1 w:1)
+	/// Storage: StorageProofs HostingSlots (r:1 w:2)
+	/// Storage: StorageProofs HostingsOf (r:1 w:1)
+	fn unhost() -> Weight {
+		Weight::from_parts(33_000_000, 6180)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: StorageProofs Challenges (r:1 w:1)
+	/// Storage: StorageProofs Provers (r:1 w:0)
+	/// Storage: StorageProofs Records (r:1 w:1)
+	/// The range of component `p` is `[0, 32]`.
+	fn respond(p: u32, ) -> Weight {
+		Weight::from_parts(73_000_000, 3601)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: StorageProofs Deadlines (r:1 w:1)
+	/// Storage: StorageProofs Challenges (r:4 w:4)
+	/// Storage: StorageProofs Records (r:4 w:4)
+	/// The range of component `e` is `[0, 4]`.
+	fn expire_challenges(e: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 3526)
+			.saturating_add(Weight::from_parts(13_000_000, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2611).saturating_mul(e.into()))
+	}
+	/// Storage: StorageProofs HostingCount (r:1 w:0)
+	/// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// Storage: StorageProofs HostingSlots (r:4 w:0)
+	/// Storage: Institutions Institutions (r:4 w:0)
+	/// Storage: StorageProofs Hostings (r:4 w:0)
+	/// Storage: StorageProofs NextChallengeId (r:1 w:1)
+	/// Storage: StorageProofs Records (r:4 w:4)
+	/// Storage: StorageProofs Deadlines (r:1 w:1)
+	/// Storage: StorageProofs Challenges (r:0 w:4)
+	fn issue_challenges() -> Weight {
+		Weight::from_parts(121_000_000, 15370)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: System Digest (r:1 w:0)
+	/// Storage: StorageProofs Deferred (r:1 w:1)
+	/// Storage: StorageProofs PreviousAuthor (r:1 w:1)
+	fn note_author() -> Weight {
+		Weight::from_parts(7_000_000, 1650)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: StorageProofs Blobs (r:1 w:1)
+	fn commit_blob() -> Weight {
+		Weight::from_parts(24_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
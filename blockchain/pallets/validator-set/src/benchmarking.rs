@@ -0,0 +1,70 @@
+//! Benchmarking setup for pallet-validator-set
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ValidatorSetPallet;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use speem_primitives::InstitutionInspect;
+
+/// Have `validator` run for the institution of `admin`, returning the institution.
+fn accepted<T: Config>(admin: &T::AccountId, validator: T::AccountId) -> InstitutionId {
+	let institution = T::Institutions::institution_of(admin).expect("admin of an institution");
+	Pallet::<T>::set_validator(RawOrigin::Signed(admin.clone()).into(), validator.clone())
+		.expect("active admins can nominate");
+	Pallet::<T>::accept_nomination(RawOrigin::Signed(validator).into(), institution)
+		.expect("nominees can accept");
+	institution
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_validator() {
+		let caller: T::AccountId = whitelisted_caller();
+		let institution = T::Institutions::register_active_admin(&caller);
+		// Replacing a previous nomination is the more expensive path.
+		let previous: T::AccountId = account("previous", 0, 0);
+		Pallet::<T>::set_validator(RawOrigin::Signed(caller.clone()).into(), previous)
+			.expect("active admins can nominate");
+		let validator: T::AccountId = account("validator", 0, 0);
+
+		#[extrinsic_call]
+		set_validator(RawOrigin::Signed(caller), validator.clone());
+
+		assert_eq!(Nominations::<T>::get(institution), Some(validator));
+	}
+
+	#[benchmark]
+	fn accept_nomination() {
+		let admin: T::AccountId = account("admin", 0, 0);
+		T::Institutions::register_active_admin(&admin);
+		// Replacing a previous validator is the more expensive path.
+		let institution = accepted::<T>(&admin, account("previous", 0, 0));
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::set_validator(RawOrigin::Signed(admin).into(), caller.clone())
+			.expect("active admins can nominate");
+
+		#[extrinsic_call]
+		accept_nomination(RawOrigin::Signed(caller.clone()), institution);
+
+		assert_eq!(Validators::<T>::get(institution), Some(caller));
+	}
+
+	#[benchmark]
+	fn remove_validator() {
+		let caller: T::AccountId = whitelisted_caller();
+		let institution = T::Institutions::register_active_admin(&caller);
+		accepted::<T>(&caller, account("validator", 0, 0));
+
+		#[extrinsic_call]
+		remove_validator(RawOrigin::Signed(caller));
+
+		assert!(!Validators::<T>::contains_key(institution));
+	}
+
+	impl_benchmark_test_suite!(ValidatorSetPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
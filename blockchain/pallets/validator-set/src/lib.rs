@@ -0,0 +1,410 @@
+//! # Validator Set Pallet
+//!
+//! Lets the institutions of the consortium run the validators that author and finalize blocks.
+//!
+//! ## Overview
+//!
+//! The admins of an active institution nominate the account of the node their institution runs
+//! through [`set_validator`](Pallet::set_validator). The nomination only takes effect once that
+//! account [`accept_nomination`](Pallet::accept_nomination)s, so that no institution can claim an
+//! account it does not control. The operator of the node then registers its session keys with
+//! `pallet_session::set_keys`, as on any session-based chain.
+//!
+//! This pallet acts as `pallet_session`'s [`SessionManager`]: at every session boundary it plans
+//! the validator set of the session after next from the nominated validators whose institution is
+//! still active and whose session keys are registered, up to [`Config::MaxValidators`] of them in
+//! order of institution identifier. `pallet_session` then hands that set to Aura and GRANDPA, so
+//! institutions join and leave the authority sets without a new genesis. If no nominee is
+//! eligible, the current set is kept rather than halting the chain.
+//!
+//! ### Offences
+//!
+//! With `pallet_session::historical`, each validator of a session is identified by the institution
+//! it ran for, as recorded in [`InstitutionOf`]. That record is never removed, so misbehaviour
+//! remains attributable after an institution nominates another validator. This pallet handles the
+//! offences reported through `pallet_offences`, such as GRANDPA equivocations: each one is
+//! recorded in [`Offences`] and the offending institution is suspended, which drops its validator
+//! from the next planned session.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`set_validator`](Pallet::set_validator) - Nominate the validator account of an institution.
+//! - [`accept_nomination`](Pallet::accept_nomination) - Accept to run for a nominating institution.
+//! - [`remove_validator`](Pallet::remove_validator) - Withdraw an institution's validator.
+//!
+//! Run `cargo doc --package pallet-validator-set --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::{
+	sp_runtime::{traits::Convert, Perbill},
+	traits::Get,
+	weights::Weight,
+};
+use pallet_session::{historical, SessionManager};
+use sp_staking::{
+	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
+	SessionIndex,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+use speem_primitives::{InstitutionId, InstitutionInspect, InstitutionSuspend};
+
+const LOG_TARGET: &str = "runtime::validator-set";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{BuildGenesisConfig, ValidatorRegistration},
+		DefaultNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+
+	/// The record of an offence committed by an institution's validator.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct OffenceRecord<AccountId, BlockNumber> {
+		/// The validator that committed the offence.
+		pub validator: AccountId,
+		/// The share of its stake the offence would be slashed on a staking chain.
+		pub slash_fraction: Perbill,
+		/// The block in which the offence was reported.
+		pub reported_at: BlockNumber,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The registry of institutions allowed to run validators.
+		type Institutions: InstitutionInspect<Self::AccountId> + InstitutionSuspend;
+		/// Whether a validator has registered its session keys.
+		type ValidatorRegistration: ValidatorRegistration<Self::AccountId>;
+		/// The maximum number of validators in a session, at most the `MaxAuthorities` of the
+		/// consensus pallets.
+		#[pallet::constant]
+		type MaxValidators: Get<u32>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The validator account nominated by each institution.
+	#[pallet::storage]
+	pub type Validators<T: Config> =
+		StorageMap<_, Twox64Concat, InstitutionId, T::AccountId, OptionQuery>;
+
+	/// The account each institution has nominated as its validator, until that account accepts.
+	#[pallet::storage]
+	pub type Nominations<T: Config> =
+		StorageMap<_, Twox64Concat, InstitutionId, T::AccountId, OptionQuery>;
+
+	/// The institution each validator account runs, or has run, for, once it has accepted.
+	///
+	/// Entries are never removed, so that an account cannot be handed to another institution and
+	/// offences from past sessions remain attributable.
+	#[pallet::storage]
+	pub type InstitutionOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, InstitutionId, OptionQuery>;
+
+	/// Offences committed by the validators of each institution, keyed by institution and then by
+	/// the session in which they were committed.
+	#[pallet::storage]
+	pub type Offences<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		InstitutionId,
+		Twox64Concat,
+		SessionIndex,
+		OffenceRecord<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The validators nominated at genesis, as `(institution, validator)`.
+		pub validators: Vec<(InstitutionId, T::AccountId)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (institution, validator) in &self.validators {
+				assert!(
+					!Validators::<T>::contains_key(institution) &&
+						!InstitutionOf::<T>::contains_key(validator),
+					"genesis validators must be distinct, one per institution"
+				);
+				Validators::<T>::insert(institution, validator);
+				InstitutionOf::<T>::insert(validator, institution);
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An institution has nominated its validator, which has yet to accept.
+		ValidatorNominated {
+			/// The institution.
+			institution: InstitutionId,
+			/// The nominated validator account.
+			validator: T::AccountId,
+		},
+		/// A validator account has accepted to run for the institution that nominated it.
+		NominationAccepted {
+			/// The institution.
+			institution: InstitutionId,
+			/// The validator account.
+			validator: T::AccountId,
+		},
+		/// An institution has withdrawn its validator.
+		ValidatorRemoved {
+			/// The institution.
+			institution: InstitutionId,
+			/// The withdrawn validator account.
+			validator: T::AccountId,
+		},
+		/// An offence by an institution's validator has been recorded and the institution
+		/// suspended.
+		OffenceRecorded {
+			/// The institution the validator ran for.
+			institution: InstitutionId,
+			/// The offending validator.
+			validator: T::AccountId,
+			/// The session in which the offence was committed.
+			session: SessionIndex,
+			/// The severity of the offence.
+			slash_fraction: Perbill,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The signer is not an admin of an active institution.
+		NotActiveAdmin,
+		/// The signer is not an admin of any institution.
+		NotAdmin,
+		/// The account runs, or has run, for another institution.
+		AlreadyNominated,
+		/// The institution has not nominated a validator.
+		NoValidator,
+		/// The signer is not the account the institution has nominated.
+		NotNominated,
+		/// The institution is not active.
+		InactiveInstitution,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Nominate the validator account of the signer's institution.
+		///
+		/// The origin must be signed by an admin of an active institution. Any nomination the
+		/// institution has pending is replaced. Once `validator` has
+		/// [`accept_nomination`](Pallet::accept_nomination)ed, it replaces any validator the
+		/// institution had, from the session after next.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotActiveAdmin`] if the signer does not act for an active institution.
+		/// - [`Error::AlreadyNominated`] if `validator` runs, or has run, for another institution.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_validator())]
+		pub fn set_validator(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let institution = T::Institutions::institution_of(&who)
+				.filter(|institution| T::Institutions::is_active(*institution))
+				.ok_or(Error::<T>::NotActiveAdmin)?;
+			ensure!(
+				InstitutionOf::<T>::get(&validator).map_or(true, |other| other == institution),
+				Error::<T>::AlreadyNominated
+			);
+
+			Nominations::<T>::insert(institution, &validator);
+
+			Self::deposit_event(Event::ValidatorNominated { institution, validator });
+			Ok(())
+		}
+
+		/// Accept to run as the validator of `institution`, which has nominated the signer.
+		///
+		/// The origin must be signed by the nominated account. It replaces any validator the
+		/// institution had, from the session after next, and stays attributed to the institution
+		/// from then on.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotNominated`] if the institution has not nominated the signer.
+		/// - [`Error::InactiveInstitution`] if the institution is not active.
+		/// - [`Error::AlreadyNominated`] if the signer runs, or has run, for another institution.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::accept_nomination())]
+		pub fn accept_nomination(
+			origin: OriginFor<T>,
+			institution: InstitutionId,
+		) -> DispatchResult {
+			let validator = ensure_signed(origin)?;
+
+			ensure!(
+				Nominations::<T>::get(institution).as_ref() == Some(&validator),
+				Error::<T>::NotNominated
+			);
+			ensure!(T::Institutions::is_active(institution), Error::<T>::InactiveInstitution);
+			ensure!(
+				InstitutionOf::<T>::get(&validator).map_or(true, |other| other == institution),
+				Error::<T>::AlreadyNominated
+			);
+
+			Nominations::<T>::remove(institution);
+			Validators::<T>::insert(institution, &validator);
+			InstitutionOf::<T>::insert(&validator, institution);
+
+			Self::deposit_event(Event::NominationAccepted { institution, validator });
+			Ok(())
+		}
+
+		/// Withdraw the validator of the signer's institution.
+		///
+		/// The origin must be signed by an admin of the institution, which need not be active. The
+		/// validator leaves the set from the session after next.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotAdmin`] if the signer does not act for an institution.
+		/// - [`Error::NoValidator`] if the institution has not nominated a validator.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_validator())]
+		pub fn remove_validator(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let institution = T::Institutions::institution_of(&who).ok_or(Error::<T>::NotAdmin)?;
+			let validator = Validators::<T>::take(institution).ok_or(Error::<T>::NoValidator)?;
+
+			Self::deposit_event(Event::ValidatorRemoved { institution, validator });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The validators eligible for the next planned session: those nominated by an active
+		/// institution and with registered session keys, in order of institution identifier and
+		/// capped at [`Config::MaxValidators`], each with the institution it runs for.
+		pub fn eligible_validators() -> Vec<(T::AccountId, InstitutionId)> {
+			let mut validators = Validators::<T>::iter()
+				.filter(|(institution, validator)| {
+					T::Institutions::is_active(*institution) &&
+						T::ValidatorRegistration::is_registered(validator)
+				})
+				.map(|(institution, validator)| (validator, institution))
+				.collect::<Vec<_>>();
+			validators.sort_by_key(|(_, institution)| *institution);
+			validators.truncate(T::MaxValidators::get() as usize);
+			validators
+		}
+
+		/// Record an offence by `validator`, running for `institution`, and suspend the
+		/// institution.
+		fn note_offence(
+			validator: T::AccountId,
+			institution: InstitutionId,
+			session: SessionIndex,
+			slash_fraction: Perbill,
+		) {
+			Offences::<T>::mutate(institution, session, |record| match record {
+				// Keep the most severe of several offences in one session.
+				Some(record) if record.slash_fraction >= slash_fraction => {},
+				_ =>
+					*record = Some(OffenceRecord {
+						validator: validator.clone(),
+						slash_fraction,
+						reported_at: frame_system::Pallet::<T>::block_number(),
+					}),
+			});
+			T::Institutions::suspend(institution);
+
+			Self::deposit_event(Event::OffenceRecorded {
+				institution,
+				validator,
+				session,
+				slash_fraction,
+			});
+		}
+	}
+}
+
+impl<T: Config> historical::SessionManager<T::AccountId, InstitutionId> for Pallet<T> {
+	fn new_session(new_index: SessionIndex) -> Option<Vec<(T::AccountId, InstitutionId)>> {
+		let validators = Self::eligible_validators();
+		if validators.is_empty() {
+			log::warn!(
+				target: LOG_TARGET,
+				"no eligible validators for session {new_index}, keeping the current set",
+			);
+			return None
+		}
+		Some(validators)
+	}
+
+	fn end_session(_: SessionIndex) {}
+
+	fn start_session(_: SessionIndex) {}
+}
+
+impl<T: Config> SessionManager<T::AccountId> for Pallet<T> {
+	fn new_session(new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
+		<Self as historical::SessionManager<_, _>>::new_session(new_index)
+			.map(|validators| validators.into_iter().map(|(validator, _)| validator).collect())
+	}
+
+	fn end_session(_: SessionIndex) {}
+
+	fn start_session(_: SessionIndex) {}
+}
+
+/// Identifies a validator by the institution it runs for, as the `FullIdentificationOf` of
+/// `pallet_session::historical`.
+pub struct InstitutionOfValidator<T>(PhantomData<T>);
+
+impl<T: Config> Convert<T::AccountId, Option<InstitutionId>> for InstitutionOfValidator<T> {
+	fn convert(validator: T::AccountId) -> Option<InstitutionId> {
+		InstitutionOf::<T>::get(validator)
+	}
+}
+
+impl<T: Config> OnOffenceHandler<T::AccountId, (T::AccountId, InstitutionId), Weight>
+	for Pallet<T>
+{
+	fn on_offence(
+		offenders: &[OffenceDetails<T::AccountId, (T::AccountId, InstitutionId)>],
+		slash_fraction: &[Perbill],
+		session: SessionIndex,
+		_: DisableStrategy,
+	) -> Weight {
+		for (details, slash_fraction) in offenders.iter().zip(slash_fraction) {
+			let (validator, institution) = details.offender.clone();
+			Self::note_offence(validator, institution, session, *slash_fraction);
+		}
+		// Reading and writing the offence record, and suspending the institution.
+		T::DbWeight::get().reads_writes(3, 3).saturating_mul(offenders.len() as u64)
+	}
+}
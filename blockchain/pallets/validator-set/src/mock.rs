@@ -0,0 +1,122 @@
+use crate as pallet_validator_set;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU16, ConstU32, ConstU64, ValidatorRegistration},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use speem_primitives::{InstitutionId, InstitutionInspect, InstitutionSuspend};
+use std::{
+	cell::RefCell,
+	collections::{BTreeMap, BTreeSet},
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		ValidatorSet: pallet_validator_set,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+thread_local! {
+	static ADMINS: RefCell<BTreeMap<u64, InstitutionId>> = RefCell::new(BTreeMap::new());
+	static SUSPENDED: RefCell<BTreeSet<InstitutionId>> = RefCell::new(BTreeSet::new());
+	static KEYS: RefCell<BTreeSet<u64>> = RefCell::new(BTreeSet::new());
+}
+
+/// A stand-in for the institution registry, in which account `n` administers institution `n - 1`
+/// for `n` in `1..=5`.
+pub struct MockInstitutions;
+
+impl InstitutionSuspend for MockInstitutions {
+	fn suspend(institution: InstitutionId) {
+		SUSPENDED.with(|suspended| suspended.borrow_mut().insert(institution));
+	}
+}
+
+impl InstitutionInspect<u64> for MockInstitutions {
+	fn institution_of(who: &u64) -> Option<InstitutionId> {
+		ADMINS.with(|admins| admins.borrow().get(who).copied())
+	}
+
+	fn is_active(institution: InstitutionId) -> bool {
+		ADMINS.with(|admins| admins.borrow().values().any(|id| *id == institution)) &&
+			!SUSPENDED.with(|suspended| suspended.borrow().contains(&institution))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn register_active_admin(who: &u64) -> InstitutionId {
+		ADMINS.with(|admins| {
+			let mut admins = admins.borrow_mut();
+			let id = admins.len() as InstitutionId;
+			admins.insert(*who, id);
+			id
+		})
+	}
+}
+
+/// A stand-in for `pallet_session`'s record of registered session keys.
+pub struct MockSessionKeys;
+
+impl MockSessionKeys {
+	/// Register session keys for `validator`.
+	pub fn register(validator: u64) {
+		KEYS.with(|keys| keys.borrow_mut().insert(validator));
+	}
+}
+
+impl ValidatorRegistration<u64> for MockSessionKeys {
+	fn is_registered(validator: &u64) -> bool {
+		KEYS.with(|keys| keys.borrow().contains(validator))
+	}
+}
+
+impl pallet_validator_set::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Institutions = MockInstitutions;
+	type ValidatorRegistration = MockSessionKeys;
+	type MaxValidators = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ADMINS.with(|admins| *admins.borrow_mut() = (1..=5).map(|who| (who, who as u32 - 1)).collect());
+	SUSPENDED.with(|suspended| suspended.borrow_mut().clear());
+	KEYS.with(|keys| keys.borrow_mut().clear());
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+}
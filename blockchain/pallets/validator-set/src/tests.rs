@@ -0,0 +1,256 @@
+use crate::{
+	mock::*, Error, Event, InstitutionOf, InstitutionOfValidator, Nominations, Offences, Validators,
+};
+use frame_support::{assert_noop, assert_ok};
+use pallet_session::{historical, SessionManager};
+use sp_runtime::{traits::Convert, Perbill};
+use sp_staking::offence::{DisableStrategy, OffenceDetails, OnOffenceHandler};
+use speem_primitives::{InstitutionInspect, InstitutionSuspend};
+
+/// Have `validator` run for the institution of `admin`, which account `n` administers as `n - 1`.
+fn accepted(admin: u64, validator: u64) {
+	assert_ok!(ValidatorSet::set_validator(RuntimeOrigin::signed(admin), validator));
+	assert_ok!(ValidatorSet::accept_nomination(RuntimeOrigin::signed(validator), admin as u32 - 1));
+}
+
+fn nominate(admin: u64, validator: u64) {
+	accepted(admin, validator);
+	MockSessionKeys::register(validator);
+}
+
+#[test]
+fn active_admins_nominate_validators() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		assert_ok!(ValidatorSet::set_validator(RuntimeOrigin::signed(1), 11));
+		System::assert_last_event(
+			Event::ValidatorNominated { institution: 0, validator: 11 }.into(),
+		);
+		assert_eq!(Nominations::<Test>::get(0), Some(11));
+		assert_ok!(ValidatorSet::accept_nomination(RuntimeOrigin::signed(11), 0));
+		System::assert_last_event(
+			Event::NominationAccepted { institution: 0, validator: 11 }.into(),
+		);
+		assert_eq!(Nominations::<Test>::get(0), None);
+		assert_eq!(Validators::<Test>::get(0), Some(11));
+		assert_eq!(InstitutionOf::<Test>::get(11), Some(0));
+
+		assert_noop!(
+			ValidatorSet::set_validator(RuntimeOrigin::signed(9), 19),
+			Error::<Test>::NotActiveAdmin
+		);
+		<MockInstitutions as InstitutionSuspend>::suspend(1);
+		assert_noop!(
+			ValidatorSet::set_validator(RuntimeOrigin::signed(2), 12),
+			Error::<Test>::NotActiveAdmin
+		);
+		assert_noop!(
+			ValidatorSet::set_validator(RuntimeOrigin::signed(3), 11),
+			Error::<Test>::AlreadyNominated
+		);
+	});
+}
+
+#[test]
+fn nominees_accept_before_they_are_attributed() {
+	new_test_ext().execute_with(|| {
+		// An institution nominating an account it does not control claims nothing.
+		assert_ok!(ValidatorSet::set_validator(RuntimeOrigin::signed(2), 11));
+		assert_eq!(Validators::<Test>::get(1), None);
+		assert_eq!(InstitutionOf::<Test>::get(11), None);
+		assert_noop!(
+			ValidatorSet::accept_nomination(RuntimeOrigin::signed(12), 1),
+			Error::<Test>::NotNominated
+		);
+
+		// The account accepts the nomination it wants.
+		accepted(1, 11);
+		assert_noop!(
+			ValidatorSet::accept_nomination(RuntimeOrigin::signed(11), 1),
+			Error::<Test>::AlreadyNominated
+		);
+		assert_noop!(
+			ValidatorSet::accept_nomination(RuntimeOrigin::signed(11), 0),
+			Error::<Test>::NotNominated
+		);
+
+		assert_ok!(ValidatorSet::set_validator(RuntimeOrigin::signed(3), 13));
+		<MockInstitutions as InstitutionSuspend>::suspend(2);
+		assert_noop!(
+			ValidatorSet::accept_nomination(RuntimeOrigin::signed(13), 2),
+			Error::<Test>::InactiveInstitution
+		);
+	});
+}
+
+#[test]
+fn nominations_replace_the_previous_validator() {
+	new_test_ext().execute_with(|| {
+		accepted(1, 11);
+		assert_ok!(ValidatorSet::set_validator(RuntimeOrigin::signed(1), 21));
+		// The previous validator runs until the new one accepts.
+		assert_eq!(Validators::<Test>::get(0), Some(11));
+		assert_ok!(ValidatorSet::accept_nomination(RuntimeOrigin::signed(21), 0));
+
+		assert_eq!(Validators::<Test>::get(0), Some(21));
+		assert_eq!(InstitutionOf::<Test>::get(21), Some(0));
+
+		// The previous validator stays attributed to the institution it ran for.
+		assert_eq!(InstitutionOf::<Test>::get(11), Some(0));
+		assert_noop!(
+			ValidatorSet::set_validator(RuntimeOrigin::signed(2), 11),
+			Error::<Test>::AlreadyNominated
+		);
+		accepted(1, 11);
+		assert_eq!(Validators::<Test>::get(0), Some(11));
+	});
+}
+
+#[test]
+fn validators_can_be_removed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			ValidatorSet::remove_validator(RuntimeOrigin::signed(1)),
+			Error::<Test>::NoValidator
+		);
+		assert_noop!(
+			ValidatorSet::remove_validator(RuntimeOrigin::signed(9)),
+			Error::<Test>::NotAdmin
+		);
+
+		accepted(1, 11);
+		// Suspended institutions can still withdraw their validator.
+		<MockInstitutions as InstitutionSuspend>::suspend(0);
+		assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::ValidatorRemoved { institution: 0, validator: 11 }.into());
+		assert_eq!(Validators::<Test>::get(0), None);
+		assert_eq!(InstitutionOf::<Test>::get(11), Some(0));
+	});
+}
+
+#[test]
+fn sessions_rotate_eligible_validators() {
+	new_test_ext().execute_with(|| {
+		nominate(2, 12);
+		nominate(1, 11);
+		// Accepted, but without session keys.
+		accepted(3, 13);
+
+		assert_eq!(ValidatorSet::new_session(1), Some(vec![11, 12]));
+
+		MockSessionKeys::register(13);
+		assert_eq!(ValidatorSet::new_session(2), Some(vec![11, 12, 13]));
+
+		<MockInstitutions as InstitutionSuspend>::suspend(0);
+		assert_eq!(ValidatorSet::new_session(3), Some(vec![12, 13]));
+	});
+}
+
+#[test]
+fn sessions_are_capped_at_max_validators() {
+	new_test_ext().execute_with(|| {
+		for admin in (1..=5).rev() {
+			nominate(admin, admin + 10);
+		}
+
+		assert_eq!(ValidatorSet::new_session(1), Some(vec![11, 12, 13]));
+	});
+}
+
+#[test]
+fn empty_sessions_keep_the_current_set() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ValidatorSet::new_session(1), None);
+
+		nominate(1, 11);
+		<MockInstitutions as InstitutionSuspend>::suspend(0);
+		assert_eq!(ValidatorSet::new_session(2), None);
+	});
+}
+
+#[test]
+fn historical_sessions_identify_validators_by_institution() {
+	new_test_ext().execute_with(|| {
+		nominate(2, 12);
+		nominate(1, 11);
+
+		assert_eq!(
+			<ValidatorSet as historical::SessionManager<_, _>>::new_session(1),
+			Some(vec![(11, 0), (12, 1)])
+		);
+		assert_eq!(InstitutionOfValidator::<Test>::convert(12), Some(1));
+		assert_eq!(InstitutionOfValidator::<Test>::convert(13), None);
+	});
+}
+
+#[test]
+fn offences_are_recorded_and_suspend_the_institution() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(7);
+		nominate(1, 11);
+		nominate(2, 12);
+
+		let offenders = [
+			OffenceDetails { offender: (11, 0), reporters: vec![] },
+			OffenceDetails { offender: (12, 1), reporters: vec![9] },
+		];
+		ValidatorSet::on_offence(
+			&offenders,
+			&[Perbill::from_percent(20), Perbill::from_percent(5)],
+			3,
+			DisableStrategy::WhenSlashed,
+		);
+
+		let record = Offences::<Test>::get(0, 3).expect("offence was recorded");
+		assert_eq!(
+			(record.validator, record.slash_fraction, record.reported_at),
+			(11, Perbill::from_percent(20), 7)
+		);
+		assert_eq!(Offences::<Test>::get(1, 3).map(|record| record.validator), Some(12));
+		System::assert_has_event(
+			Event::OffenceRecorded {
+				institution: 0,
+				validator: 11,
+				session: 3,
+				slash_fraction: Perbill::from_percent(20),
+			}
+			.into(),
+		);
+		assert!(!MockInstitutions::is_active(0));
+		assert!(!MockInstitutions::is_active(1));
+
+		// Suspended institutions drop out of the next planned session.
+		nominate(3, 13);
+		assert_eq!(ValidatorSet::new_session(4), Some(vec![13]));
+	});
+}
+
+#[test]
+fn the_most_severe_offence_of_a_session_is_kept() {
+	new_test_ext().execute_with(|| {
+		let offenders = [OffenceDetails { offender: (11, 0), reporters: vec![] }];
+		let report = |percent| {
+			ValidatorSet::on_offence(
+				&offenders,
+				&[Perbill::from_percent(percent)],
+				3,
+				DisableStrategy::WhenSlashed,
+			)
+		};
+
+		report(10);
+		report(5);
+		assert_eq!(
+			Offences::<Test>::get(0, 3).map(|record| record.slash_fraction),
+			Some(Perbill::from_percent(10))
+		);
+		report(30);
+		assert_eq!(
+			Offences::<Test>::get(0, 3).map(|record| record.slash_fraction),
+			Some(Perbill::from_percent(30))
+		);
+	});
+}
@@ -0,0 +1,81 @@
+
+//! Weights for pallet_validator_set
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_validator_set` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_validator_set.
+pub trait WeightInfo {
+	fn set_validator() -> Weight;
+	fn remove_validator() -> Weight;
+	fn accept_nomination() -> Weight;
+}
+
+/// Weights for pallet_validator_set using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: ValidatorSet InstitutionOf (r:1 w:0)
+	/// Storage: ValidatorSet Nominations (r:0 w:1)
+	fn set_validator() -> Weight {
+		Weight::from_parts(20_000_000, 4585)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: ValidatorSet Validators (r:1 w:1)
+	/// Storage: ValidatorSet InstitutionOf (r:0 w:1)
+	fn remove_validator() -> Weight {
+		Weight::from_parts(16_000_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: ValidatorSet Nominations (r:1 w:1)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: ValidatorSet InstitutionOf (r:1 w:1)
+	/// Storage: ValidatorSet Validators (r:0 w:1)
+	fn accept_nomination() -> Weight {
+		Weight::from_parts(24_000_000, 4585)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: ValidatorSet InstitutionOf (r:1 w:0)
+	/// Storage: ValidatorSet Nominations (r:0 w:1)
+	fn set_validator() -> Weight {
+		Weight::from_parts(20_000_000, 4585)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: ValidatorSet Validators (r:1 w:1)
+	/// Storage: ValidatorSet InstitutionOf (r:0 w:1)
+	fn remove_validator() -> Weight {
+		Weight::from_parts(16_000_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: ValidatorSet Nominations (r:1 w:1)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: ValidatorSet InstitutionOf (r:1 w:1)
+	/// Storage: ValidatorSet Validators (r:0 w:1)
+	fn accept_nomination() -> Weight {
+		Weight::from_parts(24_000_000, 4585)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
@@ -0,0 +1,137 @@
+//! Benchmarking setup for pallet-venues
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as VenuesPallet;
+use frame_benchmarking::v2::*;
+use frame_support::{sp_runtime::Percent, traits::Get};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_std::{vec, vec::Vec};
+
+const ISSN: Issn = *b"0317-8471";
+
+/// The most demanding review policy.
+fn max_policy<T: Config>() -> ReviewPolicy {
+	ReviewPolicy {
+		blinding: ReviewBlinding::DoubleBlind,
+		reviewers: T::MaxReviewers::get(),
+		acceptance_threshold: Percent::from_percent(100),
+	}
+}
+
+/// An editorial board of [`Config::MaxBoardSize`] members.
+fn max_board<T: Config>(seed: u32) -> Vec<T::AccountId> {
+	(0..T::MaxBoardSize::get()).map(|i| account("board", i, seed)).collect()
+}
+
+/// Create a venue of `kind` administered by `admin`, with the longest name and the largest board.
+fn new_venue<T: Config>(admin: &T::AccountId, kind: VenueKind) -> VenueId {
+	T::Institutions::register_active_admin(admin);
+	Pallet::<T>::create_venue(
+		RawOrigin::Signed(admin.clone()).into(),
+		kind,
+		ISSN,
+		vec![b'v'; T::MaxNameLength::get() as usize],
+		max_board::<T>(0),
+		max_policy::<T>(),
+	)
+	.expect("active admins can create venues");
+	NextVenueId::<T>::get() - 1
+}
+
+/// Fill the submission windows of `venue` with windows that have closed by block `now`, but for
+/// the last one, which is open.
+fn fill_windows<T: Config>(admin: &T::AccountId, venue: VenueId, now: BlockNumberFor<T>) {
+	let count = T::MaxSubmissionWindows::get();
+	for i in 0..count {
+		let closes = if i + 1 == count { now } else { 1u32.into() };
+		Pallet::<T>::add_submission_window(
+			RawOrigin::Signed(admin.clone()).into(),
+			venue,
+			0u32.into(),
+			closes,
+		)
+		.expect("windows fit");
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_venue() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Institutions::register_active_admin(&caller);
+		let name = vec![b'v'; T::MaxNameLength::get() as usize];
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			VenueKind::Journal,
+			ISSN,
+			name,
+			max_board::<T>(0),
+			max_policy::<T>(),
+		);
+
+		assert_eq!(VenueByIssn::<T>::get(ISSN), Some(0));
+	}
+
+	#[benchmark]
+	fn set_board() {
+		let caller: T::AccountId = whitelisted_caller();
+		let venue = new_venue::<T>(&caller, VenueKind::Journal);
+		let board = max_board::<T>(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), venue, board.clone());
+
+		assert_eq!(Venues::<T>::get(venue).expect("venue exists").board.into_inner(), board);
+	}
+
+	#[benchmark]
+	fn set_policy() {
+		let caller: T::AccountId = whitelisted_caller();
+		let venue = new_venue::<T>(&caller, VenueKind::Journal);
+		let policy = ReviewPolicy { blinding: ReviewBlinding::Open, ..max_policy::<T>() };
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), venue, policy);
+
+		assert_eq!(Venues::<T>::get(venue).expect("venue exists").policy, policy);
+	}
+
+	#[benchmark]
+	fn add_submission_window() {
+		let caller: T::AccountId = whitelisted_caller();
+		let venue = new_venue::<T>(&caller, VenueKind::Conference);
+		// Every window but the last has closed by block 2, and is dropped to make room.
+		fill_windows::<T>(&caller, venue, 10u32.into());
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), venue, 20u32.into(), 30u32.into());
+
+		assert_eq!(Venues::<T>::get(venue).expect("venue exists").windows.len(), 2);
+	}
+
+	#[benchmark]
+	fn submit() {
+		let admin: T::AccountId = account("admin", 0, 0);
+		let venue = new_venue::<T>(&admin, VenueKind::Conference);
+		// Only the last window is open, so that every window is checked.
+		let now = 10u32.into();
+		fill_windows::<T>(&admin, venue, now);
+		frame_system::Pallet::<T>::set_block_number(now);
+		let (document, author) = T::BenchmarkHelper::registered_document();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(author), venue, document);
+
+		assert!(Submissions::<T>::contains_key(document));
+	}
+
+	impl_benchmark_test_suite!(VenuesPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,570 @@
+//! # Venues Pallet
+//!
+//! Represents the journals and conferences manuscripts are published in.
+//!
+//! ## Overview
+//!
+//! A [`Venue`] is created by an admin of an active institution, which then owns it. It carries:
+//!
+//! - its [`VenueKind`] and name;
+//! - an ISSN-style [`Issn`] identifier, unique on chain and validated with the ISSN check digit;
+//! - its editorial board, the accounts expected to handle its submissions;
+//! - its [`ReviewPolicy`]: how blind the review is, how many reviewers each submission gets and the
+//!   share of favourable reviews required for acceptance;
+//! - its [`SubmissionWindow`]s. A journal without any window accepts submissions at any time;
+//!   otherwise, submissions are only accepted within one of the windows.
+//!
+//! The admins of the owning institution maintain the board, the policy and the windows. Authors of
+//! a final manuscript in the registry (see [`Config::Documents`]) [`submit`](Pallet::submit) it
+//! to a venue, once and before its review has opened; the venue's policy at that time is stored
+//! with the submission, so that later policy changes do not affect submissions already under way.
+//!
+//! The policy binds the review of the submission: the pallet implements [`VenueSubmissions`], from
+//! which the reviews pallet learns who may edit the submission, i.e. the venue's board, how blind
+//! its review must be, how many reviewers it gets and the threshold an acceptance must meet.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`create_venue`](Pallet::create_venue) - Create a venue owned by the signer's institution.
+//! - [`set_board`](Pallet::set_board) - Replace the editorial board of a venue.
+//! - [`set_policy`](Pallet::set_policy) - Change the review policy of a venue.
+//! - [`add_submission_window`](Pallet::add_submission_window) - Schedule a submission window.
+//! - [`submit`](Pallet::submit) - Submit a registered manuscript to a venue.
+//!
+//! Run `cargo doc --package pallet-venues --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use speem_primitives::{
+	DocumentAuthors, InstitutionId, InstitutionInspect, ReviewInspect, VenueSubmissions,
+};
+pub use speem_primitives::{ReviewBlinding, ReviewPolicy};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*, traits::Contains, CloneNoBound, EqNoBound, PartialEqNoBound,
+		RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	/// Identifier of a venue.
+	pub type VenueId = u32;
+
+	/// An ISSN-style identifier, e.g. `*b"0317-8471"`: seven digits and a check character, which
+	/// is a digit or `X`, with a hyphen after the fourth.
+	pub type Issn = [u8; 9];
+
+	/// Whether a venue is a journal or a conference.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum VenueKind {
+		/// A journal, publishing submissions on a rolling basis.
+		Journal,
+		/// A conference, publishing the submissions of each edition in its proceedings.
+		Conference,
+	}
+
+	/// A period during which a venue accepts submissions.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct SubmissionWindow<BlockNumber> {
+		/// The first block in which submissions are accepted.
+		pub opens: BlockNumber,
+		/// The last block in which submissions are accepted.
+		pub closes: BlockNumber,
+	}
+
+	impl<BlockNumber: PartialOrd> SubmissionWindow<BlockNumber> {
+		/// Whether the window is open in block `now`.
+		pub fn contains(&self, now: &BlockNumber) -> bool {
+			self.opens <= *now && *now <= self.closes
+		}
+	}
+
+	/// A journal or conference.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Venue<T: Config> {
+		/// Whether the venue is a journal or a conference.
+		pub kind: VenueKind,
+		/// The institution that owns the venue.
+		pub institution: InstitutionId,
+		/// The venue's identifier.
+		pub identifier: Issn,
+		/// The name of the venue, UTF-8 encoded.
+		pub name: BoundedVec<u8, T::MaxNameLength>,
+		/// The members of the editorial board.
+		pub board: BoundedVec<T::AccountId, T::MaxBoardSize>,
+		/// How the venue's submissions are reviewed.
+		pub policy: ReviewPolicy,
+		/// The periods during which the venue accepts submissions, in the order they were added.
+		pub windows: BoundedVec<SubmissionWindow<BlockNumberFor<T>>, T::MaxSubmissionWindows>,
+	}
+
+	/// A manuscript submitted to a venue.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct VenueSubmission<T: Config> {
+		/// The venue the manuscript was submitted to.
+		pub venue: VenueId,
+		/// The venue's review policy when the manuscript was submitted.
+		pub policy: ReviewPolicy,
+		/// The author who submitted it.
+		pub submitter: T::AccountId,
+		/// The block in which it was submitted.
+		pub submitted_at: BlockNumberFor<T>,
+	}
+
+	/// Provides benchmarks with state that this pallet does not control.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Hash, AccountId> {
+		/// Make sure a document is final in the registry and return its content hash and one of
+		/// its authors.
+		fn registered_document() -> (Hash, AccountId);
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The registry of institutions allowed to own venues.
+		type Institutions: InstitutionInspect<Self::AccountId>;
+		/// The registry of documents that can be submitted to venues, and of their authors.
+		type Documents: Contains<Self::Hash> + DocumentAuthors<Self::AccountId, Self::Hash>;
+		/// The reviews under way, which manuscripts can no longer be submitted to a venue once
+		/// they have opened.
+		type Reviews: ReviewInspect<Self::Hash>;
+		/// The maximum length of a venue's name, in bytes.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+		/// The maximum number of members of an editorial board.
+		#[pallet::constant]
+		type MaxBoardSize: Get<u32>;
+		/// The maximum number of reviewers a review policy can ask for.
+		#[pallet::constant]
+		type MaxReviewers: Get<u32>;
+		/// The maximum number of submission windows a venue keeps.
+		#[pallet::constant]
+		type MaxSubmissionWindows: Get<u32>;
+		/// Helper for benchmarks to obtain a document the registry contains.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::Hash, Self::AccountId>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The identifier the next venue will be created with.
+	#[pallet::storage]
+	pub type NextVenueId<T> = StorageValue<_, VenueId, ValueQuery>;
+
+	/// Venues, keyed by identifier.
+	#[pallet::storage]
+	pub type Venues<T: Config> = StorageMap<_, Twox64Concat, VenueId, Venue<T>, OptionQuery>;
+
+	/// The venue each ISSN-style identifier is assigned to.
+	#[pallet::storage]
+	pub type VenueByIssn<T> = StorageMap<_, Blake2_128Concat, Issn, VenueId, OptionQuery>;
+
+	/// Manuscripts submitted to venues, keyed by content hash.
+	#[pallet::storage]
+	pub type Submissions<T: Config> =
+		StorageMap<_, Identity, T::Hash, VenueSubmission<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A venue has been created.
+		VenueCreated {
+			/// The new venue.
+			venue: VenueId,
+			/// The institution that owns it.
+			institution: InstitutionId,
+			/// Whether it is a journal or a conference.
+			kind: VenueKind,
+			/// Its identifier.
+			identifier: Issn,
+		},
+		/// The editorial board of a venue has been replaced.
+		BoardUpdated {
+			/// The venue.
+			venue: VenueId,
+			/// The new members of the board.
+			board: BoundedVec<T::AccountId, T::MaxBoardSize>,
+		},
+		/// The review policy of a venue has changed.
+		PolicyUpdated {
+			/// The venue.
+			venue: VenueId,
+			/// The new policy.
+			policy: ReviewPolicy,
+		},
+		/// A submission window has been scheduled for a venue.
+		SubmissionWindowAdded {
+			/// The venue.
+			venue: VenueId,
+			/// The window.
+			window: SubmissionWindow<BlockNumberFor<T>>,
+		},
+		/// A manuscript has been submitted to a venue.
+		Submitted {
+			/// The content hash of the manuscript.
+			content_hash: T::Hash,
+			/// The venue.
+			venue: VenueId,
+			/// The review policy the manuscript will be reviewed under.
+			policy: ReviewPolicy,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The signer is not an admin of an active institution.
+		NotActiveAdmin,
+		/// The identifier is not a well-formed ISSN with a valid check character.
+		InvalidIssn,
+		/// Another venue already has the identifier.
+		IssnTaken,
+		/// The name is longer than [`Config::MaxNameLength`] allows.
+		NameTooLong,
+		/// The editorial board has no members.
+		EmptyBoard,
+		/// The editorial board has more members than [`Config::MaxBoardSize`] allows.
+		BoardTooLarge,
+		/// The editorial board lists a member more than once.
+		DuplicateBoardMember,
+		/// The policy asks for no reviewers or more than [`Config::MaxReviewers`].
+		InvalidReviewerCount,
+		/// No venue exists with the given identifier.
+		UnknownVenue,
+		/// The signer is not an admin of the institution that owns the venue.
+		NotVenueAdmin,
+		/// The window closes before it opens, or has already closed.
+		InvalidWindow,
+		/// The venue already has [`Config::MaxSubmissionWindows`] windows that have not closed.
+		TooManyWindows,
+		/// The document is not a final entry of the registry.
+		UnregisteredDocument,
+		/// The signer is not an author of the document.
+		NotAuthor,
+		/// The document has already been submitted to a venue.
+		AlreadySubmitted,
+		/// The venue does not accept submissions now.
+		SubmissionsClosed,
+		/// The review of the document has already opened outside any venue.
+		AlreadyUnderReview,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a venue owned by the signer's institution.
+		///
+		/// The origin must be signed by an admin of an active institution. The venue starts without
+		/// submission windows.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NotActiveAdmin`] if the signer does not act for an active institution.
+		/// - [`Error::InvalidIssn`] if `identifier` is not a valid ISSN.
+		/// - [`Error::IssnTaken`] if another venue already has `identifier`.
+		/// - [`Error::NameTooLong`] if `name` exceeds [`Config::MaxNameLength`].
+		/// - [`Error::EmptyBoard`], [`Error::BoardTooLarge`] or [`Error::DuplicateBoardMember`] if
+		///   `board` is not a valid editorial board.
+		/// - [`Error::InvalidReviewerCount`] if `policy` asks for an invalid number of reviewers.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_venue())]
+		pub fn create_venue(
+			origin: OriginFor<T>,
+			kind: VenueKind,
+			identifier: Issn,
+			name: Vec<u8>,
+			board: Vec<T::AccountId>,
+			policy: ReviewPolicy,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let institution = T::Institutions::institution_of(&who)
+				.filter(|institution| T::Institutions::is_active(*institution))
+				.ok_or(Error::<T>::NotActiveAdmin)?;
+			ensure!(is_valid_issn(&identifier), Error::<T>::InvalidIssn);
+			ensure!(!VenueByIssn::<T>::contains_key(identifier), Error::<T>::IssnTaken);
+			let name: BoundedVec<_, T::MaxNameLength> =
+				name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+			let board = Self::bound_board(board)?;
+			Self::ensure_valid_policy(&policy)?;
+
+			let venue = NextVenueId::<T>::get();
+			NextVenueId::<T>::put(venue.saturating_add(1));
+			Venues::<T>::insert(
+				venue,
+				Venue {
+					kind,
+					institution,
+					identifier,
+					name,
+					board,
+					policy,
+					windows: BoundedVec::new(),
+				},
+			);
+			VenueByIssn::<T>::insert(identifier, venue);
+
+			Self::deposit_event(Event::VenueCreated { venue, institution, kind, identifier });
+			Ok(())
+		}
+
+		/// Replace the editorial board of a venue.
+		///
+		/// The origin must be signed by an admin of the active institution that owns the venue.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownVenue`] if no venue exists with identifier `venue`.
+		/// - [`Error::NotVenueAdmin`] if the signer does not act for the venue's institution.
+		/// - [`Error::EmptyBoard`], [`Error::BoardTooLarge`] or [`Error::DuplicateBoardMember`] if
+		///   `board` is not a valid editorial board.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_board())]
+		pub fn set_board(
+			origin: OriginFor<T>,
+			venue: VenueId,
+			board: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut record = Self::administered_venue(&who, venue)?;
+			record.board = Self::bound_board(board)?;
+			let board = record.board.clone();
+			Venues::<T>::insert(venue, record);
+
+			Self::deposit_event(Event::BoardUpdated { venue, board });
+			Ok(())
+		}
+
+		/// Change the review policy of a venue.
+		///
+		/// The origin must be signed by an admin of the active institution that owns the venue.
+		/// Manuscripts already submitted keep the policy they were submitted under.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownVenue`] if no venue exists with identifier `venue`.
+		/// - [`Error::NotVenueAdmin`] if the signer does not act for the venue's institution.
+		/// - [`Error::InvalidReviewerCount`] if `policy` asks for an invalid number of reviewers.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_policy())]
+		pub fn set_policy(
+			origin: OriginFor<T>,
+			venue: VenueId,
+			policy: ReviewPolicy,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut record = Self::administered_venue(&who, venue)?;
+			Self::ensure_valid_policy(&policy)?;
+			record.policy = policy;
+			Venues::<T>::insert(venue, record);
+
+			Self::deposit_event(Event::PolicyUpdated { venue, policy });
+			Ok(())
+		}
+
+		/// Schedule a period during which a venue accepts submissions.
+		///
+		/// The origin must be signed by an admin of the active institution that owns the venue.
+		/// Windows that have already closed are dropped to make room for the new one.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnknownVenue`] if no venue exists with identifier `venue`.
+		/// - [`Error::NotVenueAdmin`] if the signer does not act for the venue's institution.
+		/// - [`Error::InvalidWindow`] if `closes` is before `opens` or has already passed.
+		/// - [`Error::TooManyWindows`] if the venue has no room for another window.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::add_submission_window())]
+		pub fn add_submission_window(
+			origin: OriginFor<T>,
+			venue: VenueId,
+			opens: BlockNumberFor<T>,
+			closes: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut record = Self::administered_venue(&who, venue)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(opens <= closes && now <= closes, Error::<T>::InvalidWindow);
+
+			let window = SubmissionWindow { opens, closes };
+			record.windows.retain(|window| now <= window.closes);
+			record.windows.try_push(window).map_err(|_| Error::<T>::TooManyWindows)?;
+			Venues::<T>::insert(venue, record);
+
+			Self::deposit_event(Event::SubmissionWindowAdded { venue, window });
+			Ok(())
+		}
+
+		/// Submit a final manuscript of the registry to a venue.
+		///
+		/// The origin must be signed by one of the manuscript's authors, while the venue accepts
+		/// submissions and before the manuscript's review has opened. A manuscript is submitted to
+		/// one venue only.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::UnregisteredDocument`] if `content_hash` is not a final registry entry.
+		/// - [`Error::NotAuthor`] if the signer is not one of its authors.
+		/// - [`Error::AlreadySubmitted`] if it has already been submitted to a venue.
+		/// - [`Error::AlreadyUnderReview`] if its review has already opened.
+		/// - [`Error::UnknownVenue`] if no venue exists with identifier `venue`.
+		/// - [`Error::SubmissionsClosed`] if the venue does not accept submissions now.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::submit())]
+		pub fn submit(
+			origin: OriginFor<T>,
+			venue: VenueId,
+			content_hash: T::Hash,
+		) -> DispatchResult {
+			let submitter = ensure_signed(origin)?;
+
+			ensure!(T::Documents::contains(&content_hash), Error::<T>::UnregisteredDocument);
+			ensure!(
+				T::Documents::authors_of(&content_hash).contains(&submitter),
+				Error::<T>::NotAuthor
+			);
+			ensure!(!Submissions::<T>::contains_key(content_hash), Error::<T>::AlreadySubmitted);
+			ensure!(!T::Reviews::is_under_review(&content_hash), Error::<T>::AlreadyUnderReview);
+			let record = Venues::<T>::get(venue).ok_or(Error::<T>::UnknownVenue)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(Self::accepts_submissions(&record, now), Error::<T>::SubmissionsClosed);
+
+			let policy = record.policy;
+			Submissions::<T>::insert(
+				content_hash,
+				VenueSubmission { venue, policy, submitter, submitted_at: now },
+			);
+
+			Self::deposit_event(Event::Submitted { content_hash, venue, policy });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> VenueSubmissions<T::AccountId, T::Hash> for Pallet<T> {
+		fn policy_of(content_hash: &T::Hash) -> Option<ReviewPolicy> {
+			Submissions::<T>::get(content_hash).map(|submission| submission.policy)
+		}
+
+		fn is_board_member(content_hash: &T::Hash, who: &T::AccountId) -> bool {
+			Submissions::<T>::get(content_hash)
+				.and_then(|submission| Venues::<T>::get(submission.venue))
+				.map_or(false, |venue| venue.board.contains(who))
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether `venue` accepts submissions in block `now`: within one of its windows, or at
+		/// any time for a journal without windows.
+		pub fn accepts_submissions(venue: &Venue<T>, now: BlockNumberFor<T>) -> bool {
+			if venue.windows.is_empty() {
+				return venue.kind == VenueKind::Journal
+			}
+			venue.windows.iter().any(|window| window.contains(&now))
+		}
+
+		/// The venue with identifier `venue`, provided `who` administers the active institution
+		/// that owns it.
+		fn administered_venue(who: &T::AccountId, venue: VenueId) -> Result<Venue<T>, Error<T>> {
+			let record = Venues::<T>::get(venue).ok_or(Error::<T>::UnknownVenue)?;
+			ensure!(
+				T::Institutions::institution_of(who) == Some(record.institution) &&
+					T::Institutions::is_active(record.institution),
+				Error::<T>::NotVenueAdmin
+			);
+			Ok(record)
+		}
+
+		/// Convert an unbounded list of accounts into an editorial board.
+		fn bound_board(
+			board: Vec<T::AccountId>,
+		) -> Result<BoundedVec<T::AccountId, T::MaxBoardSize>, Error<T>> {
+			ensure!(!board.is_empty(), Error::<T>::EmptyBoard);
+			let board: BoundedVec<_, T::MaxBoardSize> =
+				board.try_into().map_err(|_| Error::<T>::BoardTooLarge)?;
+			for (i, member) in board.iter().enumerate() {
+				ensure!(!board[..i].contains(member), Error::<T>::DuplicateBoardMember);
+			}
+			Ok(board)
+		}
+
+		/// Check that `policy` asks for at least one and at most [`Config::MaxReviewers`]
+		/// reviewers.
+		fn ensure_valid_policy(policy: &ReviewPolicy) -> Result<(), Error<T>> {
+			ensure!(
+				(1..=T::MaxReviewers::get()).contains(&policy.reviewers),
+				Error::<T>::InvalidReviewerCount
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Whether `issn` is formatted as `NNNN-NNNC` with a valid check character `C`.
+///
+/// The check character is the weighted sum of the seven digits, with weights 8 down to 2, taken
+/// modulo 11 and subtracted from 11, where 10 is written `X` and 11 is `0`.
+pub fn is_valid_issn(issn: &Issn) -> bool {
+	if issn[4] != b'-' {
+		return false
+	}
+	let digits = issn[..4].iter().chain(&issn[5..8]);
+	let mut sum = 0u32;
+	for (digit, weight) in digits.zip((2..=8u32).rev()) {
+		if !digit.is_ascii_digit() {
+			return false
+		}
+		sum += u32::from(digit - b'0') * weight;
+	}
+	let check = match (11 - sum % 11) % 11 {
+		10 => b'X',
+		value => b'0' + value as u8,
+	};
+	issn[8] == check
+}
@@ -0,0 +1,173 @@
+use crate as pallet_venues;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU16, ConstU32, ConstU64, Contains},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use speem_primitives::{DocumentAuthors, InstitutionId, InstitutionInspect, ReviewInspect};
+use std::{
+	cell::RefCell,
+	collections::{BTreeMap, BTreeSet},
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Venues: pallet_venues,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+thread_local! {
+	static ADMINS: RefCell<BTreeMap<u64, InstitutionId>> = RefCell::new(BTreeMap::new());
+	static SUSPENDED: RefCell<BTreeSet<InstitutionId>> = RefCell::new(BTreeSet::new());
+	static REGISTERED: RefCell<Vec<(H256, Vec<u64>)>> = RefCell::new(Vec::new());
+	static UNDER_REVIEW: RefCell<BTreeSet<H256>> = RefCell::new(BTreeSet::new());
+}
+
+/// A stand-in for the institution registry, in which account `n` administers institution `n - 1`
+/// for `n` in `1..=3`.
+pub struct MockInstitutions;
+
+impl MockInstitutions {
+	/// Suspend `institution`.
+	pub fn suspend(institution: InstitutionId) {
+		SUSPENDED.with(|suspended| suspended.borrow_mut().insert(institution));
+	}
+}
+
+impl InstitutionInspect<u64> for MockInstitutions {
+	fn institution_of(who: &u64) -> Option<InstitutionId> {
+		ADMINS.with(|admins| admins.borrow().get(who).copied())
+	}
+
+	fn is_active(institution: InstitutionId) -> bool {
+		ADMINS.with(|admins| admins.borrow().values().any(|id| *id == institution)) &&
+			!SUSPENDED.with(|suspended| suspended.borrow().contains(&institution))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn register_active_admin(who: &u64) -> InstitutionId {
+		ADMINS.with(|admins| {
+			let mut admins = admins.borrow_mut();
+			let id = admins.len() as InstitutionId;
+			admins.insert(*who, id);
+			id
+		})
+	}
+}
+
+/// A stand-in for the manuscript registry.
+pub struct MockDocuments;
+
+impl MockDocuments {
+	/// Mark `document` as a final registry entry by `authors`.
+	pub fn register(document: H256, authors: Vec<u64>) {
+		REGISTERED.with(|registered| registered.borrow_mut().push((document, authors)));
+	}
+}
+
+impl Contains<H256> for MockDocuments {
+	fn contains(document: &H256) -> bool {
+		REGISTERED.with(|registered| registered.borrow().iter().any(|(hash, _)| hash == document))
+	}
+}
+
+impl DocumentAuthors<u64, H256> for MockDocuments {
+	fn authors_of(document: &H256) -> Vec<u64> {
+		REGISTERED.with(|registered| {
+			registered
+				.borrow()
+				.iter()
+				.find(|(hash, _)| hash == document)
+				.map(|(_, authors)| authors.clone())
+				.unwrap_or_default()
+		})
+	}
+
+	fn co_authors_of(_: &u64, _: u32) -> Vec<u64> {
+		Vec::new()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<H256, u64> for MockDocuments {
+	fn registered_document() -> (H256, u64) {
+		let document = H256::repeat_byte(2);
+		Self::register(document, vec![1_000]);
+		(document, 1_000)
+	}
+}
+
+/// A stand-in for the reviews pallet.
+pub struct MockReviews;
+
+impl MockReviews {
+	/// Open the review of `document`.
+	pub fn open(document: H256) {
+		UNDER_REVIEW.with(|under_review| under_review.borrow_mut().insert(document));
+	}
+}
+
+impl ReviewInspect<H256> for MockReviews {
+	fn is_under_review(document: &H256) -> bool {
+		UNDER_REVIEW.with(|under_review| under_review.borrow().contains(document))
+	}
+}
+
+impl pallet_venues::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Institutions = MockInstitutions;
+	type Documents = MockDocuments;
+	type Reviews = MockReviews;
+	type MaxNameLength = ConstU32<32>;
+	type MaxBoardSize = ConstU32<3>;
+	type MaxReviewers = ConstU32<3>;
+	type MaxSubmissionWindows = ConstU32<2>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockDocuments;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ADMINS.with(|admins| *admins.borrow_mut() = (1..=3).map(|who| (who, who as u32 - 1)).collect());
+	SUSPENDED.with(|suspended| suspended.borrow_mut().clear());
+	REGISTERED.with(|registered| registered.borrow_mut().clear());
+	UNDER_REVIEW.with(|under_review| under_review.borrow_mut().clear());
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+}
@@ -0,0 +1,291 @@
+use crate::{
+	is_valid_issn, mock::*, Error, Event, Issn, ReviewBlinding, ReviewPolicy, SubmissionWindow,
+	Submissions, VenueByIssn, VenueKind, VenueSubmission, Venues as VenuesStorage,
+};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult, sp_runtime::Percent};
+use sp_core::H256;
+use speem_primitives::VenueSubmissions;
+
+const ISSN: Issn = *b"0317-8471";
+const DOCUMENT: H256 = H256::repeat_byte(2);
+/// The admin of institution 0.
+const ADMIN: u64 = 1;
+const AUTHOR: u64 = 10;
+
+fn policy(reviewers: u32) -> ReviewPolicy {
+	ReviewPolicy {
+		blinding: ReviewBlinding::DoubleBlind,
+		reviewers,
+		acceptance_threshold: Percent::from_percent(50),
+	}
+}
+
+fn create(kind: VenueKind) -> DispatchResult {
+	Venues::create_venue(
+		RuntimeOrigin::signed(ADMIN),
+		kind,
+		ISSN,
+		b"Journal of Provenance".to_vec(),
+		vec![4, 5],
+		policy(2),
+	)
+}
+
+fn submit() -> DispatchResult {
+	Venues::submit(RuntimeOrigin::signed(AUTHOR), 0, DOCUMENT)
+}
+
+#[test]
+fn issns_are_validated() {
+	assert!(is_valid_issn(b"0317-8471"));
+	assert!(is_valid_issn(b"2434-561X"));
+	assert!(!is_valid_issn(b"0317-8472"));
+	assert!(!is_valid_issn(b"03178-471"));
+	assert!(!is_valid_issn(b"2434-561x"));
+	assert!(!is_valid_issn(b"A317-8471"));
+}
+
+#[test]
+fn create_venue_stores_venue() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		assert_ok!(create(VenueKind::Journal));
+		System::assert_last_event(
+			Event::VenueCreated {
+				venue: 0,
+				institution: 0,
+				kind: VenueKind::Journal,
+				identifier: ISSN,
+			}
+			.into(),
+		);
+		let venue = VenuesStorage::<Test>::get(0).expect("venue was created");
+		assert_eq!(venue.institution, 0);
+		assert_eq!(venue.name.into_inner(), b"Journal of Provenance".to_vec());
+		assert_eq!(venue.board.into_inner(), vec![4, 5]);
+		assert_eq!(venue.policy, policy(2));
+		assert!(venue.windows.is_empty());
+		assert_eq!(VenueByIssn::<Test>::get(ISSN), Some(0));
+	});
+}
+
+#[test]
+fn invalid_venues_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let create_with = |who: u64, identifier: Issn, board: Vec<u64>, policy: ReviewPolicy| {
+			Venues::create_venue(
+				RuntimeOrigin::signed(who),
+				VenueKind::Conference,
+				identifier,
+				b"Conference".to_vec(),
+				board,
+				policy,
+			)
+		};
+		assert_noop!(create_with(9, ISSN, vec![4], policy(1)), Error::<Test>::NotActiveAdmin);
+		MockInstitutions::suspend(1);
+		assert_noop!(create_with(2, ISSN, vec![4], policy(1)), Error::<Test>::NotActiveAdmin);
+
+		assert_noop!(
+			create_with(ADMIN, *b"0317-8472", vec![4], policy(1)),
+			Error::<Test>::InvalidIssn
+		);
+		assert_noop!(create_with(ADMIN, ISSN, vec![], policy(1)), Error::<Test>::EmptyBoard);
+		assert_noop!(
+			create_with(ADMIN, ISSN, vec![4, 5, 6, 7], policy(1)),
+			Error::<Test>::BoardTooLarge
+		);
+		assert_noop!(
+			create_with(ADMIN, ISSN, vec![4, 5, 4], policy(1)),
+			Error::<Test>::DuplicateBoardMember
+		);
+		assert_noop!(
+			create_with(ADMIN, ISSN, vec![4], policy(0)),
+			Error::<Test>::InvalidReviewerCount
+		);
+		assert_noop!(
+			create_with(ADMIN, ISSN, vec![4], policy(4)),
+			Error::<Test>::InvalidReviewerCount
+		);
+		assert_noop!(
+			Venues::create_venue(
+				RuntimeOrigin::signed(ADMIN),
+				VenueKind::Journal,
+				ISSN,
+				vec![b'a'; 33],
+				vec![4],
+				policy(1),
+			),
+			Error::<Test>::NameTooLong
+		);
+
+		assert_ok!(create_with(ADMIN, ISSN, vec![4], policy(1)));
+		assert_noop!(create_with(3, ISSN, vec![4], policy(1)), Error::<Test>::IssnTaken);
+	});
+}
+
+#[test]
+fn venue_admins_maintain_board_and_policy() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(create(VenueKind::Journal));
+
+		assert_noop!(
+			Venues::set_board(RuntimeOrigin::signed(ADMIN), 1, vec![6]),
+			Error::<Test>::UnknownVenue
+		);
+		assert_noop!(
+			Venues::set_board(RuntimeOrigin::signed(2), 0, vec![6]),
+			Error::<Test>::NotVenueAdmin
+		);
+		assert_ok!(Venues::set_board(RuntimeOrigin::signed(ADMIN), 0, vec![6]));
+		System::assert_last_event(
+			Event::BoardUpdated { venue: 0, board: vec![6].try_into().unwrap() }.into(),
+		);
+
+		assert_noop!(
+			Venues::set_policy(RuntimeOrigin::signed(ADMIN), 0, policy(0)),
+			Error::<Test>::InvalidReviewerCount
+		);
+		assert_ok!(Venues::set_policy(RuntimeOrigin::signed(ADMIN), 0, policy(3)));
+		System::assert_last_event(Event::PolicyUpdated { venue: 0, policy: policy(3) }.into());
+		assert_eq!(VenuesStorage::<Test>::get(0).unwrap().policy, policy(3));
+
+		// Suspended institutions no longer maintain their venues.
+		MockInstitutions::suspend(0);
+		assert_noop!(
+			Venues::set_policy(RuntimeOrigin::signed(ADMIN), 0, policy(1)),
+			Error::<Test>::NotVenueAdmin
+		);
+	});
+}
+
+#[test]
+fn closed_windows_make_room_for_new_ones() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(create(VenueKind::Conference));
+		let add = |opens: u64, closes: u64| {
+			Venues::add_submission_window(RuntimeOrigin::signed(ADMIN), 0, opens, closes)
+		};
+
+		assert_noop!(add(5, 4), Error::<Test>::InvalidWindow);
+		assert_ok!(add(1, 10));
+		System::assert_last_event(
+			Event::SubmissionWindowAdded {
+				venue: 0,
+				window: SubmissionWindow { opens: 1, closes: 10 },
+			}
+			.into(),
+		);
+		assert_ok!(add(20, 30));
+		assert_noop!(add(40, 50), Error::<Test>::TooManyWindows);
+
+		System::set_block_number(11);
+		assert_noop!(add(5, 10), Error::<Test>::InvalidWindow);
+		assert_ok!(add(40, 50));
+		assert_eq!(
+			VenuesStorage::<Test>::get(0).unwrap().windows.into_inner(),
+			vec![
+				SubmissionWindow { opens: 20, closes: 30 },
+				SubmissionWindow { opens: 40, closes: 50 }
+			]
+		);
+	});
+}
+
+#[test]
+fn submissions_store_the_venue_policy() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(create(VenueKind::Journal));
+		assert_noop!(submit(), Error::<Test>::UnregisteredDocument);
+
+		MockDocuments::register(DOCUMENT, vec![AUTHOR, 11]);
+		assert_noop!(
+			Venues::submit(RuntimeOrigin::signed(12), 0, DOCUMENT),
+			Error::<Test>::NotAuthor
+		);
+		assert_noop!(
+			Venues::submit(RuntimeOrigin::signed(AUTHOR), 1, DOCUMENT),
+			Error::<Test>::UnknownVenue
+		);
+
+		// Journals without windows accept submissions at any time.
+		assert_ok!(submit());
+		System::assert_last_event(
+			Event::Submitted { content_hash: DOCUMENT, venue: 0, policy: policy(2) }.into(),
+		);
+		assert_eq!(
+			Submissions::<Test>::get(DOCUMENT),
+			Some(VenueSubmission {
+				venue: 0,
+				policy: policy(2),
+				submitter: AUTHOR,
+				submitted_at: 1
+			})
+		);
+		assert_noop!(
+			Venues::submit(RuntimeOrigin::signed(11), 0, DOCUMENT),
+			Error::<Test>::AlreadySubmitted
+		);
+
+		// Later policy changes do not affect the submission.
+		assert_ok!(Venues::set_policy(RuntimeOrigin::signed(ADMIN), 0, policy(3)));
+		assert_eq!(Submissions::<Test>::get(DOCUMENT).unwrap().policy, policy(2));
+	});
+}
+
+#[test]
+fn submissions_bind_their_review() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(create(VenueKind::Journal));
+		MockDocuments::register(DOCUMENT, vec![AUTHOR]);
+		assert_eq!(<Venues as VenueSubmissions<_, _>>::policy_of(&DOCUMENT), None);
+		assert!(!<Venues as VenueSubmissions<_, _>>::is_board_member(&DOCUMENT, &4));
+
+		assert_ok!(submit());
+		assert_eq!(<Venues as VenueSubmissions<_, _>>::policy_of(&DOCUMENT), Some(policy(2)));
+		assert!(<Venues as VenueSubmissions<_, _>>::is_board_member(&DOCUMENT, &4));
+		assert!(!<Venues as VenueSubmissions<_, _>>::is_board_member(&DOCUMENT, &ADMIN));
+
+		// Board changes apply to submissions under way.
+		assert_ok!(Venues::set_board(RuntimeOrigin::signed(ADMIN), 0, vec![6]));
+		assert!(!<Venues as VenueSubmissions<_, _>>::is_board_member(&DOCUMENT, &4));
+		assert!(<Venues as VenueSubmissions<_, _>>::is_board_member(&DOCUMENT, &6));
+	});
+}
+
+#[test]
+fn documents_under_review_cannot_be_submitted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(create(VenueKind::Journal));
+		MockDocuments::register(DOCUMENT, vec![AUTHOR]);
+		MockReviews::open(DOCUMENT);
+		assert_noop!(submit(), Error::<Test>::AlreadyUnderReview);
+	});
+}
+
+#[test]
+fn submissions_are_accepted_within_windows() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MockDocuments::register(DOCUMENT, vec![AUTHOR]);
+		assert_ok!(create(VenueKind::Conference));
+
+		// Conferences only accept submissions within a window.
+		assert_noop!(submit(), Error::<Test>::SubmissionsClosed);
+		assert_ok!(Venues::add_submission_window(RuntimeOrigin::signed(ADMIN), 0, 5, 10));
+		assert_noop!(submit(), Error::<Test>::SubmissionsClosed);
+
+		System::set_block_number(11);
+		assert_noop!(submit(), Error::<Test>::SubmissionsClosed);
+
+		System::set_block_number(10);
+		assert_ok!(submit());
+	});
+}
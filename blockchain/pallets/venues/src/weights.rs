@@ -0,0 +1,117 @@
+
+//! Weights for pallet_venues
+//!
+//! PLACEHOLDER VALUES, NOT BENCHMARK OUTPUT. No benchmark run has produced these numbers;
+//! they were written by hand. Replace this file with the output of `speem benchmark pallet`
+//! for `pallet_venues` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_venues.
+pub trait WeightInfo {
+	fn create_venue() -> Weight;
+	fn set_board() -> Weight;
+	fn set_policy() -> Weight;
+	fn add_submission_window() -> Weight;
+	fn submit() -> Weight;
+}
+
+/// Weights for pallet_venues using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: Venues VenueByIssn (r:1 w:1)
+	/// Storage: Venues NextVenueId (r:1 w:1)
+	/// Storage: Venues Venues (r:0 w:1)
+	fn create_venue() -> Weight {
+		Weight::from_parts(49_000_000, 4585)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Venues Venues (r:1 w:1)
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	fn set_board() -> Weight {
+		Weight::from_parts(42_000_000, 5870)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Venues Venues (r:1 w:1)
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	fn set_policy() -> Weight {
+		Weight::from_parts(28_000_000, 5870)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Venues Venues (r:1 w:1)
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	fn add_submission_window() -> Weight {
+		Weight::from_parts(31_000_000, 5870)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Venues Submissions (r:1 w:1)
+	/// Storage: Reviews Submissions (r:1 w:0)
+	/// Storage: Venues Venues (r:1 w:0)
+	fn submit() -> Weight {
+		Weight::from_parts(39_000_000, 6162)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	/// Storage: Venues VenueByIssn (r:1 w:1)
+	/// Storage: Venues NextVenueId (r:1 w:1)
+	/// Storage: Venues Venues (r:0 w:1)
+	fn create_venue() -> Weight {
+		Weight::from_parts(49_000_000, 4585)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Venues Venues (r:1 w:1)
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	fn set_board() -> Weight {
+		Weight::from_parts(42_000_000, 5870)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Venues Venues (r:1 w:1)
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	fn set_policy() -> Weight {
+		Weight::from_parts(28_000_000, 5870)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Venues Venues (r:1 w:1)
+	/// Storage: Institutions AdminOf (r:1 w:0)
+	/// Storage: Institutions Institutions (r:1 w:0)
+	fn add_submission_window() -> Weight {
+		Weight::from_parts(31_000_000, 5870)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Manuscripts Manuscripts (r:1 w:0)
+	/// Storage: Venues Submissions (r:1 w:1)
+	/// Storage: Reviews Submissions (r:1 w:0)
+	/// Storage: Venues Venues (r:1 w:0)
+	fn submit() -> Weight {
+		Weight::from_parts(39_000_000, 6162)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,108 @@
+//! Types and traits shared between the SPEEM pallets, the runtime and the node.
+//!
+//! Pallets that need to know about each other's state do so through the traits defined here rather
+//! than by depending on one another, so that the runtime decides how they are wired together.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{Percent, RuntimeDebug};
+
+pub mod merkle;
+pub mod multihash;
+
+/// Identifier of an institution in the registry.
+pub type InstitutionId = u32;
+
+/// Read access to the registry of participating institutions.
+pub trait InstitutionInspect<AccountId> {
+	/// The institution `who` administers, whatever its status.
+	fn institution_of(who: &AccountId) -> Option<InstitutionId>;
+
+	/// Whether `institution` has been admitted and is not suspended.
+	fn is_active(institution: InstitutionId) -> bool;
+
+	/// Whether `who` is an admin of an active institution.
+	fn is_active_admin(who: &AccountId) -> bool {
+		Self::institution_of(who).map_or(false, Self::is_active)
+	}
+
+	/// Register a new active institution administered by `who`, for benchmarks that need one.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn register_active_admin(who: &AccountId) -> InstitutionId;
+}
+
+/// Lets pallets that police the network act against institutions that misbehave.
+pub trait InstitutionSuspend {
+	/// Suspend `institution` if it is active, e.g. because its validator equivocated.
+	fn suspend(institution: InstitutionId);
+}
+
+/// Read access to the authors of the documents in the registry.
+pub trait DocumentAuthors<AccountId, Hash> {
+	/// The authors of the document registered under `content_hash`, in byline order, or none if
+	/// nothing is registered under it.
+	fn authors_of(content_hash: &Hash) -> Vec<AccountId>;
+
+	/// The accounts listed alongside `author` on the final documents listing them, looking at no
+	/// more than `max_documents` of the documents listing `author`.
+	fn co_authors_of(author: &AccountId, max_documents: u32) -> Vec<AccountId>;
+}
+
+/// Read access to the accounts responsible for the documents in the registry.
+pub trait DocumentRegistrant<AccountId, Hash> {
+	/// The account that registered the document under `content_hash`, or that the registration
+	/// was later transferred to, or `None` if nothing is registered under it.
+	fn registrant_of(content_hash: &Hash) -> Option<AccountId>;
+}
+
+/// Read access to when the documents in the registry were registered.
+pub trait DocumentRegistration<Hash, BlockNumber> {
+	/// The block in which the document under `content_hash` was registered, or `None` if nothing
+	/// is registered under it.
+	fn registered_at(content_hash: &Hash) -> Option<BlockNumber>;
+}
+
+/// Who knows whom during the review of a venue's submissions.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum ReviewBlinding {
+	/// Authors and reviewers know each other.
+	Open,
+	/// Reviewers know the authors, but not the other way round.
+	SingleBlind,
+	/// Neither authors nor reviewers know each other.
+	DoubleBlind,
+}
+
+/// How the submissions of a venue are reviewed.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct ReviewPolicy {
+	/// Who knows whom during the review.
+	pub blinding: ReviewBlinding,
+	/// The maximum number of reviewers assigned to each submission.
+	pub reviewers: u32,
+	/// The share of reviews that must recommend acceptance, with or without minor revisions, for
+	/// a submission to be accepted.
+	pub acceptance_threshold: Percent,
+}
+
+/// Read access to the venues manuscripts are submitted to.
+pub trait VenueSubmissions<AccountId, Hash> {
+	/// The review policy the manuscript under `content_hash` was submitted under, or `None` if it
+	/// has not been submitted to a venue.
+	fn policy_of(content_hash: &Hash) -> Option<ReviewPolicy>;
+
+	/// Whether `who` sits on the editorial board of the venue the manuscript under `content_hash`
+	/// was submitted to.
+	fn is_board_member(content_hash: &Hash, who: &AccountId) -> bool;
+}
+
+/// Read access to the peer reviews under way.
+pub trait ReviewInspect<Hash> {
+	/// Whether the review of the manuscript under `content_hash` has been opened.
+	fn is_under_review(content_hash: &Hash) -> bool;
+}
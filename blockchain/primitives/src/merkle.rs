@@ -0,0 +1,232 @@
+//! Merkle trees over the fixed-size chunks of a document.
+//!
+//! A document is split into chunks of [`CHUNK_SIZE`] bytes, the last of which may be shorter; an
+//! empty document is a single empty chunk. The leaves of the tree are the hashes of the chunks and
+//! each inner node hashes its two children, with a distinct prefix for leaves and inner nodes so
+//! that neither can pass for the other. A level with an odd number of nodes promotes its last node
+//! to the level above unchanged.
+//!
+//! A document can also be split into sections of any size, such as its figures and tables, whose
+//! bounds are chosen by whoever builds the tree. Each section is a leaf, hashed like a chunk.
+//!
+//! The proof that a chunk or section belongs to a document is the list of the siblings of the
+//! nodes on the path from its leaf to the root, bottom up. Checking it takes the number of leaves
+//! of the tree, which determines its shape.
+
+use alloc::vec::Vec;
+use sp_runtime::traits::Hash;
+
+/// The size of the chunks of a document, in bytes.
+pub const CHUNK_SIZE: usize = 1024;
+
+/// The prefix of the hashed preimage of a leaf.
+const LEAF_PREFIX: u8 = 0;
+
+/// The prefix of the hashed preimage of an inner node.
+const NODE_PREFIX: u8 = 1;
+
+/// The number of chunks of a document of `len` bytes.
+pub fn chunk_count(len: usize) -> u32 {
+	len.div_ceil(CHUNK_SIZE).max(1) as u32
+}
+
+/// The `index`th chunk of `data`, if it has that many.
+pub fn chunk(data: &[u8], index: u32) -> Option<&[u8]> {
+	if index >= chunk_count(data.len()) {
+		return None
+	}
+	let start = index as usize * CHUNK_SIZE;
+	Some(&data[start..data.len().min(start + CHUNK_SIZE)])
+}
+
+/// The leaf of the tree for `chunk`.
+pub fn leaf_hash<H: Hash>(chunk: &[u8]) -> H::Output {
+	H::hash(&[&[LEAF_PREFIX][..], chunk].concat())
+}
+
+fn node_hash<H: Hash>(left: &H::Output, right: &H::Output) -> H::Output {
+	H::hash(&[&[NODE_PREFIX][..], left.as_ref(), right.as_ref()].concat())
+}
+
+/// The leaves of the tree over the chunks of `data`.
+pub fn leaves<H: Hash>(data: &[u8]) -> Vec<H::Output> {
+	(0..chunk_count(data.len()))
+		.map(|index| leaf_hash::<H>(chunk(data, index).expect("index is below the count; qed")))
+		.collect()
+}
+
+fn parent_level<H: Hash>(level: &[H::Output]) -> Vec<H::Output> {
+	level
+		.chunks(2)
+		.map(|pair| match pair {
+			[left, right] => node_hash::<H>(left, right),
+			[last] => *last,
+			_ => unreachable!("`chunks(2)` yields one or two nodes; qed"),
+		})
+		.collect()
+}
+
+/// The root of the tree over the chunks of `data`.
+pub fn root<H: Hash>(data: &[u8]) -> H::Output {
+	root_of_leaves::<H>(leaves::<H>(data)).expect("a document has at least one chunk; qed")
+}
+
+/// The root of the tree with `leaves`, or `None` if there are none.
+pub fn root_of_leaves<H: Hash>(mut leaves: Vec<H::Output>) -> Option<H::Output> {
+	while leaves.len() > 1 {
+		leaves = parent_level::<H>(&leaves);
+	}
+	leaves.first().copied()
+}
+
+/// The proof that the `index`th chunk of `data` belongs to it, if it has that many chunks.
+pub fn prove<H: Hash>(data: &[u8], index: u32) -> Option<Vec<H::Output>> {
+	prove_leaf::<H>(leaves::<H>(data), index)
+}
+
+/// The proof that the `index`th of `leaves` belongs to the tree with those leaves, if there are
+/// that many.
+pub fn prove_leaf<H: Hash>(mut leaves: Vec<H::Output>, index: u32) -> Option<Vec<H::Output>> {
+	if index as usize >= leaves.len() {
+		return None
+	}
+	let mut position = index as usize;
+	let mut proof = Vec::new();
+	while leaves.len() > 1 {
+		if let Some(sibling) = leaves.get(position ^ 1) {
+			proof.push(*sibling);
+		}
+		leaves = parent_level::<H>(&leaves);
+		position /= 2;
+	}
+	Some(proof)
+}
+
+/// Whether `proof` shows that `chunk` is the `index`th of the `chunk_count` chunks of the
+/// document with tree root `root`.
+pub fn verify<H: Hash>(
+	root: &H::Output,
+	chunk_count: u32,
+	index: u32,
+	chunk: &[u8],
+	proof: &[H::Output],
+) -> bool {
+	root_from_proof::<H>(chunk_count, index, chunk, proof).as_ref() == Some(root)
+}
+
+/// The root of the tree in which `proof` shows `chunk` to be the `index`th of `chunk_count`
+/// chunks, or `None` if the proof does not fit the shape of that tree.
+pub fn root_from_proof<H: Hash>(
+	chunk_count: u32,
+	index: u32,
+	chunk: &[u8],
+	proof: &[H::Output],
+) -> Option<H::Output> {
+	if chunk.len() > CHUNK_SIZE {
+		return None
+	}
+	root_from_leaf_proof::<H>(chunk_count, index, leaf_hash::<H>(chunk), proof)
+}
+
+/// The root of the tree in which `proof` shows `leaf` to be the `index`th of `leaf_count`
+/// leaves, or `None` if the proof does not fit the shape of that tree.
+pub fn root_from_leaf_proof<H: Hash>(
+	leaf_count: u32,
+	index: u32,
+	leaf: H::Output,
+	proof: &[H::Output],
+) -> Option<H::Output> {
+	if index >= leaf_count {
+		return None
+	}
+	let mut hash = leaf;
+	let mut siblings = proof.iter();
+	let (mut position, mut width) = (index, leaf_count);
+	while width > 1 {
+		// The last node of an odd level has no sibling.
+		if position ^ 1 < width {
+			let sibling = siblings.next()?;
+			hash = if position % 2 == 0 {
+				node_hash::<H>(&hash, sibling)
+			} else {
+				node_hash::<H>(sibling, &hash)
+			};
+		}
+		position /= 2;
+		width = width.div_ceil(2);
+	}
+	siblings.next().is_none().then_some(hash)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::traits::BlakeTwo256;
+
+	fn document(chunks: usize) -> Vec<u8> {
+		(0..chunks * CHUNK_SIZE - CHUNK_SIZE / 2).map(|i| i as u8).collect()
+	}
+
+	#[test]
+	fn documents_are_split_into_chunks() {
+		assert_eq!(chunk_count(0), 1);
+		assert_eq!(chunk_count(CHUNK_SIZE), 1);
+		assert_eq!(chunk_count(CHUNK_SIZE + 1), 2);
+
+		let data = document(3);
+		assert_eq!(chunk(&data, 1), Some(&data[CHUNK_SIZE..2 * CHUNK_SIZE]));
+		assert_eq!(chunk(&data, 2).map(<[u8]>::len), Some(CHUNK_SIZE / 2));
+		assert_eq!(chunk(&data, 3), None);
+		assert_eq!(chunk(&[], 0), Some(&[][..]));
+	}
+
+	#[test]
+	fn every_chunk_is_proven() {
+		for chunks in [1, 2, 3, 5, 8, 13] {
+			let data = document(chunks);
+			let root = root::<BlakeTwo256>(&data);
+			let count = chunk_count(data.len());
+			for index in 0..count {
+				let proof = prove::<BlakeTwo256>(&data, index).unwrap();
+				let chunk = chunk(&data, index).unwrap();
+				assert!(verify::<BlakeTwo256>(&root, count, index, chunk, &proof));
+			}
+			assert_eq!(prove::<BlakeTwo256>(&data, count), None);
+		}
+	}
+
+	#[test]
+	fn proofs_are_bound_to_their_chunk() {
+		let data = document(5);
+		let root = root::<BlakeTwo256>(&data);
+		let proof = prove::<BlakeTwo256>(&data, 4).unwrap();
+		let chunk = chunk(&data, 4).unwrap();
+
+		assert!(!verify::<BlakeTwo256>(&root, 5, 3, chunk, &proof));
+		assert!(!verify::<BlakeTwo256>(&root, 6, 4, chunk, &proof));
+		assert!(!verify::<BlakeTwo256>(&root, 5, 4, &chunk[1..], &proof));
+		assert!(!verify::<BlakeTwo256>(&root, 5, 4, chunk, &proof[1..]));
+		let mut longer = proof.clone();
+		longer.push(root);
+		assert!(!verify::<BlakeTwo256>(&root, 5, 4, chunk, &longer));
+	}
+
+	#[test]
+	fn sections_of_any_size_are_proven() {
+		let sections: [&[u8]; 3] = [b"abstract", &[7; 3 * CHUNK_SIZE], b"figure 1"];
+		let leaves: Vec<_> =
+			sections.iter().map(|section| leaf_hash::<BlakeTwo256>(section)).collect();
+		let root = root_of_leaves::<BlakeTwo256>(leaves.clone()).unwrap();
+
+		for (index, section) in sections.iter().enumerate() {
+			let proof = prove_leaf::<BlakeTwo256>(leaves.clone(), index as u32).unwrap();
+			let leaf = leaf_hash::<BlakeTwo256>(section);
+			assert_eq!(
+				root_from_leaf_proof::<BlakeTwo256>(3, index as u32, leaf, &proof),
+				Some(root)
+			);
+		}
+		assert_eq!(root_of_leaves::<BlakeTwo256>(Vec::new()), None);
+		assert_eq!(prove_leaf::<BlakeTwo256>(leaves, 3), None);
+	}
+}
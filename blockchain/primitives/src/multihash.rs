@@ -0,0 +1,193 @@
+//! Multihash-encoded document identifiers.
+//!
+//! The registry keys documents by a 32-byte digest, but archives do not all compute it the same
+//! way: the runtime hashes with BLAKE2b-256, while many existing archives use SHA-256 or SHA3-256.
+//! A [`Multihash`] tells them apart by naming the [`HashAlgorithm`] alongside the digest, as in the
+//! [multihash](https://multiformats.io/multihash/) format, so that a document can be registered
+//! under the digest its archive already publishes and checked by recomputing it the same way.
+//!
+//! [`Multihash::to_bytes`] and [`Multihash::from_bytes`] convert to and from the binary multihash
+//! format, which content-addressed storage such as IPFS embeds in its identifiers.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// A hash algorithm documents may be identified by, each producing a 32-byte digest.
+#[derive(
+	Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub enum HashAlgorithm {
+	/// SHA-256, from the SHA-2 family.
+	Sha2_256,
+	/// SHA3-256.
+	Sha3_256,
+	/// BLAKE2b with a 256-bit output, the runtime's own hash.
+	#[default]
+	Blake2b256,
+}
+
+impl HashAlgorithm {
+	/// Every supported algorithm.
+	pub const ALL: [HashAlgorithm; 3] =
+		[HashAlgorithm::Sha2_256, HashAlgorithm::Sha3_256, HashAlgorithm::Blake2b256];
+
+	/// The code of the algorithm in the multicodec table.
+	pub fn code(&self) -> u64 {
+		match self {
+			HashAlgorithm::Sha2_256 => 0x12,
+			HashAlgorithm::Sha3_256 => 0x16,
+			HashAlgorithm::Blake2b256 => 0xb220,
+		}
+	}
+
+	/// The algorithm with multicodec `code`, if it is supported.
+	pub fn from_code(code: u64) -> Option<Self> {
+		Self::ALL.into_iter().find(|algorithm| algorithm.code() == code)
+	}
+
+	/// The name of the algorithm in the multicodec table, e.g. `sha2-256`.
+	pub fn name(&self) -> &'static str {
+		match self {
+			HashAlgorithm::Sha2_256 => "sha2-256",
+			HashAlgorithm::Sha3_256 => "sha3-256",
+			HashAlgorithm::Blake2b256 => "blake2b-256",
+		}
+	}
+
+	/// The algorithm named `name` in the multicodec table, if it is supported.
+	pub fn from_name(name: &str) -> Option<Self> {
+		Self::ALL.into_iter().find(|algorithm| algorithm.name() == name)
+	}
+}
+
+/// A digest together with the algorithm that produced it.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Multihash<Digest> {
+	/// The algorithm that produced `digest`.
+	pub algorithm: HashAlgorithm,
+	/// The digest.
+	pub digest: Digest,
+}
+
+impl<Digest> Multihash<Digest> {
+	/// The identifier of a document whose `algorithm` digest is `digest`.
+	pub fn new(algorithm: HashAlgorithm, digest: Digest) -> Self {
+		Self { algorithm, digest }
+	}
+}
+
+impl<Digest: AsRef<[u8]>> Multihash<Digest> {
+	/// The binary multihash: the code of the algorithm and the length of the digest, both as
+	/// unsigned varints, followed by the digest.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let digest = self.digest.as_ref();
+		let mut bytes = Vec::with_capacity(digest.len() + 4);
+		encode_varint(self.algorithm.code(), &mut bytes);
+		encode_varint(digest.len() as u64, &mut bytes);
+		bytes.extend_from_slice(digest);
+		bytes
+	}
+}
+
+impl<Digest: AsMut<[u8]> + Default> Multihash<Digest> {
+	/// Decode a binary multihash from the front of `input`, advancing it past the multihash.
+	///
+	/// Fails if the algorithm is not supported or the digest is not the size of `Digest`.
+	pub fn decode_bytes(input: &mut &[u8]) -> Option<Self> {
+		let algorithm = HashAlgorithm::from_code(decode_varint(input)?)?;
+		let len = usize::try_from(decode_varint(input)?).ok()?;
+		let mut digest = Digest::default();
+		if len != digest.as_mut().len() || input.len() < len {
+			return None
+		}
+		let (bytes, rest) = input.split_at(len);
+		digest.as_mut().copy_from_slice(bytes);
+		*input = rest;
+		Some(Self { algorithm, digest })
+	}
+
+	/// Decode `bytes`, which must hold a binary multihash and nothing else.
+	pub fn from_bytes(mut bytes: &[u8]) -> Option<Self> {
+		let multihash = Self::decode_bytes(&mut bytes)?;
+		bytes.is_empty().then_some(multihash)
+	}
+}
+
+/// Append `value` to `out` as an unsigned varint: seven bits per byte, least significant first,
+/// with the high bit set on every byte but the last.
+pub fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+	while value >= 0x80 {
+		out.push(value as u8 | 0x80);
+		value >>= 7;
+	}
+	out.push(value as u8);
+}
+
+/// Decode an unsigned varint from the front of `input`, advancing it past the varint.
+///
+/// Fails if `input` ends within the varint or the varint does not fit nine bytes, the most the
+/// multiformats allow.
+pub fn decode_varint(input: &mut &[u8]) -> Option<u64> {
+	let mut value = 0u64;
+	for (index, byte) in input.iter().enumerate().take(9) {
+		value |= u64::from(byte & 0x7f) << (7 * index);
+		if byte & 0x80 == 0 {
+			*input = &input[index + 1..];
+			return Some(value)
+		}
+	}
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::testing::H256;
+
+	// The SHA-256 digest of `hello world`.
+	const HELLO_WORLD: [u8; 32] = [
+		0xb9, 0x4d, 0x27, 0xb9, 0x93, 0x4d, 0x3e, 0x08, 0xa5, 0x2e, 0x52, 0xd7, 0xda, 0x7d, 0xab,
+		0xfa, 0xc4, 0x84, 0xef, 0xe3, 0x7a, 0x53, 0x80, 0xee, 0x90, 0x88, 0xf7, 0xac, 0xe2, 0xef,
+		0xcd, 0xe9,
+	];
+
+	#[test]
+	fn multihashes_round_trip() {
+		let sha2 = Multihash::new(HashAlgorithm::Sha2_256, H256(HELLO_WORLD));
+		let bytes = sha2.to_bytes();
+		assert_eq!(bytes[..2], [0x12, 0x20]);
+		assert_eq!(bytes[2..], HELLO_WORLD);
+		assert_eq!(Multihash::from_bytes(&bytes), Some(sha2));
+
+		// The code of BLAKE2b-256 takes three bytes.
+		let blake2 = Multihash::new(HashAlgorithm::Blake2b256, H256(HELLO_WORLD));
+		let bytes = blake2.to_bytes();
+		assert_eq!(bytes[..4], [0xa0, 0xe4, 0x02, 0x20]);
+		assert_eq!(Multihash::from_bytes(&bytes), Some(blake2));
+	}
+
+	#[test]
+	fn malformed_multihashes_are_rejected() {
+		let bytes = Multihash::new(HashAlgorithm::Sha3_256, H256(HELLO_WORLD)).to_bytes();
+		assert_eq!(Multihash::<H256>::from_bytes(&bytes[..bytes.len() - 1]), None);
+		assert_eq!(Multihash::<H256>::from_bytes(&[&bytes[..], &[0]].concat()), None);
+		// SHA-512, which is not supported.
+		assert_eq!(
+			Multihash::<H256>::from_bytes(&[&[0x13, 0x20][..], &HELLO_WORLD].concat()),
+			None
+		);
+		// A 20-byte digest.
+		assert_eq!(Multihash::<H256>::from_bytes(&[&[0x12, 0x14][..], &[0; 20]].concat()), None);
+	}
+
+	#[test]
+	fn algorithms_are_named_as_in_the_multicodec_table() {
+		for algorithm in HashAlgorithm::ALL {
+			assert_eq!(HashAlgorithm::from_name(algorithm.name()), Some(algorithm));
+			assert_eq!(HashAlgorithm::from_code(algorithm.code()), Some(algorithm));
+		}
+		assert_eq!(HashAlgorithm::from_name("sha2-512"), None);
+	}
+}
@@ -3,14 +3,17 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use 	pallet_grandpa::AuthorityId as GrandpaId;
+use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	traits::{
+		BlakeTwo256, Block as BlockT, ConvertInto, IdentifyAccount, NumberFor, One, OpaqueKeys,
+		Verify,
+	},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
 use sp_std::prelude::*;
@@ -22,7 +25,8 @@ use frame_support::genesis_builder_helper::{build_config, create_default_config}
 pub use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
-		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
+		fungible::HoldConsideration, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8,
+		EitherOfDiverse, EqualPrivilegeOnly, KeyOwnerProofSystem, LinearStoragePrice, Randomness,
 		StorageInfo,
 	},
 	weights::{
@@ -34,6 +38,7 @@ pub use frame_support::{
 	StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::{EnsureRoot, EnsureSigned};
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -41,8 +46,16 @@ use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
-/// Import the template pallet.
-pub use pallet_template;
+/// Import the SPEEM pallets.
+pub use pallet_citations;
+pub use pallet_institutions;
+pub use pallet_integrity;
+pub use pallet_manuscripts;
+pub use pallet_priority_claims;
+pub use pallet_reviews;
+pub use pallet_storage_proofs;
+pub use pallet_validator_set;
+pub use pallet_venues;
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -99,10 +112,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -175,26 +188,39 @@ impl frame_system::Config for Runtime {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	/// The maximum size of the Aura and GRANDPA authority sets.
+	pub const MaxAuthorities: u32 = 32;
+}
+
 impl pallet_aura::Config for Runtime {
 	type AuthorityId = AuraId;
 	type DisabledValidators = ();
-	type MaxAuthorities = ConstU32<32>;
+	type MaxAuthorities = MaxAuthorities;
 	type AllowMultipleBlocksPerSlot = ConstBool<false>;
 
 	#[cfg(feature = "experimental")]
 	type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Runtime>;
 }
 
+parameter_types! {
+	/// The number of sessions for which equivocations can still be reported: a week.
+	pub const MaxSetIdSessionEntries: u64 = 7 * 24;
+	/// The number of blocks an equivocation report stays valid for in the transaction pool.
+	pub const ReportLongevity: u64 = MaxSetIdSessionEntries::get() * SessionPeriod::get() as u64;
+}
+
 impl pallet_grandpa::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 
 	type WeightInfo = ();
-	type MaxAuthorities = ConstU32<32>;
+	type MaxAuthorities = MaxAuthorities;
 	type MaxNominators = ConstU32<0>;
-	type MaxSetIdSessionEntries = ConstU64<0>;
+	type MaxSetIdSessionEntries = MaxSetIdSessionEntries;
 
-	type KeyOwnerProof = sp_core::Void;
-	type EquivocationReportSystem = ();
+	type KeyOwnerProof = <Historical as KeyOwnerProofSystem<(KeyTypeId, GrandpaId)>>::Proof;
+	type EquivocationReportSystem =
+		pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -208,6 +234,10 @@ impl pallet_timestamp::Config for Runtime {
 /// Existential deposit.
 pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
+/// One unit of the native token, which has 12 decimals.
+pub const UNIT: Balance = 1_000_000_000_000;
+pub const MILLI_UNIT: Balance = UNIT / 1_000;
+
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
 	type MaxReserves = ();
@@ -222,7 +252,7 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
 }
 
@@ -239,16 +269,485 @@ impl pallet_transaction_payment::Config for Runtime {
 	type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
 }
 
-impl pallet_sudo::Config for Runtime {
+parameter_types! {
+	/// Two thirds of the active institutions must vote to admit a new one.
+	pub InstitutionAdmissionThreshold: Perbill = Perbill::from_rational(2u32, 3u32);
+	/// The maximum number of active institutions, and hence of council members.
+	pub const MaxInstitutions: u32 = 256;
+}
+
+// Governance: the council gathers one representative of every active institution, as maintained
+// by pallet-institutions. Council motions put proposals, such as runtime upgrades, to referendum;
+// the technical committee, whose members the council appoints, can fast-track or veto them.
+type CouncilCollective = pallet_collective::Instance1;
+type TechnicalCollective = pallet_collective::Instance2;
+
+/// Root, or a motion approved by at least two thirds of the council.
+type EnsureRootOrTwoThirdsCouncil = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+>;
+
+parameter_types! {
+	pub MaxCollectiveProposalWeight: Weight =
+		Perbill::from_percent(50) * BlockWeights::get().max_block;
+}
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = ConstU32<{ 5 * DAYS }>;
+	type MaxProposals = ConstU32<100>;
+	type MaxMembers = MaxInstitutions;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	// Members are set by pallet-institutions; root can only step in to repair them.
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxCollectiveProposalWeight;
+}
+
+parameter_types! {
+	/// The maximum size of the technical committee.
+	pub const TechnicalMaxMembers: u32 = 16;
+}
+
+impl pallet_collective::Config<TechnicalCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = ConstU32<{ 2 * DAYS }>;
+	type MaxProposals = ConstU32<100>;
+	type MaxMembers = TechnicalMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	// Members are managed through pallet-membership.
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxCollectiveProposalWeight;
+}
+
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EnsureRootOrTwoThirdsCouncil;
+	type RemoveOrigin = EnsureRootOrTwoThirdsCouncil;
+	type SwapOrigin = EnsureRootOrTwoThirdsCouncil;
+	type ResetOrigin = EnsureRootOrTwoThirdsCouncil;
+	type PrimeOrigin = EnsureRootOrTwoThirdsCouncil;
+	type MembershipInitialized = TechnicalCommittee;
+	type MembershipChanged = TechnicalCommittee;
+	type MaxMembers = TechnicalMaxMembers;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = UNIT;
+	pub const PreimageByteDeposit: Balance = 10 * MILLI_UNIT;
+	pub const PreimageHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		PreimageHoldReason,
+		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+	>;
+}
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
 	type RuntimeCall = RuntimeCall;
-	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = Preimage;
+}
+
+parameter_types! {
+	pub const LaunchPeriod: BlockNumber = 7 * DAYS;
+	pub const VotingPeriod: BlockNumber = 7 * DAYS;
+	/// The shortest voting period the technical committee can fast-track a referendum to.
+	pub const FastTrackVotingPeriod: BlockNumber = 3 * HOURS;
+	pub const EnactmentPeriod: BlockNumber = DAYS;
+	pub const CooloffPeriod: BlockNumber = 7 * DAYS;
+	pub const MinimumDeposit: Balance = 100 * UNIT;
+}
+
+impl pallet_democracy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type EnactmentPeriod = EnactmentPeriod;
+	type LaunchPeriod = LaunchPeriod;
+	type VotingPeriod = VotingPeriod;
+	type VoteLockingPeriod = EnactmentPeriod;
+	type MinimumDeposit = MinimumDeposit;
+	/// A simple majority of the council can put a proposal to a majority-carries referendum.
+	type ExternalOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
+	/// Three quarters of the council can put a proposal to a majority-carries referendum.
+	type ExternalMajorityOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>;
+	/// A unanimous council can put a proposal to a referendum that carries unless rejected.
+	type ExternalDefaultOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type SubmitOrigin = EnsureSigned<AccountId>;
+	/// Two thirds of the technical committee can fast-track a council proposal, such as a runtime
+	/// upgrade, to a shorter voting period.
+	type FastTrackOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>;
+	/// A unanimous technical committee can fast-track it to any voting period.
+	type InstantOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 1>;
+	type InstantAllowed = ConstBool<true>;
+	type FastTrackVotingPeriod = FastTrackVotingPeriod;
+	/// Two thirds of the council can cancel a referendum.
+	type CancellationOrigin = EnsureRootOrTwoThirdsCouncil;
+	type BlacklistOrigin = EnsureRoot<AccountId>;
+	/// A unanimous technical committee can cancel a malicious public proposal.
+	type CancelProposalOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 1>,
+	>;
+	/// Any technical committee member can veto a council proposal for the cooloff period.
+	type VetoOrigin = pallet_collective::EnsureMember<AccountId, TechnicalCollective>;
+	type CooloffPeriod = CooloffPeriod;
+	type Slash = ();
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type MaxVotes = ConstU32<100>;
+	type WeightInfo = pallet_democracy::weights::SubstrateWeight<Runtime>;
+	type MaxProposals = ConstU32<100>;
+	type Preimages = Preimage;
+	type MaxDeposits = ConstU32<100>;
+	type MaxBlacklisted = ConstU32<100>;
 }
 
-/// Configure the pallet-template in pallets/template.
-impl pallet_template::Config for Runtime {
+/// Configure the pallet-institutions in pallets/institutions.
+impl pallet_institutions::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ApplicationDeposit = ConstU128<{ 100 * UNIT }>;
+	type VotingPeriod = ConstU32<{ 14 * DAYS }>;
+	type AdmissionThreshold = InstitutionAdmissionThreshold;
+	type ForceOrigin = EnsureRootOrTwoThirdsCouncil;
+	type Representatives = Council;
+	type MaxInstitutions = MaxInstitutions;
+	type MaxAdmins = ConstU32<16>;
+	type MaxNameLength = ConstU32<128>;
+	type MaxDomainLength = ConstU32<64>;
+	type WeightInfo = pallet_institutions::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	/// Validator sets are rotated every hour.
+	pub const SessionPeriod: BlockNumber = HOURS;
+	pub const SessionOffset: BlockNumber = 0;
+}
+
+impl pallet_session::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, ValidatorSet>;
+	type SessionHandler = <opaque::SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = opaque::SessionKeys;
+	type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
+}
+
+/// Validators are identified in historical sessions by the institution they run for, so that
+/// offences can be attributed to it.
+impl pallet_session::historical::Config for Runtime {
+	type FullIdentification = pallet_institutions::InstitutionId;
+	type FullIdentificationOf = pallet_validator_set::InstitutionOfValidator<Runtime>;
+}
+
+impl pallet_offences::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
+	type OnOffenceHandler = ValidatorSet;
+}
+
+/// Configure the pallet-validator-set in pallets/validator-set.
+impl pallet_validator_set::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Institutions = Institutions;
+	type ValidatorRegistration = Session;
+	type MaxValidators = MaxAuthorities;
+	type WeightInfo = pallet_validator_set::weights::SubstrateWeight<Runtime>;
+}
+
+/// Configure the pallet-manuscripts in pallets/manuscripts.
+impl pallet_manuscripts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type DepositBase = ConstU128<UNIT>;
+	type DepositPerByte = ConstU128<{ 10 * MILLI_UNIT }>;
+	type Time = Timestamp;
+	type MaxTitleLength = ConstU32<512>;
+	type MaxAuthors = ConstU32<64>;
+	type MaxLicenseLength = ConstU32<64>;
+	type ApprovalPeriod = ConstU32<{ 7 * DAYS }>;
+	type AuthorPageSize = ConstU32<100>;
+	type WeightInfo = pallet_manuscripts::weights::SubstrateWeight<Runtime>;
+}
+
+/// Registers a manuscript and has every author approve it, so that it is final, for the benchmarks
+/// of pallets that refer to registered documents.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DocumentsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl DocumentsBenchmarkHelper {
+	fn register(authors: u32) -> Hash {
+		Self::register_content(authors, b"document")
+	}
+
+	/// Register `content` by `authors` accounts, all of which approve it.
+	fn register_content(authors: u32, content: &[u8]) -> Hash {
+		Self::register_authors(
+			(0..authors).map(|i| frame_benchmarking::account("author", i, 0)).collect(),
+			content,
+		)
+	}
+
+	/// Register `content` by `authors`, the first of which registers it and all of which approve
+	/// it.
+	fn register_authors(authors: Vec<AccountId>, content: &[u8]) -> Hash {
+		use frame_support::traits::fungible::Mutate;
+		use sp_runtime::traits::Hash as _;
+
+		let lead = authors[0].clone();
+		Balances::set_balance(&lead, 1_000 * UNIT);
+		let content_hash = BlakeTwo256::hash(content);
+		Manuscripts::register(
+			RuntimeOrigin::signed(lead.clone()),
+			pallet_manuscripts::Multihash::new(
+				pallet_manuscripts::HashAlgorithm::Blake2b256,
+				content_hash,
+			),
+			Vec::new(),
+			Hash::default(),
+			authors.clone(),
+			Vec::new(),
+			None,
+		)
+		.expect("the lead author can register a manuscript");
+		for author in authors.into_iter().filter(|author| *author != lead) {
+			Manuscripts::approve_authorship(RuntimeOrigin::signed(author), content_hash)
+				.expect("listed authors can approve a pending manuscript");
+		}
+		content_hash
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_priority_claims::BenchmarkHelper<Hash> for DocumentsBenchmarkHelper {
+	fn registered_document() -> Hash {
+		Self::register(1)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_reviews::BenchmarkHelper<Hash, AccountId, <Signature as Verify>::Signer, Signature>
+	for DocumentsBenchmarkHelper
+{
+	fn registered_document() -> Hash {
+		use frame_support::traits::Get;
+
+		Self::register(<Runtime as pallet_manuscripts::Config>::MaxAuthors::get())
+	}
+
+	fn co_authored_documents(author: &AccountId, count: u32) {
+		use codec::Encode;
+		use frame_support::traits::Get;
+
+		let max_authors = <Runtime as pallet_manuscripts::Config>::MaxAuthors::get();
+		for document in 0..count {
+			let authors = sp_std::iter::once(author.clone())
+				.chain(
+					(1..max_authors).map(|i| frame_benchmarking::account("co-author", i, document)),
+				)
+				.collect();
+			Self::register_authors(authors, &(b"co-authored document", document).encode());
+		}
+	}
+
+	fn pseudonym() -> <Signature as Verify>::Signer {
+		use sp_runtime::app_crypto::RuntimePublic;
+
+		sp_core::sr25519::Public::generate_pair(PSEUDONYM_KEY_TYPE, None).into()
+	}
+
+	fn sign(public: &<Signature as Verify>::Signer, message: &[u8]) -> Signature {
+		use sp_runtime::{app_crypto::RuntimePublic, MultiSigner};
+
+		match public {
+			MultiSigner::Sr25519(public) => public
+				.sign(PSEUDONYM_KEY_TYPE, &message)
+				.expect("the pseudonym was generated in the keystore")
+				.into(),
+			_ => unreachable!("pseudonyms are sr25519 keys"),
+		}
+	}
+}
+
+/// The keystore slot of the pseudonymous keys generated for the benchmarks of pallet-reviews.
+#[cfg(feature = "runtime-benchmarks")]
+const PSEUDONYM_KEY_TYPE: KeyTypeId = KeyTypeId(*b"revw");
+
+/// Configure the pallet-priority-claims in pallets/priority-claims.
+impl pallet_priority_claims::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Time = Timestamp;
+	type Documents = Manuscripts;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DocumentsBenchmarkHelper;
+	type WeightInfo = pallet_priority_claims::weights::SubstrateWeight<Runtime>;
+}
+
+/// Configure the pallet-reviews in pallets/reviews.
+impl pallet_reviews::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type EditorOrigin = pallet_institutions::EnsureActiveAdmin<Runtime>;
+	type Documents = Manuscripts;
+	type Venues = Venues;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	type ReviewPeriod = ConstU32<{ 30 * DAYS }>;
+	type RevealPeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxReviewers = ConstU32<5>;
+	type MaxAffiliations = ConstU32<8>;
+	type MaxCoAuthoredDocuments = ConstU32<128>;
+	type UnsignedPriority = ConstU64<{ TransactionPriority::MAX / 2 }>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DocumentsBenchmarkHelper;
+	type WeightInfo = pallet_reviews::weights::SubstrateWeight<Runtime>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_venues::BenchmarkHelper<Hash, AccountId> for DocumentsBenchmarkHelper {
+	fn registered_document() -> (Hash, AccountId) {
+		(Self::register(1), frame_benchmarking::account("author", 0, 0))
+	}
+}
+
+/// Configure the pallet-venues in pallets/venues.
+impl pallet_venues::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Institutions = Institutions;
+	type Documents = Manuscripts;
+	type Reviews = Reviews;
+	type MaxNameLength = ConstU32<256>;
+	type MaxBoardSize = ConstU32<64>;
+	type MaxReviewers = ConstU32<5>;
+	type MaxSubmissionWindows = ConstU32<8>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DocumentsBenchmarkHelper;
+	type WeightInfo = pallet_venues::weights::SubstrateWeight<Runtime>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_integrity::BenchmarkHelper<Hash> for DocumentsBenchmarkHelper {
+	fn registered_documents() -> (Hash, Hash) {
+		(Self::register(1), Self::register_content(1, b"corrected document"))
+	}
+}
+
+/// Configure the pallet-integrity in pallets/integrity.
+impl pallet_integrity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRootOrTwoThirdsCouncil;
+	type Documents = Manuscripts;
+	type MaxEvidence = ConstU32<8>;
+	type MaxNotices = ConstU32<16>;
+	type MaxRegistrantCorrections = ConstU32<4>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DocumentsBenchmarkHelper;
+	type WeightInfo = pallet_integrity::weights::SubstrateWeight<Runtime>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_citations::BenchmarkHelper<Hash, AccountId> for DocumentsBenchmarkHelper {
+	fn registered_document(seed: u32) -> (Hash, AccountId) {
+		(
+			Self::register_content(1, &seed.to_le_bytes()),
+			frame_benchmarking::account("author", 0, 0),
+		)
+	}
+}
+
+/// Configure the pallet-citations in pallets/citations.
+impl pallet_citations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Documents = Manuscripts;
+	type MaxReferences = ConstU32<128>;
+	type MaxIdentifierLength = ConstU32<128>;
+	type MaxPageSize = ConstU32<100>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DocumentsBenchmarkHelper;
+	type WeightInfo = pallet_citations::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_storage_proofs::BenchmarkHelper<Hash, AccountId> for DocumentsBenchmarkHelper {
+	fn registered_document() -> (Hash, AccountId) {
+		(Self::register_content(1, b"hosted document"), frame_benchmarking::account("author", 0, 0))
+	}
+}
+
+/// Finds the institution running the validator that authored a block, from the index of its Aura
+/// authority.
+pub struct AuthorInstitution;
+
+impl frame_support::traits::FindAuthor<pallet_institutions::InstitutionId> for AuthorInstitution {
+	fn find_author<'a, I>(digests: I) -> Option<pallet_institutions::InstitutionId>
+	where
+		I: 'a + IntoIterator<Item = (sp_runtime::ConsensusEngineId, &'a [u8])>,
+	{
+		use frame_support::traits::FindAuthor;
+
+		let validator =
+			pallet_session::FindAccountFromAuthorIndex::<Runtime, Aura>::find_author(digests)?;
+		pallet_validator_set::InstitutionOf::<Runtime>::get(validator)
+	}
+}
+
+/// Configure the pallet-storage-proofs in pallets/storage-proofs.
+impl pallet_storage_proofs::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ProverId = pallet_storage_proofs::sr25519::ProverId;
+	type Institutions = Institutions;
+	type Documents = Manuscripts;
+	type Randomness = RandomnessCollectiveFlip;
+	type FindAuthor = AuthorInstitution;
+	type ChallengePeriod = ConstU32<HOURS>;
+	type ResponseWindow = ConstU32<{ 10 * MINUTES }>;
+	type ChallengesPerPeriod = ConstU32<4>;
+	type MaxHostings = ConstU32<100_000>;
+	type MaxHostingsPerInstitution = ConstU32<1_000>;
+	type UnsignedPriority = ConstU64<{ TransactionPriority::MAX / 2 }>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DocumentsBenchmarkHelper;
+	type WeightInfo = pallet_storage_proofs::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -286,12 +785,71 @@ mod runtime {
 	#[runtime::pallet_index(5)]
 	pub type TransactionPayment = pallet_transaction_payment;
 
-	#[runtime::pallet_index(6)]
-	pub type Sudo = pallet_sudo;
-
-	// Include the custom logic from the pallet-template in the runtime.
+	// The SPEEM manuscript registry.
 	#[runtime::pallet_index(7)]
-	pub type TemplateModule = pallet_template;
+	pub type Manuscripts = pallet_manuscripts;
+
+	// Commit-reveal priority claims over registered manuscripts.
+	#[runtime::pallet_index(8)]
+	pub type PriorityClaims = pallet_priority_claims;
+
+	// The consortium of participating institutions.
+	#[runtime::pallet_index(9)]
+	pub type Institutions = pallet_institutions;
+
+	// The validators run by the institutions, rotated at session boundaries. It must come before
+	// `Session` so that its genesis nominations are in place when the first session is planned.
+	#[runtime::pallet_index(10)]
+	pub type ValidatorSet = pallet_validator_set;
+
+	#[runtime::pallet_index(11)]
+	pub type Session = pallet_session;
+
+	// Session proofs and offence reports, for GRANDPA equivocations.
+	#[runtime::pallet_index(12)]
+	pub type Historical = pallet_session::historical;
+
+	#[runtime::pallet_index(13)]
+	pub type Offences = pallet_offences;
+
+	// Governance by the consortium of institutions.
+	#[runtime::pallet_index(14)]
+	pub type Council = pallet_collective<Instance1>;
+
+	#[runtime::pallet_index(15)]
+	pub type TechnicalCommittee = pallet_collective<Instance2>;
+
+	#[runtime::pallet_index(16)]
+	pub type TechnicalMembership = pallet_membership<Instance1>;
+
+	#[runtime::pallet_index(17)]
+	pub type Preimage = pallet_preimage;
+
+	#[runtime::pallet_index(18)]
+	pub type Scheduler = pallet_scheduler;
+
+	#[runtime::pallet_index(19)]
+	pub type Democracy = pallet_democracy;
+
+	#[runtime::pallet_index(20)]
+	pub type Reviews = pallet_reviews;
+
+	#[runtime::pallet_index(21)]
+	pub type Venues = pallet_venues;
+
+	#[runtime::pallet_index(22)]
+	pub type Integrity = pallet_integrity;
+
+	#[runtime::pallet_index(23)]
+	pub type Citations = pallet_citations;
+
+	// Randomness from recent block hashes, which storage challenges are drawn with.
+	#[runtime::pallet_index(24)]
+	pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
+
+	// Proof-of-storage challenges for the institutions hosting registered documents.
+	#[runtime::pallet_index(25)]
+	pub type StorageProofs = pallet_storage_proofs;
 }
 
 /// The address format for describing accounts.
@@ -315,14 +873,30 @@ pub type SignedExtra = (
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
-#[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	// The sudo key is retired now that the council governs the chain.
+	frame_support::migrations::RemovePallet<SudoPalletName, RocksDbWeight>,
+);
+
+parameter_types! {
+	pub const SudoPalletName: &'static str = "Sudo";
+}
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+
+/// Lets the runtime submit unsigned transactions, such as GRANDPA equivocation reports.
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -340,8 +914,21 @@ mod benches {
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
-		[pallet_sudo, Sudo]
-		[pallet_template, TemplateModule]
+		[pallet_collective, Council]
+		[pallet_collective, TechnicalCommittee]
+		[pallet_membership, TechnicalMembership]
+		[pallet_preimage, Preimage]
+		[pallet_scheduler, Scheduler]
+		[pallet_democracy, Democracy]
+		[pallet_manuscripts, Manuscripts]
+		[pallet_priority_claims, PriorityClaims]
+		[pallet_institutions, Institutions]
+		[pallet_validator_set, ValidatorSet]
+		[pallet_reviews, Reviews]
+		[pallet_venues, Venues]
+		[pallet_integrity, Integrity]
+		[pallet_citations, Citations]
+		[pallet_storage_proofs, StorageProofs]
 	);
 }
 
@@ -443,23 +1030,26 @@ impl_runtime_apis! {
 		}
 
 		fn submit_report_equivocation_unsigned_extrinsic(
-			_equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+			equivocation_proof: sp_consensus_grandpa::EquivocationProof<
 				<Block as BlockT>::Hash,
 				NumberFor<Block>,
 			>,
-			_key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+			key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
 		) -> Option<()> {
-			None
+			let key_owner_proof = key_owner_proof.decode()?;
+
+			Grandpa::submit_unsigned_equivocation_report(equivocation_proof, key_owner_proof)
 		}
 
 		fn generate_key_ownership_proof(
 			_set_id: sp_consensus_grandpa::SetId,
-			_authority_id: GrandpaId,
+			authority_id: GrandpaId,
 		) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
-			// NOTE: this is the only implementation possible since we've
-			// defined our key owner proof type as a bottom type (i.e. a type
-			// with no values).
-			None
+			use codec::Encode;
+
+			Historical::prove((sp_consensus_grandpa::KEY_TYPE, authority_id))
+				.map(|proof| proof.encode())
+				.map(sp_consensus_grandpa::OpaqueKeyOwnershipProof::new)
 		}
 	}
 
@@ -490,6 +1080,69 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_manuscripts_runtime_api::ManuscriptsApi<
+		Block,
+		AccountId,
+		Hash,
+		BlockNumber,
+		pallet_manuscripts::ManuscriptRecord<Runtime>,
+	> for Runtime
+	{
+		fn verify_hash(content_hash: Hash) -> Option<pallet_manuscripts::ManuscriptRecord<Runtime>> {
+			pallet_manuscripts::Manuscripts::<Runtime>::get(content_hash)
+		}
+
+		fn content_id(content_hash: Hash) -> Option<pallet_manuscripts::Multihash<Hash>> {
+			Manuscripts::content_id(content_hash)
+		}
+
+		fn history(document: pallet_manuscripts::DocumentId) -> Vec<Hash> {
+			Manuscripts::history(document)
+		}
+
+		fn by_author(author: AccountId, page: u32) -> Vec<Hash> {
+			Manuscripts::by_author(&author, page)
+		}
+
+		fn status(
+			document: pallet_manuscripts::DocumentId,
+		) -> Option<pallet_manuscripts::ManuscriptStatus<BlockNumber>> {
+			Manuscripts::document_status(document)
+		}
+
+		fn commitment(
+			content_hash: Hash,
+		) -> Option<pallet_manuscripts::MerkleCommitment<Hash>> {
+			pallet_manuscripts::Commitments::<Runtime>::get(content_hash)
+		}
+	}
+
+	impl pallet_reviews_runtime_api::ReviewsApi<Block, Hash, pallet_reviews::Submission<Runtime>>
+		for Runtime
+	{
+		fn review(content_hash: Hash) -> Option<pallet_reviews::Submission<Runtime>> {
+			pallet_reviews::Submissions::<Runtime>::get(content_hash)
+		}
+	}
+
+	impl pallet_integrity_runtime_api::IntegrityApi<Block, Hash> for Runtime {
+		fn integrity_status(
+			content_hash: Hash,
+		) -> Option<pallet_integrity_runtime_api::IntegrityStatus<Hash>> {
+			Integrity::integrity_status(&content_hash)
+		}
+	}
+
+	impl pallet_citations_runtime_api::CitationsApi<Block, Hash> for Runtime {
+		fn citation_count(content_hash: Hash) -> u32 {
+			Citations::citation_count(&content_hash)
+		}
+
+		fn cited_by(content_hash: Hash, page: u32, page_size: u32) -> Vec<Hash> {
+			Citations::cited_by(&content_hash, page, page_size)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
@@ -586,3 +1239,6 @@ impl_runtime_apis! {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests;
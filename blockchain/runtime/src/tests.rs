@@ -0,0 +1,115 @@
+//! End-to-end tests of the runtime's consensus and governance wiring.
+
+use super::*;
+use codec::Encode;
+use frame_support::assert_ok;
+use pallet_institutions::InstitutionInspect;
+use sp_consensus_grandpa::{Equivocation, EquivocationProof, RoundNumber, SetId, KEY_TYPE};
+use sp_core::H256;
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+
+/// The institution Alice administers and validates for.
+const INSTITUTION: pallet_institutions::InstitutionId = 0;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let alice = Sr25519Keyring::Alice.to_account_id();
+	RuntimeGenesisConfig {
+		institutions: InstitutionsConfig {
+			institutions: vec![(
+				b"Alice University".to_vec(),
+				*b"CH",
+				b"alice.example".to_vec(),
+				vec![alice.clone()],
+			)],
+		},
+		validator_set: ValidatorSetConfig { validators: vec![(INSTITUTION, alice.clone())] },
+		session: SessionConfig {
+			keys: vec![(
+				alice.clone(),
+				alice,
+				opaque::SessionKeys {
+					aura: Sr25519Keyring::Alice.public().into(),
+					grandpa: Ed25519Keyring::Alice.public().into(),
+				},
+			)],
+			..Default::default()
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap()
+	.into()
+}
+
+/// Two conflicting prevotes signed by `keyring` in the same round.
+fn prevote_equivocation(
+	set_id: SetId,
+	round: RoundNumber,
+	keyring: Ed25519Keyring,
+) -> EquivocationProof<H256, BlockNumber> {
+	let signed_prevote = |hash: H256, number: BlockNumber| {
+		let prevote = finality_grandpa::Prevote { target_hash: hash, target_number: number };
+		let message = finality_grandpa::Message::Prevote(prevote.clone());
+		let payload = sp_consensus_grandpa::localized_payload(round, set_id, &message);
+		(prevote, keyring.sign(&payload).into())
+	};
+
+	EquivocationProof::new(
+		set_id,
+		Equivocation::Prevote(finality_grandpa::Equivocation {
+			round_number: round,
+			identity: keyring.public().into(),
+			first: signed_prevote(H256::repeat_byte(1), 1),
+			second: signed_prevote(H256::repeat_byte(2), 1),
+		}),
+	)
+}
+
+#[test]
+fn grandpa_equivocations_suspend_the_validators_institution() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert!(Institutions::is_active(INSTITUTION));
+
+		let equivocation =
+			prevote_equivocation(Grandpa::current_set_id(), 1, Ed25519Keyring::Alice);
+		let grandpa_id: GrandpaId = Ed25519Keyring::Alice.public().into();
+		let key_owner_proof = Historical::prove((KEY_TYPE, grandpa_id)).unwrap();
+
+		// The proof survives the opaque encoding used by the runtime API.
+		let opaque = sp_consensus_grandpa::OpaqueKeyOwnershipProof::new(key_owner_proof.encode());
+		let key_owner_proof = opaque.decode().unwrap();
+
+		assert_ok!(Grandpa::report_equivocation_unsigned(
+			RuntimeOrigin::none(),
+			Box::new(equivocation),
+			key_owner_proof,
+		));
+
+		assert!(!Institutions::is_active(INSTITUTION));
+		let record = pallet_validator_set::Offences::<Runtime>::get(INSTITUTION, 0)
+			.expect("the offence is recorded against the institution");
+		assert_eq!(record.validator, Sr25519Keyring::Alice.to_account_id());
+	});
+}
+
+#[test]
+fn council_motions_act_for_the_institutions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = Sr25519Keyring::Alice.to_account_id();
+		assert_eq!(
+			pallet_collective::Members::<Runtime, CouncilCollective>::get(),
+			vec![alice.clone()]
+		);
+
+		let call = RuntimeCall::Institutions(pallet_institutions::Call::suspend {
+			institution: INSTITUTION,
+		});
+		let length = call.encoded_size() as u32;
+		assert_ok!(Council::propose(RuntimeOrigin::signed(alice), 1, Box::new(call), length));
+
+		assert!(!Institutions::is_active(INSTITUTION));
+		assert!(pallet_collective::Members::<Runtime, CouncilCollective>::get().is_empty());
+	});
+}